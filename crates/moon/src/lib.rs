@@ -75,6 +75,12 @@ pub use up_msg_request::UpMsgRequest;
 
 const MAX_UP_MSG_BYTES: usize = 2 * 1_048_576;
 
+/// Expected in the `X-Serde-Format` header of `UpMsg` requests.
+#[cfg(feature = "serde")]
+const SERDE_FORMAT: &str = "serde";
+#[cfg(feature = "serde-lite")]
+const SERDE_FORMAT: &str = "lite";
+
 #[derive(Copy, Clone)]
 struct SharedData {
     backend_build_id: u128,
@@ -326,6 +332,7 @@ where
     UMsg: DeserializeOwned,
 {
     let headers = req.headers();
+    check_serde_format(headers)?;
 
     let up_msg_request = UpMsgRequest {
         up_msg: parse_up_msg(payload).await?,
@@ -368,6 +375,21 @@ async fn parse_up_msg<UMsg: Deserialize>(mut payload: web::Payload) -> Result<UM
         .map_err(error::ErrorBadRequest)
 }
 
+/// Requests without the header (e.g. from older frontends) are accepted.
+fn check_serde_format(headers: &HeaderMap) -> Result<(), Error> {
+    let serde_format = match headers.get("X-Serde-Format") {
+        Some(serde_format) => serde_format.to_str().map_err(error::ErrorBadRequest)?,
+        None => return Ok(()),
+    };
+    if serde_format != SERDE_FORMAT {
+        Err(error::ErrorBadRequest(format!(
+            "UpMsg serialized by '{}' but the backend expects '{}'; enable the same serde feature in Zoon and Moon",
+            serde_format, SERDE_FORMAT
+        )))?
+    }
+    Ok(())
+}
+
 fn parse_session_id(headers: &HeaderMap) -> Result<SessionId, Error> {
     headers
         .get("X-Session-ID")
@@ -502,10 +524,15 @@ async fn reload_sse_responder(
 
 async fn message_sse_responder(
     session_id: web::Path<String>,
+    request: HttpRequest,
     sse: web::Data<MessageSSE>,
 ) -> Result<HttpResponse, Error> {
     let session_id = session_id.parse().map_err(error::ErrorBadRequest)?;
-    let (_, event_stream) = sse.new_connection(Some(session_id));
+    let (_, event_stream) = if query_param(&request, "resume") == Some("true") {
+        sse.new_resumable_connection(session_id, last_event_id(&request))
+    } else {
+        sse.new_connection(Some(session_id))
+    };
     SessionActor::create(session_id, MessageSSE::clone(&sse));
 
     Ok(HttpResponse::Ok()
@@ -514,6 +541,24 @@ async fn message_sse_responder(
         .streaming(event_stream))
 }
 
+fn query_param<'a>(request: &'a HttpRequest, name: &str) -> Option<&'a str> {
+    request.query_string().split('&').find_map(|pair| {
+        let (key, value) = pair.split_once('=')?;
+        (key == name).then(|| value)
+    })
+}
+
+/// The browser sends `Last-Event-ID` when `EventSource` reconnects by itself,
+/// Zoon passes `lastEventId` in the query when it opens a new `EventSource`.
+fn last_event_id(request: &HttpRequest) -> Option<u64> {
+    request
+        .headers()
+        .get("Last-Event-ID")
+        .and_then(|id| id.to_str().ok())
+        .or_else(|| query_param(request, "lastEventId"))
+        .and_then(|id| id.parse().ok())
+}
+
 // ------ frontend_responder ------
 
 async fn frontend_responder<FRB, FRBO>(frontend: web::Data<FRB>) -> impl Responder
@@ -5,6 +5,7 @@ use chashmap::CHashMap;
 use futures::Stream;
 use moonlight::SessionId;
 use std::cell::RefCell;
+use std::collections::VecDeque;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
@@ -14,6 +15,11 @@ use tokio::time::{interval_at, Instant};
 
 pub type ShareableSSE = Arc<SSE>;
 
+/// How many of the latest events are kept per resumable session for replaying after reconnection.
+const MAX_LOGGED_EVENTS: usize = 256;
+/// How long the event log of a disconnected session waits for the client to reconnect.
+const EVENT_LOG_TTL: Duration = Duration::from_secs(60);
+
 // ------ Connection ------
 
 pub struct Connection {
@@ -38,11 +44,58 @@ impl Connection {
     }
 
     pub fn send(&self, event: &str, data: &str) -> Result<(), SendError<Bytes>> {
-        let message = Bytes::from(["event: ", event, "\n", "data: ", data, "\n\n"].concat());
+        self.send_message(message(None, event, data))
+    }
+
+    fn send_message(&self, message: Bytes) -> Result<(), SendError<Bytes>> {
         self.sender.send(message)
     }
 }
 
+fn message(id: Option<u64>, event: &str, data: &str) -> Bytes {
+    let id = id.map(|id| format!("id: {}\n", id)).unwrap_or_default();
+    Bytes::from([id.as_str(), "event: ", event, "\n", "data: ", data, "\n\n"].concat())
+}
+
+// ------ EventLog ------
+
+/// Numbered events of a resumable session.
+/// The client sends the id of the last received event on reconnection
+/// and the newer events are replayed to it.
+struct EventLog {
+    next_id: u64,
+    events: VecDeque<(u64, Bytes)>,
+    disconnected_at: Option<Instant>,
+}
+
+impl EventLog {
+    fn new() -> Self {
+        Self {
+            next_id: 1,
+            events: VecDeque::new(),
+            disconnected_at: None,
+        }
+    }
+
+    fn push(&mut self, event: &str, data: &str) -> Bytes {
+        let id = self.next_id;
+        self.next_id += 1;
+        let message = message(Some(id), event, data);
+        if self.events.len() == MAX_LOGGED_EVENTS {
+            self.events.pop_front();
+        }
+        self.events.push_back((id, message.clone()));
+        message
+    }
+
+    fn events_after(&self, last_event_id: u64) -> impl Iterator<Item = &Bytes> {
+        self.events
+            .iter()
+            .filter(move |(id, _)| *id > last_event_id)
+            .map(|(_, message)| message)
+    }
+}
+
 // ------ EventStream ------
 
 pub struct EventStream(UnboundedReceiver<Bytes>);
@@ -63,12 +116,14 @@ impl Stream for EventStream {
 
 pub struct SSE {
     connections: CHashMap<SessionId, Arc<Connection>>,
+    event_logs: CHashMap<SessionId, EventLog>,
 }
 
 impl SSE {
     pub fn start() -> ShareableSSE {
         let sse = SSE {
             connections: CHashMap::new(),
+            event_logs: CHashMap::new(),
         };
         let this = Arc::new(sse);
         this.spawn_connection_remover();
@@ -83,6 +138,14 @@ pub trait ShareableSSEMethods {
 
     fn new_connection(&self, session_id: Option<SessionId>) -> (Arc<Connection>, EventStream);
 
+    /// Creates a connection whose events are numbered and logged,
+    /// the logged events newer than `last_event_id` are sent to the new connection immediately.
+    fn new_resumable_connection(
+        &self,
+        session_id: SessionId,
+        last_event_id: Option<u64>,
+    ) -> (Arc<Connection>, EventStream);
+
     fn broadcast(&self, event: &str, data: &str) -> Result<(), Vec<SendError<Bytes>>>;
 
     fn send(
@@ -104,13 +167,23 @@ impl ShareableSSEMethods for ShareableSSE {
                 interval.tick().await;
                 this.connections.retain(|session_id, connection| {
                     let active = connection.send("ping", "").is_ok();
-                    if !active && connection.remove_session_actor_on_remove {
-                        if let Some(session_actor) = sessions::by_session_id().get(session_id) {
-                            session_actor.remove();
+                    if !active {
+                        if let Some(mut event_log) = this.event_logs.get_mut(session_id) {
+                            event_log.disconnected_at = Some(Instant::now());
+                        }
+                        if connection.remove_session_actor_on_remove {
+                            if let Some(session_actor) = sessions::by_session_id().get(session_id) {
+                                session_actor.remove();
+                            }
                         }
                     }
                     active
                 });
+                this.event_logs.retain(|_, event_log| {
+                    event_log.disconnected_at.map_or(true, |disconnected_at| {
+                        disconnected_at.elapsed() < EVENT_LOG_TTL
+                    })
+                });
             }
         });
     }
@@ -122,6 +195,31 @@ impl ShareableSSEMethods for ShareableSSE {
         (connection, event_stream)
     }
 
+    fn new_resumable_connection(
+        &self,
+        session_id: SessionId,
+        last_event_id: Option<u64>,
+    ) -> (Arc<Connection>, EventStream) {
+        let (connection, event_stream) = Connection::new(Some(session_id));
+        self.event_logs.upsert(session_id, EventLog::new, |_| ());
+        // The log is locked until the connection is registered
+        // so no event is lost or sent twice between the replay and `send`.
+        let mut event_log = self
+            .event_logs
+            .get_mut(&session_id)
+            .expect("event log inserted above");
+        event_log.disconnected_at = None;
+        if let Some(last_event_id) = last_event_id {
+            for message in event_log.events_after(last_event_id) {
+                // The receiver is in `event_stream`, it can't be closed yet.
+                let _ = connection.send_message(message.clone());
+            }
+        }
+        self.connections.insert(session_id, connection.clone());
+        drop(event_log);
+        (connection, event_stream)
+    }
+
     fn broadcast(&self, event: &str, data: &str) -> Result<(), Vec<SendError<Bytes>>> {
         let errors = RefCell::new(Vec::new());
         self.connections.retain(|_, connection| {
@@ -143,7 +241,13 @@ impl ShareableSSEMethods for ShareableSSE {
         event: &str,
         data: &str,
     ) -> Option<Result<(), SendError<Bytes>>> {
-        // @TODO Last-Event-Id
+        if let Some(mut event_log) = self.event_logs.get_mut(session_id) {
+            let message = event_log.push(event, data);
+            return self
+                .connections
+                .get(session_id)
+                .map(|connection| connection.send_message(message));
+        }
         self.connections
             .get(session_id)
             .map(|connection| connection.send(event, data))
@@ -151,6 +255,7 @@ impl ShareableSSEMethods for ShareableSSE {
 
     fn remove_connection(&self, session_id: &SessionId) {
         let connection = self.connections.remove(session_id);
+        self.event_logs.remove(session_id);
 
         if let Some(connection) = connection {
             if connection.remove_session_actor_on_remove {
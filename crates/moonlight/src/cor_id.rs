@@ -1,6 +1,17 @@
 use crate::*;
 use std::{fmt, str::FromStr};
 
+/// Correlation id - pairs an `UpMsg` with the `DownMsg`s sent in reaction to it.
+///
+/// Parse it from a known string with `str::parse` and write it back with `to_string`,
+/// e.g. in tests or to correlate the messages with external systems.
+/// ```
+/// use moonlight::CorId;
+///
+/// let cor_id: CorId = "01FQ8W3BQJ1PZ7R4N6ESWH5T4V".parse().unwrap();
+/// assert_eq!(cor_id.to_string(), "01FQ8W3BQJ1PZ7R4N6ESWH5T4V");
+/// assert!("invalid".parse::<CorId>().is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CorId(Ulid);
@@ -1,6 +1,17 @@
 use crate::*;
 use std::{fmt, str::FromStr};
 
+/// Identifies the frontend app instance. The backend sends `DownMsg`s
+/// to the session the `UpMsg` has been sent from.
+///
+/// Parse it from a known string with `str::parse` and write it back with `to_string`,
+/// e.g. in tests or when the id is assigned by the server.
+/// ```
+/// use moonlight::SessionId;
+///
+/// let session_id = SessionId::new();
+/// assert_eq!(session_id.to_string().parse::<SessionId>().unwrap(), session_id);
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SessionId(Ulid);
 
@@ -0,0 +1,145 @@
+use crate::*;
+use std::cell::{Cell, RefCell};
+
+/// Announcements made within this period are read together.
+const COALESCE_DELAY: u32 = 150;
+/// The announced text is removed after this period so it isn't read again
+/// when the user navigates through the page.
+const CLEAR_DELAY: u32 = 3000;
+
+// ------ Politeness ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Politeness {
+    /// Read when the user is idle, e.g. "5 results loaded".
+    Polite,
+    /// Read immediately, interrupting the current speech. Use only for urgent messages.
+    Assertive,
+}
+
+impl Politeness {
+    fn aria_live(self) -> &'static str {
+        match self {
+            Self::Polite => "polite",
+            Self::Assertive => "assertive",
+        }
+    }
+}
+
+// ------ announce ------
+
+/// Reads the message by screen readers without showing it on the page.
+///
+/// The messages are written to shared visually-hidden `aria-live` regions
+/// appended to `body` on the first call. Messages announced in quick succession
+/// are joined into one announcement, duplicates are skipped.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let results = vec!["MoonZoon", "Dominator"];
+/// a11y::announce(format!("{} results loaded", results.len()), a11y::Politeness::Polite);
+/// ```
+pub fn announce(message: impl Into<String>, politeness: Politeness) {
+    let message = message.into();
+    if message.trim().is_empty() {
+        return;
+    }
+    ANNOUNCER.with(|announcer| {
+        let region = announcer.region(politeness);
+        let mut pending_messages = region.pending_messages.borrow_mut();
+        if pending_messages.last() != Some(&message) {
+            pending_messages.push(message);
+        }
+        if not(region.flush_scheduled.replace(true)) {
+            // the previous timer has already fired, so it can be dropped
+            *region.flush_timer.borrow_mut() = Some(Timer::once(COALESCE_DELAY, move || {
+                ANNOUNCER.with(|announcer| announcer.region(politeness).flush())
+            }));
+        }
+    })
+}
+
+// ------ Announcer ------
+
+thread_local! {
+    static ANNOUNCER: Announcer = Announcer::new();
+}
+
+struct Announcer {
+    polite: LiveRegion,
+    assertive: LiveRegion,
+}
+
+impl Announcer {
+    fn new() -> Self {
+        Self {
+            polite: LiveRegion::new(Politeness::Polite),
+            assertive: LiveRegion::new(Politeness::Assertive),
+        }
+    }
+
+    fn region(&self, politeness: Politeness) -> &LiveRegion {
+        match politeness {
+            Politeness::Polite => &self.polite,
+            Politeness::Assertive => &self.assertive,
+        }
+    }
+}
+
+// ------ LiveRegion ------
+
+struct LiveRegion {
+    text: Mutable<String>,
+    pending_messages: RefCell<Vec<String>>,
+    flush_scheduled: Cell<bool>,
+    flush_timer: RefCell<Option<Timer>>,
+    clear_timer: RefCell<Option<Timer>>,
+}
+
+impl LiveRegion {
+    fn new(politeness: Politeness) -> Self {
+        let text = Mutable::new(String::new());
+        let region = RawHtmlEl::<web_sys::HtmlElement>::new("div")
+            .class("live_region")
+            .attr("aria-live", politeness.aria_live())
+            .attr("aria-atomic", "true")
+            // visually hidden, but still accessible
+            .style("position", "absolute")
+            .style("width", "1px")
+            .style("height", "1px")
+            .style("margin", "-1px")
+            .style("padding", "0")
+            .style("border", "0")
+            .style("overflow", "hidden")
+            .style("clip", "rect(0 0 0 0)")
+            .style("white-space", "nowrap")
+            .child_signal(text.signal_cloned());
+        dominator::append_dom(&dom::body(), region.into_raw_element().into_dom());
+        Self {
+            text,
+            pending_messages: RefCell::default(),
+            flush_scheduled: Cell::default(),
+            flush_timer: RefCell::default(),
+            clear_timer: RefCell::default(),
+        }
+    }
+
+    fn flush(&self) {
+        self.flush_scheduled.set(false);
+        let messages = self.pending_messages.take();
+        if messages.is_empty() {
+            return;
+        }
+        let mut text = messages.join(". ");
+        // screen readers ignore a region update that doesn't change its content
+        if *self.text.lock_ref() == text {
+            text.push('\u{00A0}');
+        }
+        self.text.set(text);
+
+        let text = self.text.clone();
+        *self.clear_timer.borrow_mut() =
+            Some(Timer::once(CLEAR_DELAY, move || text.set(String::new())));
+    }
+}
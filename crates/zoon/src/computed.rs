@@ -0,0 +1,146 @@
+use crate::*;
+use futures_util::task::noop_waker_ref;
+use std::{
+    cell::RefCell,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+type DependencySignal = Pin<Box<dyn Signal<Item = ()>>>;
+
+// ------ Computed ------
+
+/// A value derived from `Mutable`s. The `Mutable`s read by `compute` through the passed
+/// `Tracker` are tracked as dependencies and the value is recomputed
+/// only when one of them changes. Other `Computed`s can be dependencies as well.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let price = Mutable::new(20);
+/// let quantity = Mutable::new(3);
+/// let total = Computed::new({
+///     let price = price.clone();
+///     let quantity = quantity.clone();
+///     move |tracker| tracker.get(&price) * tracker.get(&quantity)
+/// });
+/// let total_text = Text::with_signal(total.signal());
+/// quantity.set(4);
+/// ```
+pub struct Computed<T> {
+    value: Mutable<T>,
+    _updater: TaskHandle,
+}
+
+impl<T: PartialEq + 'static> Computed<T> {
+    pub fn new(mut compute: impl FnMut(&Tracker) -> T + 'static) -> Self {
+        let (initial_value, mut dependencies) = Tracker::track(&mut compute);
+        let value = Mutable::new(initial_value);
+        let updater = Task::start_droppable({
+            let value = value.clone();
+            async move {
+                loop {
+                    let mut changes = futures_util::stream::select_all(
+                        dependencies
+                            .into_iter()
+                            .map(|dependency| dependency.to_stream()),
+                    );
+                    if changes.next().await.is_none() {
+                        break;
+                    }
+                    let (new_value, new_dependencies) = Tracker::track(&mut compute);
+                    value.set_neq(new_value);
+                    dependencies = new_dependencies;
+                }
+            }
+        });
+        Self {
+            value,
+            _updater: updater,
+        }
+    }
+
+    pub fn map<B>(&self, f: impl FnOnce(&T) -> B) -> B {
+        self.value.map(f)
+    }
+}
+
+impl<T: Copy + 'static> Computed<T> {
+    pub fn get(&self) -> T {
+        self.value.get()
+    }
+
+    pub fn signal(&self) -> MutableSignal<T> {
+        self.value.signal()
+    }
+}
+
+impl<T: Clone + 'static> Computed<T> {
+    pub fn get_cloned(&self) -> T {
+        self.value.get_cloned()
+    }
+
+    pub fn signal_cloned(&self) -> MutableSignalCloned<T> {
+        self.value.signal_cloned()
+    }
+}
+
+// ------ Tracker ------
+
+/// Reads `Mutable`s and `Computed`s and registers them as dependencies of the `Computed`
+/// being computed.
+#[derive(Default)]
+pub struct Tracker {
+    dependencies: RefCell<Vec<DependencySignal>>,
+}
+
+impl Tracker {
+    pub fn get<T: Copy + 'static>(&self, dependency: &impl Dependency<T>) -> T {
+        self.map(dependency, |value| *value)
+    }
+
+    pub fn get_cloned<T: Clone + 'static>(&self, dependency: &impl Dependency<T>) -> T {
+        self.map(dependency, T::clone)
+    }
+
+    pub fn map<T: 'static, B>(
+        &self,
+        dependency: &impl Dependency<T>,
+        f: impl FnOnce(&T) -> B,
+    ) -> B {
+        let mutable = dependency.mutable();
+        let mut signal: DependencySignal = Box::pin(mutable.signal_ref(|_| ()));
+        // The first poll returns the current value immediately,
+        // so only changes made after this read wake the `Computed`.
+        let poll = signal
+            .as_mut()
+            .poll_change(&mut Context::from_waker(noop_waker_ref()));
+        debug_assert!(matches!(poll, Poll::Ready(Some(()))));
+        self.dependencies.borrow_mut().push(signal);
+        mutable.map(f)
+    }
+
+    fn track<T>(compute: &mut impl FnMut(&Tracker) -> T) -> (T, Vec<DependencySignal>) {
+        let tracker = Tracker::default();
+        let value = compute(&tracker);
+        (value, tracker.dependencies.into_inner())
+    }
+}
+
+// ------ Dependency ------
+
+pub trait Dependency<T> {
+    fn mutable(&self) -> &Mutable<T>;
+}
+
+impl<T> Dependency<T> for Mutable<T> {
+    fn mutable(&self) -> &Mutable<T> {
+        self
+    }
+}
+
+impl<T> Dependency<T> for Computed<T> {
+    fn mutable(&self) -> &Mutable<T> {
+        &self.value
+    }
+}
@@ -1,30 +1,110 @@
 use crate::*;
-use moonlight::serde::{de::DeserializeOwned, Serialize};
+use futures_channel::mpsc;
 use moonlight::{serde_json, AuthToken, CorId, SessionId};
 use std::error::Error;
 use std::fmt;
 use std::marker::PhantomData;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, RwLock,
+};
 use web_sys::{Request, RequestInit, Response};
 
+mod offline_queue;
+use offline_queue::OfflineQueue;
+pub use offline_queue::QueueConfig;
+
 mod sse;
 use sse::SSE;
 
+#[cfg(feature = "serde")]
+use moonlight::serde::{de::DeserializeOwned, Serialize};
+
+#[cfg(not(any(feature = "serde", feature = "serde-lite")))]
+compile_error!("the `connection` feature requires the `serde` or the `serde-lite` feature");
+
+#[cfg(all(feature = "serde", feature = "serde-lite"))]
+compile_error!("the `serde` and `serde-lite` features are mutually exclusive");
+
+const RESERVED_HEADERS: [&str; 4] = [
+    "X-Correlation-ID",
+    "X-Session-ID",
+    "X-Auth-Token",
+    "X-Serde-Format",
+];
+
+/// Sent in the `X-Serde-Format` header so the backend can reject
+/// `UpMsg`s serialized by the other serde library.
+#[cfg(feature = "serde")]
+const SERDE_FORMAT: &str = "serde";
+#[cfg(feature = "serde-lite")]
+const SERDE_FORMAT: &str = "lite";
+
 // ------ Connection ------
 
 pub struct Connection<UMsg, DMsg> {
-    session_id: SessionId,
-    _sse: SSE,
-    auth_token_getter: Option<Box<dyn Fn() -> Option<AuthToken> + Send + Sync>>,
+    context: Arc<RequestContext>,
+    sse: SSE,
+    offline_queue: Option<Arc<OfflineQueue>>,
+    pending_up_msg_count: Mutable<usize>,
     msg_types: PhantomData<(UMsg, DMsg)>,
 }
 
-impl<UMsg: Serialize, DMsg: DeserializeOwned> Connection<UMsg, DMsg> {
+struct RequestContext {
+    session_id: SessionId,
+    auth_token_getter: RwLock<Option<Box<dyn Fn() -> Option<AuthToken> + Send + Sync>>>,
+    compress: AtomicBool,
+}
+
+impl<UMsg: SerializeUpMsg, DMsg: DeserializeDownMsg> Connection<UMsg, DMsg> {
     pub fn new(down_msg_handler: impl FnOnce(DMsg, CorId) + Clone + Send + Sync + 'static) -> Self {
+        Self::with_sse(|session_id| SSE::new(session_id, down_msg_handler))
+    }
+}
+
+impl<UMsg: SerializeUpMsg, DMsg> Connection<UMsg, DMsg> {
+    /// A `Connection` without a down-message handler,
+    /// all received down-messages are sent in order to the returned stream instead.
+    /// It allows to split down-messages by variant with standard stream combinators.
+    ///
+    /// It's a stream rather than a signal because signals skip intermediate values -
+    /// a signal polled after two down-messages have arrived would see only the second one.
+    /// Use `Mutable` and `signal_cloned` when only the latest down-message matters, e.g.
+    /// `Task::start(down_msgs.for_each(move |(down_msg, _)| { last.set(Some(down_msg)); async {} }))`.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let (connection, down_msgs) = Connection::<String, String>::with_down_msg_stream();
+    /// let greetings = down_msgs.filter(|(down_msg, _)| future::ready(down_msg.starts_with("Hello")));
+    /// Task::start(greetings.for_each(|(greeting, _)| {
+    ///     println!("{}", greeting);
+    ///     async {}
+    /// }));
+    /// ```
+    pub fn with_down_msg_stream() -> (Self, mpsc::UnboundedReceiver<(DMsg, CorId)>)
+    where
+        DMsg: DeserializeDownMsg + Send + 'static,
+    {
+        let (down_msg_sender, down_msg_receiver) = mpsc::unbounded();
+        let this = Self::new(move |down_msg, cor_id| {
+            // the receiver has been dropped when it fails
+            let _ = down_msg_sender.unbounded_send((down_msg, cor_id));
+        });
+        (this, down_msg_receiver)
+    }
+
+    fn with_sse(sse: impl FnOnce(SessionId) -> SSE) -> Self {
         let session_id = SessionId::new();
         Self {
-            session_id,
-            _sse: SSE::new(session_id, down_msg_handler),
-            auth_token_getter: None,
+            context: Arc::new(RequestContext {
+                session_id,
+                auth_token_getter: RwLock::new(None),
+                compress: AtomicBool::new(false),
+            }),
+            sse: sse(session_id),
+            offline_queue: None,
+            pending_up_msg_count: Mutable::new(0),
             msg_types: PhantomData,
         }
     }
@@ -37,74 +117,394 @@ impl<UMsg: Serialize, DMsg: DeserializeOwned> Connection<UMsg, DMsg> {
         IAT: Into<Option<AuthToken>>,
     {
         let getter = move || (getter.clone())().into();
-        self.auth_token_getter = Some(Box::new(getter));
+        *self.context.auth_token_getter.write().unwrap_throw() = Some(Box::new(getter));
+        self
+    }
+
+    /// Compresses up-message bodies with gzip (`CompressionStream`)
+    /// and sends them with the `Content-Encoding: gzip` header.
+    /// It reduces the size of large up-messages, e.g. big JSON documents sent over mobile networks.
+    ///
+    /// The backend has to decompress the request bodies, so it's disabled by default.
+    /// Bodies are sent uncompressed in browsers without `CompressionStream`.
+    ///
+    /// Down-messages are compressed by the backend or a proxy according to the browser's
+    /// `Accept-Encoding` header, the browser decompresses them transparently.
+    pub fn compress(self, compress: bool) -> Self {
+        self.context.compress.store(compress, Ordering::SeqCst);
+        self
+    }
+
+    /// Enables an outbox for up-messages sent while the device is offline
+    /// (`navigator.onLine` is `false`) or after repeated failed requests
+    /// (see `QueueConfig::failure_threshold`).
+    /// The messages are replayed in order once the device is back online.
+    ///
+    /// Queued messages are reported as `SendUpMsgError::Queued`.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let connection = Connection::<String, String>::new(|_, _| {})
+    ///     .offline_queue(QueueConfig::new().persist("outbox"));
+    ///
+    /// let pending_info = Text::with_signal(
+    ///     connection
+    ///         .pending_up_msg_count_signal()
+    ///         .map(|count| format!("{} changes pending sync", count)),
+    /// );
+    /// ```
+    pub fn offline_queue(mut self, config: QueueConfig) -> Self {
+        self.offline_queue = Some(OfflineQueue::new(
+            config,
+            Arc::clone(&self.context),
+            self.pending_up_msg_count.clone(),
+        ));
+        self
+    }
+
+    /// Asks the backend to replay the down-messages missed while the SSE connection was down.
+    ///
+    /// Call it right after `Connection::new`, the stream is opened with the `resume=true`
+    /// query parameter then. Moon numbers the down-messages of such a session, keeps the latest 256
+    /// and sends the ones newer than the `lastEventId` query parameter (or the `Last-Event-ID`
+    /// header) on reconnection. The log is dropped after a minute without a connection.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// #[static_ref]
+    /// fn connection() -> &'static Connection<String, String> {
+    ///     Connection::new(|down_msg, _| println!("{}", down_msg)).resume_down_msgs()
+    /// }
+    /// ```
+    pub fn resume_down_msgs(mut self) -> Self {
+        self.sse.enable_resumption();
         self
     }
 
+    /// Sent in the `X-Session-ID` header with each up-message,
+    /// the backend sends down-messages to this session.
+    pub fn session_id(&self) -> SessionId {
+        self.context.session_id
+    }
+
+    /// Resolves once the down-message stream (SSE) is open, i.e. once the backend
+    /// can deliver down-messages to this `Connection`'s session.
+    ///
+    /// The stream is opened in a task spawned by `Connection::new`, so a down-message sent
+    /// by the backend in reaction to an up-message sent before the stream is open
+    /// may get lost. Await `wait_ready` before sending the first up-message when it matters.
+    ///
+    /// Ordering guarantees:
+    /// - Down-messages are handled in the order the backend sends them.
+    /// - Up-messages are independent requests, so concurrently sent up-messages
+    ///   may be handled by the backend in any order. Await `send_up_msg` to keep the order.
+    /// - Down-messages sent while the stream is reconnecting are lost,
+    ///   unless they are replayed (see `resume_down_msgs`).
+    ///   The stream reconnects with an exponential backoff from 0.5 to 30 seconds.
+    ///   `wait_ready` resolves immediately when the stream is open,
+    ///   otherwise it waits for the (re)connection.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// #[static_ref]
+    /// fn connection() -> &'static Connection<String, String> {
+    ///     Connection::new(|down_msg, _| println!("{}", down_msg))
+    /// }
+    ///
+    /// Task::start(async {
+    ///     connection().wait_ready().await;
+    ///     if let Err(error) = connection().send_up_msg("Hello!".to_owned()).await {
+    ///         eprintln!("{}", error);
+    ///     }
+    /// });
+    /// ```
+    pub async fn wait_ready(&self) {
+        self.sse.open_signal().wait_for(true).await;
+    }
+
+    /// `true` while the down-message stream (SSE) is open, `false` while it's (re)connecting.
+    pub fn ready_signal(&self) -> impl Signal<Item = bool> {
+        self.sse.open_signal()
+    }
+
+    /// The id of the last received down-message event, if the backend sends event ids.
+    pub fn last_down_msg_event_id(&self) -> Option<String> {
+        self.sse.last_event_id()
+    }
+
+    /// The number of up-messages waiting in the offline queue.
+    pub fn pending_up_msg_count_signal(&self) -> impl Signal<Item = usize> {
+        self.pending_up_msg_count.signal()
+    }
+
     pub async fn send_up_msg(&self, up_msg: UMsg) -> Result<CorId, SendUpMsgError> {
-        // ---- RequestInit ----
-        #[cfg(feature = "serde-lite")]
-        let body = serde_json::to_string(&up_msg.serialize().unwrap_throw()).unwrap_throw();
-        #[cfg(feature = "serde")]
-        let body = serde_json::to_string(&up_msg).unwrap_throw();
+        self.send_up_msg_with_headers(up_msg, Vec::new()).await
+    }
 
-        let mut request_init = RequestInit::new();
-        request_init.method("POST").body(Some(&JsValue::from(body)));
+    /// Sends the `UpMsg` with additional request headers, e.g. an idempotency key
+    /// or a `traceparent` header for distributed tracing.
+    ///
+    /// Headers colliding with the reserved ones (`X-Correlation-ID`, `X-Session-ID`,
+    /// `X-Auth-Token`, `X-Serde-Format` and `Content-Encoding` while `compress` is enabled)
+    /// are ignored with a warning.
+    pub async fn send_up_msg_with_headers(
+        &self,
+        up_msg: UMsg,
+        custom_headers: Vec<(String, String)>,
+    ) -> Result<CorId, SendUpMsgError> {
+        let (cor_id, response) = self.fetch_up_msg(up_msg, custom_headers).await?;
+        if response.ok() {
+            return Ok(cor_id);
+        }
+        Err(SendUpMsgError::ResponseIsNot2xx)
+    }
 
-        // ---- Request ----
-        let request =
-            Request::new_with_str_and_init("/_api/up_msg_handler", &request_init).unwrap_throw();
+    /// Sends the `UpMsg` and returns the response status and headers
+    /// (e.g. rate-limit headers or a server-assigned id).
+    ///
+    /// Unlike `send_up_msg`, non-2xx responses are returned as `Ok`
+    /// so their headers can be read too.
+    pub async fn send_up_msg_full(&self, up_msg: UMsg) -> Result<UpMsgResponse, SendUpMsgError> {
+        let (cor_id, response) = self.fetch_up_msg(up_msg, Vec::new()).await?;
+        Ok(UpMsgResponse {
+            cor_id,
+            status: response.status(),
+            headers: response.headers(),
+        })
+    }
 
-        // ---- Headers ----
+    async fn fetch_up_msg(
+        &self,
+        up_msg: UMsg,
+        custom_headers: Vec<(String, String)>,
+    ) -> Result<(CorId, Response), SendUpMsgError> {
+        let body = up_msg.to_json().map_err(SendUpMsgError::Serialization)?;
         let cor_id = CorId::new();
-        let headers = request.headers();
-        headers
-            .set("X-Correlation-ID", &cor_id.to_string())
-            .unwrap_throw();
+
+        if let Some(offline_queue) = &self.offline_queue {
+            if offline_queue.should_enqueue() {
+                offline_queue.enqueue(cor_id, body, custom_headers);
+                return Err(SendUpMsgError::Queued(cor_id));
+            }
+        }
+        match fetch(&self.context, cor_id, &body, &custom_headers).await {
+            Ok(response) => {
+                if let Some(offline_queue) = &self.offline_queue {
+                    offline_queue.request_succeeded();
+                }
+                Ok((cor_id, response))
+            }
+            Err(SendUpMsgError::RequestFailed(error)) => match &self.offline_queue {
+                Some(offline_queue) if offline_queue.request_failed() => {
+                    offline_queue.enqueue(cor_id, body, custom_headers);
+                    Err(SendUpMsgError::Queued(cor_id))
+                }
+                _ => Err(SendUpMsgError::RequestFailed(error)),
+            },
+            Err(error) => Err(error),
+        }
+    }
+}
+
+// ------ fetch ------
+
+async fn fetch(
+    context: &RequestContext,
+    cor_id: CorId,
+    body: &str,
+    custom_headers: &[(String, String)],
+) -> Result<Response, SendUpMsgError> {
+    // ---- Body ----
+    let compress = context.compress.load(Ordering::SeqCst);
+    let compressed_body = if compress {
+        match gzip(body).await {
+            Ok(compressed_body) => Some(compressed_body),
+            Err(error) => {
+                crate::zwarn!("UpMsg body not compressed: {:?}", error);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    // ---- RequestInit ----
+    let mut request_init = RequestInit::new();
+    match &compressed_body {
+        Some(compressed_body) => request_init
+            .method("POST")
+            .body(Some(compressed_body.as_ref())),
+        None => request_init.method("POST").body(Some(&JsValue::from(body))),
+    };
+
+    // ---- Request ----
+    let request =
+        Request::new_with_str_and_init("/_api/up_msg_handler", &request_init).unwrap_throw();
+
+    // ---- Headers ----
+    let headers = request.headers();
+    headers
+        .set("X-Correlation-ID", &cor_id.to_string())
+        .unwrap_throw();
+    headers
+        .set("X-Session-ID", &context.session_id.to_string())
+        .unwrap_throw();
+    headers.set("X-Serde-Format", SERDE_FORMAT).unwrap_throw();
+    if compressed_body.is_some() {
+        headers.set("Content-Encoding", "gzip").unwrap_throw();
+    }
+
+    let auth_token = context
+        .auth_token_getter
+        .read()
+        .unwrap_throw()
+        .as_ref()
+        .and_then(|auth_token_getter| auth_token_getter());
+    if let Some(auth_token) = auth_token {
         headers
-            .set("X-Session-ID", &self.session_id.to_string())
+            .set("X-Auth-Token", auth_token.as_str())
             .unwrap_throw();
+    }
 
-        let auth_token = self
-            .auth_token_getter
-            .as_ref()
-            .and_then(|auth_token_getter| auth_token_getter());
-        if let Some(auth_token) = auth_token {
-            headers
-                .set("X-Auth-Token", auth_token.as_str())
-                .unwrap_throw();
+    for (name, value) in custom_headers {
+        // `Content-Encoding` is reserved only while compression is enabled
+        if RESERVED_HEADERS
+            .iter()
+            .any(|reserved| reserved.eq_ignore_ascii_case(name))
+            || (compress && name.eq_ignore_ascii_case("Content-Encoding"))
+        {
+            crate::zwarn!("reserved UpMsg header `{}` ignored", name);
+            continue;
         }
+        headers
+            .set(name, value)
+            .map_err(SendUpMsgError::RequestFailed)?;
+    }
 
-        // ---- Response ----
-        let response = JsFuture::from(window().fetch_with_request(&request))
-            .await
-            .map_err(|error| SendUpMsgError::RequestFailed(error))?
-            .unchecked_into::<Response>();
+    // ---- Response ----
+    let response = JsFuture::from(window().fetch_with_request(&request))
+        .await
+        .map_err(|error| SendUpMsgError::RequestFailed(error))?
+        .unchecked_into::<Response>();
 
-        if response.ok() {
-            return Ok(cor_id);
-        }
-        Err(SendUpMsgError::ResponseIsNot2xx)
+    Ok(response)
+}
+
+// ------ gzip ------
+
+async fn gzip(body: &str) -> Result<js_sys::Uint8Array, JsValue> {
+    // fails in browsers without `CompressionStream`
+    let compression_stream = CompressionStream::new("gzip")?;
+    let stream = Response::new_with_opt_str(Some(body))?
+        .body()
+        .expect_throw("Response created from a string has a body");
+    let compressed_stream = stream
+        .unchecked_into::<PipeableStream>()
+        .pipe_through(&compression_stream);
+    let compressed = Response::new_with_opt_readable_stream(Some(&compressed_stream))?;
+    let buffer = JsFuture::from(compressed.array_buffer()?).await?;
+    Ok(js_sys::Uint8Array::new(&buffer))
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = js_sys::Object)]
+    type CompressionStream;
+
+    #[wasm_bindgen(constructor, catch)]
+    fn new(format: &str) -> Result<CompressionStream, JsValue>;
+
+    #[wasm_bindgen(extends = js_sys::Object, js_name = ReadableStream)]
+    type PipeableStream;
+
+    #[wasm_bindgen(method, js_class = "ReadableStream", js_name = pipeThrough)]
+    fn pipe_through(
+        this: &PipeableStream,
+        transform: &CompressionStream,
+    ) -> web_sys::ReadableStream;
+}
+
+// ------ UpMsgResponse ------
+
+pub struct UpMsgResponse {
+    pub cor_id: CorId,
+    pub status: u16,
+    headers: web_sys::Headers,
+}
+
+impl UpMsgResponse {
+    /// Header names are case-insensitive.
+    pub fn header(&self, name: &str) -> Option<String> {
+        self.headers.get(name).ok().flatten()
+    }
+
+    pub fn is_2xx(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+// ------ SerializeUpMsg ------
+
+/// Implemented for all `UpMsg`s serializable by the enabled `serde` or `serde-lite` feature
+/// so all request paths serialize messages the same way.
+pub trait SerializeUpMsg {
+    fn to_json(&self) -> Result<String, String>;
+}
+
+#[cfg(feature = "serde")]
+impl<T: Serialize> SerializeUpMsg for T {
+    fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string(self).map_err(|error| error.to_string())
+    }
+}
+#[cfg(feature = "serde-lite")]
+impl<T: Serialize> SerializeUpMsg for T {
+    fn to_json(&self) -> Result<String, String> {
+        let intermediate = self.serialize().map_err(|error| error.to_string())?;
+        serde_json::to_string(&intermediate).map_err(|error| error.to_string())
     }
 }
 
+/// Implemented for all `DownMsg`s deserializable by the enabled `serde` or `serde-lite` feature.
+#[cfg(feature = "serde")]
+pub trait DeserializeDownMsg: DeserializeOwned {}
+#[cfg(feature = "serde")]
+impl<T: DeserializeOwned> DeserializeDownMsg for T {}
+#[cfg(feature = "serde-lite")]
+pub trait DeserializeDownMsg: Deserialize {}
+#[cfg(feature = "serde-lite")]
+impl<T: Deserialize> DeserializeDownMsg for T {}
+
 // ------ SendUpMsgError ------
 
 #[derive(Debug)]
 pub enum SendUpMsgError {
+    /// The `UpMsg` can't be serialized, e.g. because it contains a non-finite float.
+    Serialization(String),
     RequestFailed(JsValue),
     ResponseIsNot2xx,
+    /// The `UpMsg` has been put into the offline queue, see `Connection::offline_queue`.
+    Queued(CorId),
 }
 
 impl fmt::Display for SendUpMsgError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            SendUpMsgError::Serialization(error) => {
+                write!(f, "failed to serialize UpMsg: {}", error)
+            }
             SendUpMsgError::RequestFailed(error) => {
                 write!(f, "request failed: {:?}", error)
             }
             SendUpMsgError::ResponseIsNot2xx => {
                 write!(f, "response status is not 2xx")
             }
+            SendUpMsgError::Queued(cor_id) => {
+                write!(f, "UpMsg {} queued until the device is online", cor_id)
+            }
         }
     }
 }
@@ -0,0 +1,297 @@
+use super::{fetch, RequestContext, SendUpMsgError};
+use crate::{network::is_online, *};
+use moonlight::{
+    serde_json::{self, json, Value},
+    CorId,
+};
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Arc, Mutex, Weak,
+    },
+};
+
+const DEFAULT_FAILURE_THRESHOLD: u32 = 3;
+const FIRST_RETRY_DELAY: u32 = 1_000;
+const MAX_RETRY_DELAY: u32 = 30_000;
+
+// ------ QueueConfig ------
+
+/// Configures the `Connection`'s outbox for up-messages sent while offline.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let config = QueueConfig::new()
+///     .persist("my_app_outbox")
+///     .failure_threshold(5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct QueueConfig {
+    storage_key: Option<String>,
+    failure_threshold: u32,
+}
+
+impl Default for QueueConfig {
+    fn default() -> Self {
+        Self {
+            storage_key: None,
+            failure_threshold: DEFAULT_FAILURE_THRESHOLD,
+        }
+    }
+}
+
+impl QueueConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps the queued up-messages in the local storage under the `storage_key`
+    /// so they are replayed after a page reload as well.
+    pub fn persist(mut self, storage_key: impl Into<String>) -> Self {
+        self.storage_key = Some(storage_key.into());
+        self
+    }
+
+    /// Up-messages are queued even while `navigator.onLine` is `true`
+    /// after `failure_threshold` requests in a row have failed, 3 by default.
+    /// The requests that have reached the threshold are queued as well.
+    pub fn failure_threshold(mut self, failure_threshold: u32) -> Self {
+        self.failure_threshold = failure_threshold.max(1);
+        self
+    }
+}
+
+// ------ OfflineQueue ------
+
+pub(super) struct OfflineQueue {
+    messages: Mutex<VecDeque<QueuedUpMsg>>,
+    pending_count: Mutable<usize>,
+    storage_key: Option<String>,
+    failure_threshold: u32,
+    failure_count: AtomicU32,
+    replaying: AtomicBool,
+    context: Arc<RequestContext>,
+    online_listener: Mutex<Option<SendWrapper<Closure<dyn Fn()>>>>,
+}
+
+impl OfflineQueue {
+    pub(super) fn new(
+        config: QueueConfig,
+        context: Arc<RequestContext>,
+        pending_count: Mutable<usize>,
+    ) -> Arc<Self> {
+        let messages = config
+            .storage_key
+            .as_deref()
+            .map(load_messages)
+            .unwrap_or_default();
+        pending_count.set(messages.len());
+
+        let queue = Arc::new(Self {
+            messages: Mutex::new(messages),
+            pending_count,
+            storage_key: config.storage_key,
+            failure_threshold: config.failure_threshold,
+            failure_count: AtomicU32::new(0),
+            replaying: AtomicBool::new(false),
+            context,
+            online_listener: Mutex::new(None),
+        });
+
+        let weak_queue = Arc::downgrade(&queue);
+        let online_listener = Closure::wrap(Box::new(move || {
+            if let Some(queue) = Weak::upgrade(&weak_queue) {
+                queue.replay();
+            }
+        }) as Box<dyn Fn()>);
+        window()
+            .add_event_listener_with_callback("online", online_listener.as_ref().unchecked_ref())
+            .unwrap_throw();
+        *queue.online_listener.lock().unwrap_throw() = Some(SendWrapper::new(online_listener));
+
+        queue.replay();
+        queue
+    }
+
+    /// Up-messages have to wait in the queue while the device is offline,
+    /// after repeated failed requests or while older up-messages are waiting, to keep their order.
+    pub(super) fn should_enqueue(&self) -> bool {
+        not(is_online())
+            || self.failure_threshold_reached()
+            || not(self.messages.lock().unwrap_throw().is_empty())
+    }
+
+    /// Returns `true` if the failed up-message should be queued.
+    pub(super) fn request_failed(&self) -> bool {
+        self.failure_count.fetch_add(1, Ordering::SeqCst);
+        not(is_online()) || self.failure_threshold_reached()
+    }
+
+    pub(super) fn request_succeeded(&self) {
+        self.failure_count.store(0, Ordering::SeqCst);
+    }
+
+    fn failure_threshold_reached(&self) -> bool {
+        self.failure_count.load(Ordering::SeqCst) >= self.failure_threshold
+    }
+
+    pub(super) fn enqueue(
+        self: &Arc<Self>,
+        cor_id: CorId,
+        body: String,
+        custom_headers: Vec<(String, String)>,
+    ) {
+        {
+            let mut messages = self.messages.lock().unwrap_throw();
+            messages.push_back(QueuedUpMsg {
+                cor_id,
+                body,
+                custom_headers,
+            });
+            self.messages_changed(&messages);
+        }
+        self.replay();
+    }
+
+    /// Sends the queued up-messages in order until the queue is empty
+    /// or the device goes offline.
+    /// Requests failed while online are retried with an exponential backoff.
+    fn replay(self: &Arc<Self>) {
+        if not(is_online()) || self.replaying.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let queue = Arc::clone(self);
+        Task::start(async move {
+            let mut retry_delay = FIRST_RETRY_DELAY;
+            loop {
+                let message = queue.messages.lock().unwrap_throw().front().cloned();
+                let message = match message {
+                    Some(message) => message,
+                    None => break,
+                };
+                match fetch(
+                    &queue.context,
+                    message.cor_id,
+                    &message.body,
+                    &message.custom_headers,
+                )
+                .await
+                {
+                    Err(SendUpMsgError::RequestFailed(_)) => {
+                        queue.failure_count.fetch_add(1, Ordering::SeqCst);
+                        if not(is_online()) {
+                            // the `online` listener restarts the replay
+                            break;
+                        }
+                        Timer::sleep(retry_delay).await;
+                        retry_delay = (retry_delay * 2).min(MAX_RETRY_DELAY);
+                        continue;
+                    }
+                    Err(error) => crate::zerror!("queued UpMsg dropped: {}", error),
+                    Ok(response) if not(response.ok()) => {
+                        crate::zwarn!("queued UpMsg rejected with status {}", response.status());
+                    }
+                    Ok(_) => (),
+                }
+                queue.request_succeeded();
+                retry_delay = FIRST_RETRY_DELAY;
+                let mut messages = queue.messages.lock().unwrap_throw();
+                messages.pop_front();
+                queue.messages_changed(&messages);
+            }
+            queue.replaying.store(false, Ordering::SeqCst);
+        });
+    }
+
+    fn messages_changed(&self, messages: &VecDeque<QueuedUpMsg>) {
+        self.pending_count.set_neq(messages.len());
+        if let Some(storage_key) = &self.storage_key {
+            store_messages(storage_key, messages);
+        }
+    }
+}
+
+impl Drop for OfflineQueue {
+    fn drop(&mut self) {
+        if let Some(online_listener) = self.online_listener.lock().unwrap_throw().take() {
+            window()
+                .remove_event_listener_with_callback(
+                    "online",
+                    online_listener.as_ref().unchecked_ref(),
+                )
+                .unwrap_throw();
+        }
+    }
+}
+
+// ------ QueuedUpMsg ------
+
+#[derive(Clone)]
+struct QueuedUpMsg {
+    cor_id: CorId,
+    body: String,
+    custom_headers: Vec<(String, String)>,
+}
+
+impl QueuedUpMsg {
+    fn to_json(&self) -> Value {
+        json!({
+            "cor_id": self.cor_id.to_string(),
+            "body": self.body,
+            "custom_headers": self.custom_headers,
+        })
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        let custom_headers = value["custom_headers"]
+            .as_array()?
+            .iter()
+            .map(|header| {
+                Some((
+                    header[0].as_str()?.to_owned(),
+                    header[1].as_str()?.to_owned(),
+                ))
+            })
+            .collect::<Option<_>>()?;
+        Some(Self {
+            cor_id: value["cor_id"].as_str()?.parse().ok()?,
+            body: value["body"].as_str()?.to_owned(),
+            custom_headers,
+        })
+    }
+}
+
+// ------ helpers ------
+
+fn local_storage() -> Option<web_sys::Storage> {
+    window().local_storage().ok().flatten()
+}
+
+fn load_messages(storage_key: &str) -> VecDeque<QueuedUpMsg> {
+    let messages = local_storage()
+        .and_then(|storage| storage.get_item(storage_key).ok().flatten())
+        .and_then(|messages| serde_json::from_str::<Vec<Value>>(&messages).ok())
+        .unwrap_or_default();
+    messages.iter().filter_map(QueuedUpMsg::from_json).collect()
+}
+
+fn store_messages(storage_key: &str, messages: &VecDeque<QueuedUpMsg>) {
+    let storage = match local_storage() {
+        Some(storage) => storage,
+        None => return,
+    };
+    let result = if messages.is_empty() {
+        storage.remove_item(storage_key)
+    } else {
+        let messages = messages
+            .iter()
+            .map(QueuedUpMsg::to_json)
+            .collect::<Vec<_>>();
+        storage.set_item(storage_key, &Value::from(messages).to_string())
+    };
+    if let Err(error) = result {
+        crate::zerror!("failed to store queued UpMsgs: {:?}", error);
+    }
+}
@@ -1,20 +1,33 @@
 use crate::moonlight::{serde_json, CorId, DeserializeOwned, DownMsgTransporterForDe, SessionId};
+use crate::routing::encode_uri_component;
 use crate::{format, *};
-use std::{error::Error, fmt};
+use std::{
+    cell::{Cell, RefCell},
+    error::Error,
+    fmt,
+    rc::{Rc, Weak},
+};
+use web_sys::EventSource;
 
 #[cfg(feature = "moonlight/serde_with_serde")]
 use moonlight::serde::{self, Deserialize};
 
+const FIRST_RECONNECT_DELAY: u32 = 500;
+const MAX_RECONNECT_DELAY: u32 = 30_000;
+
 // ------ SSE ------
 
+/// The down-message stream.
+///
+/// The `EventSource` is closed on every error and a new one is opened after
+/// an exponential backoff delay, so the browser's own fixed-delay reconnection never kicks in.
 pub struct SSE {
-    reconnecting_event_source: SendWrapper<ReconnectingEventSource>,
-    _down_msg_handler: SendWrapper<Closure<dyn Fn(JsValue)>>,
+    source: SendWrapper<Rc<Source>>,
 }
 
 impl Drop for SSE {
     fn drop(&mut self) {
-        self.reconnecting_event_source.close();
+        self.source.close();
     }
 }
 
@@ -24,35 +37,170 @@ impl SSE {
         session_id: SessionId,
         down_msg_handler: impl FnOnce(DMsg, CorId) + Clone + 'static,
     ) -> Self {
-        let down_msg_handler = down_msg_handler_closure(down_msg_handler);
-
-        let reconnecting_event_source = connect(session_id);
-        reconnecting_event_source
-            .add_event_listener("down_msg", down_msg_handler.as_ref().unchecked_ref());
-
-        Self {
-            reconnecting_event_source: SendWrapper::new(reconnecting_event_source),
-            _down_msg_handler: SendWrapper::new(down_msg_handler),
-        }
+        Self::with_down_msg_handler(session_id, down_msg_handler_closure(down_msg_handler))
     }
     #[cfg(feature = "serde-lite")]
     pub fn new<DMsg: Deserialize>(
         session_id: SessionId,
         down_msg_handler: impl FnOnce(DMsg, CorId) + Clone + 'static,
     ) -> Self {
-        let down_msg_handler = down_msg_handler_closure(down_msg_handler);
-
-        let reconnecting_event_source = connect(session_id);
-        reconnecting_event_source
-            .add_event_listener("down_msg", down_msg_handler.as_ref().unchecked_ref());
+        Self::with_down_msg_handler(session_id, down_msg_handler_closure(down_msg_handler))
+    }
 
+    /// The `EventSource` is opened in a spawned task
+    /// so builder methods like `enable_resumption` apply to the first and only connection.
+    fn with_down_msg_handler(
+        session_id: SessionId,
+        down_msg_handler: Closure<dyn Fn(JsValue)>,
+    ) -> Self {
+        let source = Source::new(session_id, down_msg_handler);
+        let weak_source = Rc::downgrade(&source);
+        Task::start(async move {
+            if let Some(source) = weak_source.upgrade() {
+                source.connect();
+            }
+        });
         Self {
-            reconnecting_event_source: SendWrapper::new(reconnecting_event_source),
-            _down_msg_handler: SendWrapper::new(down_msg_handler),
+            source: SendWrapper::new(source),
+        }
+    }
+
+    /// `true` while the event stream is open, `false` while connecting or reconnecting.
+    pub fn open_signal(&self) -> impl Signal<Item = bool> {
+        self.source.open.signal()
+    }
+
+    /// Adds the `resume` flag and the last received event id to the URL
+    /// so the backend numbers the down-messages and replays the missed ones on reconnection.
+    pub fn enable_resumption(&mut self) {
+        if self.source.resume.replace(true) {
+            return;
+        }
+        if self.source.event_source.borrow().is_some() {
+            crate::zwarn!("SSE resumption enabled after the connection has been opened, it applies from the next reconnection");
+        }
+    }
+
+    pub fn last_event_id(&self) -> Option<String> {
+        self.source.last_event_id.borrow().clone()
+    }
+}
+
+// ------ Source ------
+
+struct Source {
+    session_id: SessionId,
+    resume: Cell<bool>,
+    event_source: RefCell<Option<EventSource>>,
+    listeners: RefCell<Vec<(&'static str, Closure<dyn Fn(JsValue)>)>>,
+    last_event_id: RefCell<Option<String>>,
+    open: Mutable<bool>,
+    failed_attempts: Cell<u32>,
+    reconnect_timer: RefCell<Option<Timer>>,
+}
+
+impl Source {
+    fn new(session_id: SessionId, down_msg_handler: Closure<dyn Fn(JsValue)>) -> Rc<Self> {
+        let this = Rc::new(Self {
+            session_id,
+            resume: Cell::new(false),
+            event_source: RefCell::new(None),
+            listeners: RefCell::new(Vec::new()),
+            last_event_id: RefCell::new(None),
+            open: Mutable::new(false),
+            failed_attempts: Cell::new(0),
+            reconnect_timer: RefCell::new(None),
+        });
+        let listeners = vec![
+            ("open", listener(&this, Source::on_open)),
+            ("error", listener(&this, Source::on_error)),
+            ("down_msg", listener(&this, Source::track_event_id)),
+            ("down_msg", down_msg_handler),
+        ];
+        *this.listeners.borrow_mut() = listeners;
+        this
+    }
+
+    fn connect(self: &Rc<Self>) {
+        let mut url = format!("/_api/message_sse/{}", self.session_id);
+        if self.resume.get() {
+            url.push_str("?resume=true");
+            if let Some(last_event_id) = self.last_event_id.borrow().as_deref() {
+                url.push_str("&lastEventId=");
+                url.push_str(&encode_uri_component(last_event_id));
+            }
+        }
+        let event_source = EventSource::new(&url).unwrap_throw();
+        for (event_type, listener) in self.listeners.borrow().iter() {
+            event_source
+                .add_event_listener_with_callback(event_type, listener.as_ref().unchecked_ref())
+                .unwrap_throw();
+        }
+        *self.event_source.borrow_mut() = Some(event_source);
+    }
+
+    fn close(&self) {
+        self.reconnect_timer.borrow_mut().take();
+        if let Some(event_source) = self.event_source.borrow_mut().take() {
+            event_source.close();
+        }
+        self.open.set_neq(false);
+    }
+
+    fn on_open(self: &Rc<Self>, _: JsValue) {
+        self.failed_attempts.set(0);
+        self.open.set_neq(true);
+    }
+
+    fn on_error(self: &Rc<Self>, _: JsValue) {
+        self.close();
+        let failed_attempts = self.failed_attempts.get();
+        self.failed_attempts.set(failed_attempts.saturating_add(1));
+        let delay = randomized(backoff_delay(failed_attempts));
+        let weak_this = Rc::downgrade(self);
+        let timer = Timer::once(delay, move || {
+            if let Some(this) = weak_this.upgrade() {
+                this.connect();
+            }
+        });
+        *self.reconnect_timer.borrow_mut() = Some(timer);
+    }
+
+    /// Events sent with an `id` field update the last event id.
+    fn track_event_id(self: &Rc<Self>, event: JsValue) {
+        let event_id = Reflect::get(&event, &JsValue::from("lastEventId"))
+            .ok()
+            .and_then(|event_id| event_id.as_string())
+            .filter(|event_id| not(event_id.is_empty()));
+        if event_id.is_some() {
+            *self.last_event_id.borrow_mut() = event_id;
         }
     }
 }
 
+fn listener(source: &Rc<Source>, handler: fn(&Rc<Source>, JsValue)) -> Closure<dyn Fn(JsValue)> {
+    let source: Weak<Source> = Rc::downgrade(source);
+    Closure::wrap(Box::new(move |event: JsValue| {
+        if let Some(source) = source.upgrade() {
+            handler(&source, event)
+        }
+    }) as Box<dyn Fn(JsValue)>)
+}
+
+/// The delay doubles with every failed attempt up to `MAX_RECONNECT_DELAY` ms.
+fn backoff_delay(failed_attempts: u32) -> u32 {
+    FIRST_RECONNECT_DELAY
+        .saturating_mul(1 << failed_attempts.min(16))
+        .min(MAX_RECONNECT_DELAY)
+}
+
+/// Randomizes the second half of the delay so clients disconnected
+/// by the same server restart don't reconnect all at once.
+fn randomized(delay: u32) -> u32 {
+    let half = delay / 2;
+    half + (js_sys::Math::random() * f64::from(delay - half)) as u32
+}
+
 #[cfg(feature = "serde")]
 fn down_msg_handler_closure<DMsg: DeserializeOwned>(
     down_msg_handler: impl FnOnce(DMsg, CorId) + Clone + 'static,
@@ -61,7 +209,7 @@ fn down_msg_handler_closure<DMsg: DeserializeOwned>(
     Closure::wrap(Box::new(
         move |event: JsValue| match down_msg_transporter_from_event(event) {
             Ok(DownMsgTransporterForDe { down_msg, cor_id }) => down_msg_handler(down_msg, cor_id),
-            Err(error) => crate::eprintln!("{:?}", error),
+            Err(error) => crate::zerror!("{:?}", error),
         },
     ) as Box<dyn Fn(JsValue)>)
 }
@@ -73,7 +221,7 @@ fn down_msg_handler_closure<DMsg: Deserialize>(
     Closure::wrap(Box::new(
         move |event: JsValue| match down_msg_transporter_from_event(event) {
             Ok(DownMsgTransporterForDe { down_msg, cor_id }) => down_msg_handler(down_msg, cor_id),
-            Err(error) => crate::eprintln!("{:?}", error),
+            Err(error) => crate::zerror!("{:?}", error),
         },
     ) as Box<dyn Fn(JsValue)>)
 }
@@ -105,16 +253,6 @@ fn down_msg_transporter_from_event<DMsg: Deserialize>(
     .map_err(DownMsgError::DeserializationFailed)
 }
 
-fn connect(session_id: SessionId) -> ReconnectingEventSource {
-    ReconnectingEventSource::new(
-        &format!("/_api/message_sse/{}", session_id),
-        Some(ReconnectingEventSourceOptions {
-            withCredentials: false,
-            max_retry_time: 5000,
-        }),
-    )
-}
-
 // ------ DownMsgError ------
 
 #[derive(Debug)]
@@ -147,27 +285,3 @@ impl fmt::Display for DownMsgError {
 }
 
 impl Error for DownMsgError {}
-
-// ------ ReconnectingEventSource ------
-
-#[wasm_bindgen]
-extern "C" {
-    type ReconnectingEventSource;
-
-    #[wasm_bindgen(constructor)]
-    fn new(url: &str, options: Option<ReconnectingEventSourceOptions>) -> ReconnectingEventSource;
-
-    #[wasm_bindgen(method, js_name = addEventListener)]
-    fn add_event_listener(this: &ReconnectingEventSource, type_: &str, listener: &js_sys::Function);
-
-    #[wasm_bindgen(method)]
-    fn close(this: &ReconnectingEventSource);
-}
-
-#[wasm_bindgen]
-#[allow(non_snake_case)]
-#[allow(dead_code)]
-struct ReconnectingEventSourceOptions {
-    withCredentials: bool,
-    max_retry_time: u32,
-}
@@ -2,9 +2,10 @@ use crate::*;
 
 // ------ println ------
 
+/// Alias for `zinfo!`.
 #[macro_export]
 macro_rules! println {
-    ($($arg:tt)*) => ($crate::console::log(&$crate::format!($($arg)*)))
+    ($($arg:tt)*) => ($crate::zinfo!($($arg)*))
 }
 
 #[wasm_bindgen]
@@ -15,9 +16,10 @@ extern "C" {
 
 // ------ eprintln ------
 
+/// Alias for `zerror!`.
 #[macro_export]
 macro_rules! eprintln {
-    ($($arg:tt)*) => ($crate::console::error(&$crate::format!($($arg)*)))
+    ($($arg:tt)*) => ($crate::zerror!($($arg)*))
 }
 
 #[wasm_bindgen]
@@ -25,3 +27,14 @@ extern "C" {
     #[wasm_bindgen(js_namespace = console)]
     pub fn error(input: &str);
 }
+
+// ------ debug, warn ------
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = console)]
+    pub fn debug(input: &str);
+
+    #[wasm_bindgen(js_namespace = console)]
+    pub fn warn(input: &str);
+}
@@ -7,6 +7,8 @@ use std::borrow::Cow;
 //  IntoCowStr
 // ------ ------
 
+/// Implemented for strings, numbers, `bool`, `char`, `HSLuv`
+/// and style values like `Transition` or `Shadow`.
 pub trait IntoCowStr<'a> {
     fn into_cow_str(self) -> Cow<'a, str>;
 
@@ -61,12 +63,16 @@ macro_rules! make_into_cow_str_impls {
         )*
     )
 }
-make_into_cow_str_impls!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+make_into_cow_str_impls!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char
+);
 
 // -------- --------
 // IntoOptionCowStr
 // -------- --------
 
+/// Implemented for all `IntoCowStr` types and their `Option`s.
+/// `None` removes the value, e.g. a style property set by a signal.
 pub trait IntoOptionCowStr<'a>: BoxedIntoOptionCowStr<'a> {
     fn into_option_cow_str(self) -> Option<Cow<'a, str>>;
 
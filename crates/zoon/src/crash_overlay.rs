@@ -0,0 +1,48 @@
+use crate::*;
+use std::{borrow::Cow, panic};
+
+/// Shows an overlay with the `message` and the panic message when the app panics
+/// instead of leaving the page frozen and blank. The panic is still logged.
+///
+/// Note: It isn't an error boundary. Panics can't be caught in Wasm - they abort the app,
+/// so the rest of the app stops responding as well. The overlay is rendered
+/// by the panic hook with plain DOM calls just before the app is aborted.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// set_crash_overlay("Something went wrong. Please reload the page.");
+/// start_app("app", || El::new().child("Hello!"));
+/// ```
+pub fn set_crash_overlay(message: impl Into<Cow<'static, str>>) {
+    // `start_app` would replace the hook set below otherwise
+    #[cfg(feature = "panic_hook")]
+    #[cfg(debug_assertions)]
+    console_error_panic_hook::set_once();
+
+    let message = message.into();
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |panic_info| {
+        previous_hook(panic_info);
+        show_overlay(&message, &panic_info.to_string());
+    }));
+}
+
+fn show_overlay(message: &str, panic_message: &str) {
+    // Zoon elements can't be used, the panic may have happened while they were borrowed.
+    let document = document();
+    let overlay = match document.create_element("div") {
+        Ok(overlay) => overlay,
+        Err(_) => return,
+    };
+    let _ = overlay.set_attribute("role", "alert");
+    let _ = overlay.set_attribute(
+        "style",
+        "position: fixed; inset: 0; z-index: 2147483647; overflow: auto; \
+         padding: 2em; background-color: white; color: black; white-space: pre-wrap",
+    );
+    overlay.set_text_content(Some(&[message, "\n\n", panic_message].concat()));
+    if let Some(body) = document.body() {
+        let _ = body.append_child(&overlay);
+    }
+}
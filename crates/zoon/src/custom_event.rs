@@ -0,0 +1,84 @@
+use crate::*;
+use serde::{de::DeserializeOwned, Serialize};
+
+// ------ dispatch_custom_event ------
+
+/// Dispatches a bubbling `CustomEvent` with the serialized `detail` on the `target`.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// dispatch_custom_event(&document(), "my-app:ready", &42).unwrap_throw();
+/// ```
+pub fn dispatch_custom_event<T: Serialize + ?Sized>(
+    target: &web_sys::EventTarget,
+    name: &str,
+    detail: &T,
+) -> Result<(), serde_json::Error> {
+    let detail = serde_json::to_string(detail)?;
+    let detail = js_sys::JSON::parse(&detail).unwrap_throw();
+    let event = web_sys::CustomEvent::new_with_event_init_dict(
+        name,
+        web_sys::CustomEventInit::new()
+            .bubbles(true)
+            .detail(&detail),
+    )
+    .unwrap_throw();
+    target.dispatch_event(&event).unwrap_throw();
+    Ok(())
+}
+
+// ------ CustomEventListener ------
+
+pub(crate) struct CustomEventListener {
+    target: web_sys::EventTarget,
+    name: String,
+    callback: Closure<dyn FnMut(web_sys::Event)>,
+}
+
+impl CustomEventListener {
+    /// Missing `detail` is deserialized from `null`.
+    /// Events with a `detail` that can't be deserialized to `T` are logged and ignored.
+    pub(crate) fn new<T: DeserializeOwned>(
+        target: web_sys::EventTarget,
+        name: String,
+        handler: impl FnOnce(T) + Clone + 'static,
+    ) -> Self {
+        let event_name = name.clone();
+        let callback = move |event: web_sys::Event| {
+            let detail = event.unchecked_into::<web_sys::CustomEvent>().detail();
+            let detail = if detail.is_undefined() {
+                "null".to_owned()
+            } else {
+                match js_sys::JSON::stringify(&detail) {
+                    Ok(detail) => String::from(detail),
+                    Err(error) => {
+                        crate::zerror!("custom event `{}`: {:?}", event_name, error);
+                        return;
+                    }
+                }
+            };
+            match serde_json::from_str(&detail) {
+                Ok(detail) => handler.clone()(detail),
+                Err(error) => crate::zerror!("custom event `{}`: {}", event_name, error),
+            }
+        };
+        let callback = Closure::wrap(Box::new(callback) as Box<dyn FnMut(web_sys::Event)>);
+        target
+            .add_event_listener_with_callback(&name, callback.as_ref().unchecked_ref())
+            .unwrap_throw();
+        Self {
+            target,
+            name,
+            callback,
+        }
+    }
+}
+
+impl Drop for CustomEventListener {
+    fn drop(&mut self) {
+        self.target
+            .remove_event_listener_with_callback(&self.name, self.callback.as_ref().unchecked_ref())
+            .unwrap_throw();
+    }
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use std::{cell::RefCell, mem};
 
 pub fn window() -> web_sys::Window {
     web_sys::window().unwrap_throw()
@@ -62,3 +63,286 @@ pub async fn load_script(url: impl AsRef<str>) {
         .unwrap_throw();
     receiver.await.unwrap_throw();
 }
+
+// ------ computed_style ------
+
+/// The resolved value of the CSS property (`getComputedStyle(element).getPropertyValue(name)`),
+/// e.g. `height: auto` resolved to pixels. An empty string for unknown properties.
+///
+/// Note: It forces a synchronous layout (reflow) when styles have changed,
+/// so don't call it in a loop interleaved with style updates.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let panel = El::new().child("Content").after_insert(|ws_element| {
+///     let height = dom::computed_style(&ws_element, "height");
+///     println!("expanded height: {}", height);
+/// });
+/// ```
+pub fn computed_style(element: &web_sys::Element, name: &str) -> String {
+    window()
+        .get_computed_style(element)
+        .ok()
+        .flatten()
+        .and_then(|style| style.get_property_value(name).ok())
+        .unwrap_or_default()
+}
+
+// ------ scroll_into_view ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollBehavior {
+    /// Determined by the css `scroll-behavior` property.
+    Auto,
+    Smooth,
+    Instant,
+}
+
+/// The element's position in the scroll container after the scroll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollAlignment {
+    Start,
+    Center,
+    End,
+    /// `Start` or `End`, whichever is closer. The element isn't scrolled when it's visible.
+    Nearest,
+}
+
+impl ScrollAlignment {
+    fn into_native(self) -> web_sys::ScrollLogicalPosition {
+        match self {
+            Self::Start => web_sys::ScrollLogicalPosition::Start,
+            Self::Center => web_sys::ScrollLogicalPosition::Center,
+            Self::End => web_sys::ScrollLogicalPosition::End,
+            Self::Nearest => web_sys::ScrollLogicalPosition::Nearest,
+        }
+    }
+}
+
+/// `block` is the vertical alignment, `inline` the horizontal one (in horizontal writing modes).
+/// The defaults are the browser's defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollIntoViewOptions {
+    pub behavior: ScrollBehavior,
+    pub block: ScrollAlignment,
+    pub inline: ScrollAlignment,
+}
+
+impl Default for ScrollIntoViewOptions {
+    fn default() -> Self {
+        Self {
+            behavior: ScrollBehavior::Auto,
+            block: ScrollAlignment::Start,
+            inline: ScrollAlignment::Nearest,
+        }
+    }
+}
+
+impl ScrollIntoViewOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn smooth(mut self) -> Self {
+        self.behavior = ScrollBehavior::Smooth;
+        self
+    }
+
+    pub fn block(mut self, block: ScrollAlignment) -> Self {
+        self.block = block;
+        self
+    }
+
+    pub fn inline(mut self, inline: ScrollAlignment) -> Self {
+        self.inline = inline;
+        self
+    }
+}
+
+/// Scrolls all scroll containers of the element (including the page) so the element is visible.
+/// Use it e.g. in the `after_insert` handler.
+pub fn scroll_into_view(element: &web_sys::Element, options: ScrollIntoViewOptions) {
+    let behavior = match options.behavior {
+        ScrollBehavior::Auto => web_sys::ScrollBehavior::Auto,
+        ScrollBehavior::Smooth => web_sys::ScrollBehavior::Smooth,
+        ScrollBehavior::Instant => web_sys::ScrollBehavior::Instant,
+    };
+    let mut native_options = web_sys::ScrollIntoViewOptions::new();
+    native_options
+        .behavior(behavior)
+        .block(options.block.into_native())
+        .inline(options.inline.into_native());
+    element.scroll_into_view_with_scroll_into_view_options(&native_options);
+}
+
+/// Like `scroll_into_view`, but only when the element isn't fully visible - it has to be
+/// inside both its nearest scroll container and the window. Returns `true` when scrolled.
+/// # Example
+/// Jump to the first invalid field.
+/// ```no_run
+/// use zoon::*;
+///
+/// let email_input = document().get_element_by_id("email").unwrap_throw();
+/// dom::scroll_into_view_if_needed(
+///     &email_input,
+///     ScrollIntoViewOptions::new().smooth().block(ScrollAlignment::Center),
+/// );
+/// ```
+pub fn scroll_into_view_if_needed(
+    element: &web_sys::Element,
+    options: ScrollIntoViewOptions,
+) -> bool {
+    let rect = element.get_bounding_client_rect();
+    let is_inside = |top: f64, right: f64, bottom: f64, left: f64| {
+        rect.top() >= top && rect.bottom() <= bottom && rect.left() >= left && rect.right() <= right
+    };
+    let size = |size: Result<JsValue, JsValue>| {
+        size.ok().and_then(|size| size.as_f64()).unwrap_or_default()
+    };
+    // The scroll container itself may be partially out of the window.
+    let mut visible = is_inside(
+        0.,
+        size(window().inner_width()),
+        size(window().inner_height()),
+        0.,
+    );
+    if let Some(container) = nearest_scrollable(element) {
+        let container = container.get_bounding_client_rect();
+        visible = visible
+            && is_inside(
+                container.top(),
+                container.right(),
+                container.bottom(),
+                container.left(),
+            );
+    }
+    if visible {
+        return false;
+    }
+    scroll_into_view(element, options);
+    true
+}
+
+/// The closest ancestor with scrollable overflowing content,
+/// `None` when only the page itself can be scrolled.
+pub fn nearest_scrollable(element: &web_sys::Element) -> Option<web_sys::Element> {
+    let mut ancestor = element.parent_element();
+    while let Some(element) = ancestor {
+        // the page is scrolled by `<html>` or `<body>` depending on the browser
+        if matches!(element.tag_name().as_str(), "BODY" | "HTML") {
+            return None;
+        }
+        let scrollable = |overflow: &str| matches!(overflow, "auto" | "scroll" | "overlay");
+        if let Ok(Some(style)) = window().get_computed_style(&element) {
+            let overflow_x = style.get_property_value("overflow-x").unwrap_or_default();
+            let overflow_y = style.get_property_value("overflow-y").unwrap_or_default();
+            let overflows_x = element.scroll_width() > element.client_width();
+            let overflows_y = element.scroll_height() > element.client_height();
+            if (scrollable(&overflow_x) && overflows_x) || (scrollable(&overflow_y) && overflows_y)
+            {
+                return Some(element);
+            }
+        }
+        ancestor = element.parent_element();
+    }
+    None
+}
+
+// ------ batched DOM updates ------
+
+/// Element attributes, styles and children bound to signals are updated by Dominator
+/// in the tasks polling the signals, i.e. all changes made in the same tick are applied
+/// in the following microtasks before the browser renders. Writes alone are cheap,
+/// the browser recalculates styles and layout once before painting.
+/// That's why Zoon doesn't batch these bound updates itself - deferring them
+/// to a microtask or an animation frame wouldn't reduce the number of layouts,
+/// it would only delay the updates. The example `dom_updates_benchmark` measures
+/// 60 bound properties changed in the same tick against the same writes interleaved
+/// with layout reads.
+///
+/// Layout thrashing is caused by reads of layout properties (e.g. `offsetHeight`
+/// or `getBoundingClientRect`) interleaved with writes, every such read forces a synchronous
+/// layout. Schedule reads with `batch_read` and writes depending on them with `batch_write`
+/// in your signal handlers - all queued reads run before all queued writes
+/// in one flush after the current microtasks, so layout is computed at most once.
+///
+/// Call `flush_sync` when the queued writes have to be applied immediately,
+/// e.g. before measuring the DOM outside of `batch_read` or before a synchronous third-party call.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let rows = (0..50)
+///     .map(|_| RawHtmlEl::<web_sys::HtmlElement>::new("div"))
+///     .collect::<Vec<_>>();
+/// let row_elements = rows.iter().map(|row| row.dom_element()).collect::<Vec<_>>();
+/// let font_size = Mutable::new(16);
+///
+/// Task::start(font_size.signal().for_each_sync(move |_| {
+///     for row in row_elements.clone() {
+///         dom::batch_read(move || {
+///             let height = row.offset_height();
+///             dom::batch_write(move || {
+///                 row.set_attribute("data-height", &height.to_string()).unwrap_throw()
+///             });
+///         });
+///     }
+/// }));
+/// ```
+pub fn batch_read(read: impl FnOnce() + 'static) {
+    BATCH.with(|batch| batch.borrow_mut().reads.push(Box::new(read)));
+    schedule_flush();
+}
+
+/// See `batch_read`.
+pub fn batch_write(write: impl FnOnce() + 'static) {
+    BATCH.with(|batch| batch.borrow_mut().writes.push(Box::new(write)));
+    schedule_flush();
+}
+
+/// Runs all reads and writes queued by `batch_read` and `batch_write` now.
+/// Reads and writes queued by them are run too.
+///
+/// Note: Only the callbacks queued by `batch_read` and `batch_write` are run,
+/// updates of the elements bound to signals are still applied by their tasks.
+pub fn flush_sync() {
+    loop {
+        let (reads, writes) = BATCH.with(|batch| {
+            let mut batch = batch.borrow_mut();
+            (mem::take(&mut batch.reads), mem::take(&mut batch.writes))
+        });
+        if reads.is_empty() && writes.is_empty() {
+            break;
+        }
+        // reads first, so the layout computed by the first read is reused by the others
+        reads.into_iter().for_each(|read| read());
+        writes.into_iter().for_each(|write| write());
+    }
+}
+
+#[derive(Default)]
+struct Batch {
+    reads: Vec<Box<dyn FnOnce()>>,
+    writes: Vec<Box<dyn FnOnce()>>,
+    flush_scheduled: bool,
+}
+
+thread_local! {
+    static BATCH: RefCell<Batch> = RefCell::new(Batch::default());
+}
+
+fn schedule_flush() {
+    let already_scheduled =
+        BATCH.with(|batch| mem::replace(&mut batch.borrow_mut().flush_scheduled, true));
+    if already_scheduled {
+        return;
+    }
+    Task::start(async {
+        // a resolved promise resumes the task after the currently queued tasks,
+        // so the flush includes the updates from all signals changed in the same tick
+        let _ = JsFuture::from(js_sys::Promise::resolve(&JsValue::UNDEFINED)).await;
+        BATCH.with(|batch| batch.borrow_mut().flush_scheduled = false);
+        flush_sync();
+    });
+}
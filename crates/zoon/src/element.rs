@@ -1,4 +1,5 @@
 use crate::*;
+use futures_util::future::LocalBoxFuture;
 use std::iter;
 
 // -- modules --
@@ -12,12 +13,27 @@ pub use canvas::Canvas;
 pub mod checkbox;
 pub use checkbox::Checkbox;
 
+pub mod collapse;
+pub use collapse::Collapse;
+
 pub mod column;
 pub use column::Column;
 
+pub mod deferred;
+pub use deferred::{Deferred, LoadState};
+
+pub mod dialog;
+pub use dialog::Dialog;
+
+pub mod disclosure;
+pub use disclosure::Disclosure;
+
 pub mod el;
 pub use el::El;
 
+pub mod form;
+pub use form::{Form, FormSubmitHandle, FormValues};
+
 pub mod image;
 pub use image::Image;
 
@@ -30,9 +46,18 @@ pub use link::Link;
 pub mod paragraph;
 pub use paragraph::Paragraph;
 
+pub mod portal;
+pub use portal::Portal;
+
 pub mod row;
 pub use row::Row;
 
+pub mod slider;
+pub use slider::Slider;
+
+pub mod spinner;
+pub use spinner::Spinner;
+
 pub mod stack;
 pub use stack::Stack;
 
@@ -48,11 +73,16 @@ pub use text_input::{InputType, Placeholder, TextInput};
 // --
 
 pub mod raw_el;
-pub use raw_el::{RawEl, RawHtmlEl, RawSvgEl, UpdateRawEl};
+#[cfg(feature = "deterministic_class_ids")]
+pub use raw_el::reset_class_ids;
+pub use raw_el::{MeetOrSlice, RawEl, RawHtmlEl, RawSvgEl, UpdateRawEl};
 
 pub mod raw_text;
 pub use raw_text::RawText;
 
+pub mod child_transition;
+pub use child_transition::ChildTransition;
+
 // --
 
 pub mod ability;
@@ -62,6 +92,39 @@ pub use ability::*;
 
 pub trait Element: IntoIterator<Item = Self> {
     fn into_raw_element(self) -> RawElement;
+
+    /// See `RawElement::to_html_string`.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// Task::start(async {
+    ///     let count = Mutable::new(3);
+    ///     let html = El::new()
+    ///         .child_signal(count.signal())
+    ///         .after_insert(|element| element.set_attribute("data-inserted", "").unwrap_throw())
+    ///         .to_html_string()
+    ///         .await;
+    ///     // the current signal values and changes made by `after_insert` are captured
+    ///     assert!(html.contains(">3</div>"));
+    ///     assert!(html.contains("data-inserted"));
+    ///
+    ///     let (delayed, delayed_signal) = Mutable::new_and_signal(None);
+    ///     Task::start(async move {
+    ///         Timer::sleep(100).await;
+    ///         delayed.set(Some("loaded"));
+    ///     });
+    ///     let html = El::new().child_signal(delayed_signal).to_html_string().await;
+    ///     // values set by tasks waiting for timers or requests aren't captured
+    ///     assert!(not(html.contains("loaded")));
+    /// });
+    /// ```
+    fn to_html_string(self) -> LocalBoxFuture<'static, String>
+    where
+        Self: Sized,
+    {
+        Box::pin(self.into_raw_element().to_html_string())
+    }
 }
 
 // ------ RawElement ------
@@ -72,6 +135,50 @@ pub enum RawElement {
     Text(RawText),
 }
 
+impl RawElement {
+    /// A one-shot snapshot of the element's DOM as HTML markup, e.g. for golden-file tests
+    /// or debugging. It isn't updated when signals change.
+    ///
+    /// The element is inserted into a hidden container in the document, so `after_insert`
+    /// hooks run like in the app. The snapshot is taken once the markup stops changing
+    /// between microtasks, i.e. it contains the current values of the signals,
+    /// including dynamic children and their nested signals. Values set later, e.g. by tasks waiting for timers
+    /// or requests, aren't captured. Then the element is removed and its `after_remove`
+    /// hooks run.
+    ///
+    /// Note: Styles set by `.s(...)` may be rendered as generated classes
+    /// defined in the global stylesheet, not in the markup.
+    pub async fn to_html_string(self) -> String {
+        let container = document().create_element("div").unwrap_throw();
+        container
+            .set_attribute(
+                "style",
+                "position: fixed; visibility: hidden; pointer-events: none",
+            )
+            .unwrap_throw();
+        document()
+            .body()
+            .unwrap_throw()
+            .append_child(&container)
+            .unwrap_throw();
+        let dom_handle = dominator::append_dom(&container, self.into_dom());
+        // Signals are applied by spawned tasks running in microtasks,
+        // a resolved promise resumes this future after the tasks queued so far.
+        let mut html = container.inner_html();
+        loop {
+            let _ = JsFuture::from(js_sys::Promise::resolve(&JsValue::UNDEFINED)).await;
+            let current_html = container.inner_html();
+            if current_html == html {
+                break;
+            }
+            html = current_html;
+        }
+        dom_handle.discard();
+        container.remove();
+        html
+    }
+}
+
 impl IntoDom for RawElement {
     fn into_dom(self) -> Dom {
         match self {
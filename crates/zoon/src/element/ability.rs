@@ -1,6 +1,9 @@
 mod focusable;
 pub use focusable::Focusable;
 
+mod disableable;
+pub use disableable::Disableable;
+
 mod styleable;
 pub use styleable::Styleable;
 
@@ -8,7 +11,7 @@ mod keyboard_event_aware;
 pub use keyboard_event_aware::{Key, KeyboardEvent, KeyboardEventAware, RawKeyboardEvent};
 
 mod mouse_event_aware;
-pub use mouse_event_aware::{MouseEvent, MouseEventAware, RawMouseEvent};
+pub use mouse_event_aware::{MouseEvent, MouseEventAware, RawMouseEvent, WheelEvent};
 
 mod pointer_event_aware;
 pub use pointer_event_aware::{
@@ -16,7 +19,9 @@ pub use pointer_event_aware::{
 };
 
 mod touch_event_aware;
-pub use touch_event_aware::{TouchEventAware, TouchHandling};
+pub use touch_event_aware::{
+    PinchEvent, SwipeDirection, SwipeEvent, SwipeOptions, TouchEventAware, TouchHandling,
+};
 
 mod hookable;
 pub use hookable::Hookable;
@@ -0,0 +1,83 @@
+use crate::*;
+
+// ------ Disableable ------
+
+/// Disables the interactive element - the `disabled` and `aria-disabled` attributes are set.
+///
+/// Native form elements (e.g. `TextInput` or `Slider`) ignore user input when disabled.
+/// `Button` and `Checkbox` don't call their `on_press` / `on_change` handlers, however
+/// the generic mouse and pointer handlers (e.g. `on_click`) are still called.
+/// They are also removed from the tab order (`tabindex="-1"`) while disabled
+/// and their previous `tabindex` is restored once enabled.
+///
+/// Style the disabled state with the `[disabled]` selector.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let saving = Mutable::new(false);
+/// let save_button = Button::new()
+///     .update_raw_el(|raw_el| {
+///         raw_el.style_group(
+///             StyleGroup::new("[disabled]")
+///                 .style("cursor", "not-allowed")
+///                 .style("opacity", "0.5"),
+///         )
+///     })
+///     .disabled_signal(saving.signal())
+///     .label("Save")
+///     .on_press(move || saving.set(true));
+/// ```
+pub trait Disableable: UpdateRawEl + Sized {
+    fn disabled(self, disabled: bool) -> Self {
+        self.update_raw_el(|raw_el| {
+            set_disabled(raw_el.dom_element().as_ref(), disabled, &mut None);
+            raw_el
+        })
+    }
+
+    fn disabled_signal(self, disabled: impl Signal<Item = bool> + Unpin + 'static) -> Self {
+        self.update_raw_el(|raw_el| {
+            let dom_element = raw_el.dom_element();
+            let mut saved_tab_index = None;
+            let disabled_updater =
+                Task::start_droppable(disabled.dedupe().for_each_sync(move |disabled| {
+                    set_disabled(dom_element.as_ref(), disabled, &mut saved_tab_index)
+                }));
+            raw_el.after_remove(move |_| drop(disabled_updater))
+        })
+    }
+}
+
+/// `saved_tab_index` - the `tabindex` attribute of the element without native `disabled` support
+/// before it has been disabled, `None` when it hasn't been saved.
+fn set_disabled(
+    element: &web_sys::Element,
+    disabled: bool,
+    saved_tab_index: &mut Option<Option<String>>,
+) {
+    if disabled {
+        element.set_attribute("disabled", "").unwrap_throw();
+        element
+            .set_attribute("aria-disabled", "true")
+            .unwrap_throw();
+        // `disabled` doesn't remove e.g. `<div role="button" tabindex="0">` from the tab order.
+        if not(has_native_disabled(element)) && saved_tab_index.is_none() {
+            *saved_tab_index = Some(element.get_attribute("tabindex"));
+            element.set_attribute("tabindex", "-1").unwrap_throw();
+        }
+    } else {
+        element.remove_attribute("disabled").unwrap_throw();
+        element.remove_attribute("aria-disabled").unwrap_throw();
+        match saved_tab_index.take() {
+            Some(Some(tab_index)) => element.set_attribute("tabindex", &tab_index).unwrap_throw(),
+            Some(None) => element.remove_attribute("tabindex").unwrap_throw(),
+            None => (),
+        }
+    }
+}
+
+/// Form elements like `<input>` or `<button>` have the `disabled` property.
+fn has_native_disabled(element: &web_sys::Element) -> bool {
+    Reflect::has(element, &"disabled".into()).unwrap_throw()
+}
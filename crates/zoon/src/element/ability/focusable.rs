@@ -1,4 +1,5 @@
 use crate::*;
+use std::{cell::Cell, rc::Rc};
 
 pub trait Focusable: UpdateRawEl + Sized
 where
@@ -15,6 +16,32 @@ where
         self.update_raw_el(|raw_el| raw_el.focus_signal(focus))
     }
 
+    /// See `RawEl::tab_index` for the meaning of the values.
+    fn tab_index(self, index: i32) -> Self {
+        self.update_raw_el(|raw_el| raw_el.tab_index(index))
+    }
+
+    fn tab_index_signal(
+        self,
+        index: impl Signal<Item = impl Into<Option<i32>>> + Unpin + 'static,
+    ) -> Self {
+        self.update_raw_el(|raw_el| raw_el.tab_index_signal(index))
+    }
+
+    /// Makes the element reachable by Tab (`true`) or only programmatically focusable (`false`).
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let card = El::new()
+    ///     .focusable(true)
+    ///     .on_focused_change(|focused| println!("card focused: {}", focused))
+    ///     .child("Card");
+    /// ```
+    fn focusable(self, focusable: bool) -> Self {
+        self.update_raw_el(|raw_el| raw_el.focusable(focusable))
+    }
+
     fn on_focus(self, handler: impl FnOnce() + Clone + 'static) -> Self {
         let handler = move || handler.clone()();
         self.update_raw_el(|raw_el| raw_el.event_handler(move |_: events::Focus| handler()))
@@ -31,4 +58,31 @@ where
         self.on_focus(move || focus_handler(true))
             .on_blur(move || blur_handler(false))
     }
+
+    /// `:focus-within` semantics - the handler is called with `true` when the element
+    /// or any of its descendants receives focus and with `false` when the focus leaves them all.
+    /// Moving focus between descendants doesn't call the handler.
+    fn on_focus_within_change(self, handler: impl FnOnce(bool) + Clone + 'static) -> Self {
+        let focused_within = Rc::new(Cell::new(false));
+        let set_focused_within = move |new_focused_within| {
+            if focused_within.replace(new_focused_within) != new_focused_within {
+                handler.clone()(new_focused_within)
+            }
+        };
+        let set_focused_within_for_out = set_focused_within.clone();
+        self.update_raw_el(|raw_el| {
+            let dom_element = AsRef::<web_sys::Node>::as_ref(&raw_el.dom_element()).clone();
+            raw_el
+                .event_handler(move |_: events_extra::FocusIn| set_focused_within(true))
+                .event_handler(move |event: events_extra::FocusOut| {
+                    let focus_stays_within = event
+                        .related_target()
+                        .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+                        .map_or(false, |target| dom_element.contains(Some(&target)));
+                    if not(focus_stays_within) {
+                        set_focused_within_for_out(false)
+                    }
+                })
+        })
+    }
 }
@@ -1,10 +1,40 @@
 use crate::*;
 
 pub trait HasIds: UpdateRawEl + Sized {
+    /// Sets the DOM `id`, e.g. for in-page links (`#section-2`)
+    /// or ARIA relationships like `aria-labelledby`.
+    ///
+    /// Ids have to be unique in the document, Zoon doesn't check it.
+    /// The id is independent of the generated `class_id`, which is a class.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let section = Column::new()
+    ///     .item(Paragraph::new().id("pricing-title").content("Pricing"))
+    ///     .update_raw_el(|raw_el| raw_el.attr("aria-labelledby", "pricing-title"));
+    /// ```
     fn id<'a>(self, id: impl IntoCowStr<'a>) -> Self {
         self.update_raw_el(move |raw_el| raw_el.id(id))
     }
 
+    /// Like `id`, but the element is also scrolled into view once inserted
+    /// when the URL hash targets it (e.g. `/docs#install`).
+    /// Browsers scroll to the hash target on page load, before the app has rendered it.
+    ///
+    /// Add the css property `scroll-margin-top` to keep the element below a fixed header.
+    fn anchor_id<'a>(self, id: impl IntoCowStr<'a>) -> Self {
+        let id = id.into_cow_str();
+        let hash = ["#", &id].concat();
+        self.update_raw_el(move |raw_el| {
+            raw_el.id(id).after_insert(move |ws_element| {
+                if window().location().hash().ok().as_deref() == Some(hash.as_str()) {
+                    dom::scroll_into_view(ws_element.as_ref(), ScrollIntoViewOptions::default());
+                }
+            })
+        })
+    }
+
     fn class_id(self, consumer: impl FnOnce(ClassId)) -> Self {
         self.update_raw_el(move |raw_el| {
             consumer(raw_el.class_id());
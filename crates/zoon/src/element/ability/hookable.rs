@@ -1,6 +1,18 @@
 use crate::*;
 
 pub trait Hookable: UpdateRawEl + Sized {
+    /// Called once the element has been inserted into the document,
+    /// not when the element is built - the element can be measured here
+    /// or passed to a non-Zoon JS library.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let el = El::new().after_insert(|ws_element| {
+    ///     let rect = ws_element.get_bounding_client_rect();
+    ///     println!("width: {}", rect.width());
+    /// });
+    /// ```
     fn after_insert(
         self,
         handler: impl FnOnce(<Self::RawEl as RawEl>::DomElement) + 'static,
@@ -8,6 +20,12 @@ pub trait Hookable: UpdateRawEl + Sized {
         self.update_raw_el(|raw_el| raw_el.after_insert(handler))
     }
 
+    /// Called once the element has been detached from the document.
+    /// The element is still a valid `web_sys` object, so use this hook for teardown
+    /// (e.g. to destroy instances of non-Zoon JS widgets).
+    ///
+    /// Note: There is no `before_remove` hook, because Dominator notifies only
+    /// after the element has been removed.
     fn after_remove(
         self,
         handler: impl FnOnce(<Self::RawEl as RawEl>::DomElement) + 'static,
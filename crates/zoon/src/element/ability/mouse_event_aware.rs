@@ -1,7 +1,15 @@
 use crate::*;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
 
 // ------ MouseEventAware ------
 
+/// Convenience handlers for the most common mouse events.
+/// Any other DOM event can be handled by a generic event handler, e.g.
+/// `.update_raw_el(|raw_el| raw_el.event_handler(|event: events::MouseDown| ...))`,
+/// see `dominator::events` and `events_extra` for the available events.
 pub trait MouseEventAware: UpdateRawEl + Sized {
     fn on_hovered_change(self, handler: impl FnOnce(bool) + Clone + 'static) -> Self {
         let mouse_over_handler = move |hovered| (handler.clone())(hovered);
@@ -13,6 +21,53 @@ pub trait MouseEventAware: UpdateRawEl + Sized {
         })
     }
 
+    /// Like `on_hovered_change`, but the handler is called only when the pointer stays
+    /// on (`show_delay` ms) or off (`hide_delay` ms) the element, so e.g. tooltips
+    /// don't flicker when the pointer moves quickly across many elements.
+    fn on_hovered_change_delayed(
+        self,
+        show_delay: u32,
+        hide_delay: u32,
+        handler: impl FnOnce(bool) + Clone + 'static,
+    ) -> Self {
+        let hovered = Rc::new(Cell::new(false));
+        let scheduled = Rc::new(Cell::new(None::<bool>));
+        let timer = Rc::new(RefCell::new(None));
+
+        let schedule = move |new_hovered: bool| {
+            if scheduled.get() == Some(new_hovered) {
+                return;
+            }
+            if hovered.get() == new_hovered {
+                // cancel the opposite change
+                scheduled.set(None);
+                timer.replace(None);
+                return;
+            }
+            scheduled.set(Some(new_hovered));
+            let delay = if new_hovered { show_delay } else { hide_delay };
+            let on_timeout = {
+                let hovered = Rc::clone(&hovered);
+                let scheduled = Rc::clone(&scheduled);
+                let handler = handler.clone();
+                move || {
+                    scheduled.set(None);
+                    hovered.set(new_hovered);
+                    handler(new_hovered)
+                }
+            };
+            // the previous timer (if any) is cancelled by dropping
+            timer.replace(Some(Timer::once(delay, on_timeout)));
+        };
+        let schedule_for_leave = schedule.clone();
+
+        self.update_raw_el(|raw_el| {
+            raw_el
+                .event_handler(move |_: events_extra::MouseOver| schedule(true))
+                .event_handler(move |_: events::MouseLeave| schedule_for_leave(false))
+        })
+    }
+
     fn on_click(self, handler: impl FnOnce() + Clone + 'static) -> Self {
         let handler = move || handler.clone()();
         self.update_raw_el(|raw_el| raw_el.event_handler(move |_: events::Click| handler()))
@@ -53,6 +108,105 @@ pub trait MouseEventAware: UpdateRawEl + Sized {
         })
     }
 
+    /// Called on a right click (or a long press on touch screens, or the context menu key).
+    /// The native context menu is suppressed so you can show your own menu.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let (menu_position, menu_position_signal) = Mutable::new_and_signal(None);
+    /// let editor = El::new()
+    ///     .on_context_menu_event(move |event| menu_position.set(Some((event.x(), event.y()))))
+    ///     .child_signal(menu_position_signal.map_some(|(x, y)| {
+    ///         Column::new()
+    ///             .s(Fixed::new().left(x).top(y))
+    ///             .item("Cut")
+    ///             .item("Copy")
+    ///     }));
+    /// ```
+    fn on_context_menu(self, handler: impl FnOnce() + Clone + 'static) -> Self {
+        let handler = move || handler.clone()();
+        self.update_raw_el(|raw_el| {
+            raw_el.event_handler_with_options(
+                EventOptions::new().prevent_default(),
+                move |_: events_extra::ContextMenu| handler(),
+            )
+        })
+    }
+
+    /// Like `on_context_menu`, the native context menu is suppressed too.
+    fn on_context_menu_event(self, handler: impl FnOnce(MouseEvent) + Clone + 'static) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el.event_handler_with_options(
+                EventOptions::new().prevent_default(),
+                move |event: events_extra::ContextMenu| {
+                    let mouse_event = MouseEvent {
+                        x: event.x(),
+                        y: event.y(),
+                        movement_x: 0,
+                        movement_y: 0,
+                        raw_event: RawMouseEvent::ContextMenu(event),
+                    };
+                    (handler.clone())(mouse_event)
+                },
+            )
+        })
+    }
+
+    /// Called on mouse wheel or touchpad scrolling. The deltas are normalized to pixels
+    /// (browsers may report them in lines or pages) and `ctrl_key` is `true` also
+    /// during touchpad pinch-zooming.
+    ///
+    /// The listener isn't passive so you can call `raw_event.prevent_default()`
+    /// to disable the native scrolling or page zooming.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let zoom = Mutable::new(100.);
+    /// let canvas = El::new()
+    ///     .s(Transform::with_signal(zoom.signal().map(|zoom| Transform::new().scale(zoom))))
+    ///     .on_wheel(move |event| {
+    ///         let WheelEvent { delta_y, ctrl_key, .. } = event;
+    ///         if ctrl_key {
+    ///             event.raw_event.prevent_default();
+    ///             zoom.update(|zoom| (zoom - delta_y).clamp(10., 1000.));
+    ///         }
+    ///     })
+    ///     .child("Zoom me with Ctrl + wheel");
+    /// ```
+    fn on_wheel(self, handler: impl FnOnce(WheelEvent) + Clone + 'static) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el.event_handler_with_options(
+                EventOptions::new().preventable(),
+                move |event: events_extra::WheelEvent| {
+                    let (delta_x, delta_y) = match event.delta_mode() {
+                        events_extra::WheelDeltaMode::Pixel => (event.delta_x(), event.delta_y()),
+                        events_extra::WheelDeltaMode::Line => {
+                            (event.delta_x() * LINE_HEIGHT, event.delta_y() * LINE_HEIGHT)
+                        }
+                        events_extra::WheelDeltaMode::Page => {
+                            let window = window();
+                            let page_width = window.inner_width().unwrap_throw().as_f64();
+                            let page_height = window.inner_height().unwrap_throw().as_f64();
+                            (
+                                event.delta_x() * page_width.unwrap_or_default(),
+                                event.delta_y() * page_height.unwrap_or_default(),
+                            )
+                        }
+                    };
+                    let wheel_event = WheelEvent {
+                        delta_x,
+                        delta_y,
+                        ctrl_key: event.ctrl_key(),
+                        raw_event: event,
+                    };
+                    (handler.clone())(wheel_event)
+                },
+            )
+        })
+    }
+
     fn on_click_outside<'a>(
         self,
         handler: impl FnOnce() + Clone + 'static,
@@ -151,9 +305,25 @@ impl MouseEvent {
     }
 }
 
+// ------ WheelEvent ------
+
+/// Pixels per line for wheel events reported in lines (e.g. by Firefox with a mouse wheel).
+const LINE_HEIGHT: f64 = 16.;
+
+pub struct WheelEvent {
+    /// Pixels, positive when scrolling right.
+    pub delta_x: f64,
+    /// Pixels, positive when scrolling down.
+    pub delta_y: f64,
+    /// `Ctrl` or `Meta` is pressed. Browsers set it also for touchpad pinch gestures.
+    pub ctrl_key: bool,
+    pub raw_event: events_extra::WheelEvent,
+}
+
 // ------ RawMouseEvent ------
 
 pub enum RawMouseEvent {
     Click(events::Click),
     DoubleClick(events::DoubleClick),
+    ContextMenu(events_extra::ContextMenu),
 }
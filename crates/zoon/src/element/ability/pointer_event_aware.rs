@@ -13,12 +13,15 @@ pub trait PointerEventAware: UpdateRawEl + Sized {
 
     fn on_pointer_down_event(self, handler: impl FnOnce(PointerEvent) + Clone + 'static) -> Self {
         self.update_raw_el(|raw_el| {
+            let dom_element: web_sys::Element = raw_el.dom_element().into();
             raw_el.event_handler(move |event: events_extra::PointerDown| {
                 let pointer_event = PointerEvent {
                     x: event.x(),
                     y: event.y(),
                     movement_x: 0,
                     movement_y: 0,
+                    pointer_id: event.pointer_id(),
+                    element: dom_element.clone(),
                     raw_event: RawPointerEvent::PointerDown(event),
                 };
                 (handler.clone())(pointer_event)
@@ -35,12 +38,15 @@ pub trait PointerEventAware: UpdateRawEl + Sized {
 
     fn on_pointer_up_event(self, handler: impl FnOnce(PointerEvent) + Clone + 'static) -> Self {
         self.update_raw_el(|raw_el| {
+            let dom_element: web_sys::Element = raw_el.dom_element().into();
             raw_el.event_handler(move |event: events_extra::PointerUp| {
                 let pointer_event = PointerEvent {
                     x: event.x(),
                     y: event.y(),
                     movement_x: 0,
                     movement_y: 0,
+                    pointer_id: event.pointer_id(),
+                    element: dom_element.clone(),
                     raw_event: RawPointerEvent::PointerUp(event),
                 };
                 (handler.clone())(pointer_event)
@@ -68,6 +74,7 @@ pub trait PointerEventAware: UpdateRawEl + Sized {
             }
         })
         .update_raw_el(|raw_el| {
+            let dom_element: web_sys::Element = raw_el.dom_element().into();
             raw_el.event_handler(move |event: events_extra::PointerMove| {
                 let x = event.x();
                 let y = event.y();
@@ -78,6 +85,8 @@ pub trait PointerEventAware: UpdateRawEl + Sized {
                     y: event.y(),
                     movement_x: previous_x.map_or(0, |previous_x| x - previous_x),
                     movement_y: previous_y.map_or(0, |previous_y| y - previous_y),
+                    pointer_id: event.pointer_id(),
+                    element: dom_element.clone(),
                     raw_event: RawPointerEvent::PointerMove(event),
                 };
                 (handler.clone())(pointer_event)
@@ -100,6 +109,72 @@ pub trait PointerEventAware: UpdateRawEl + Sized {
         })
     }
 
+    /// Called with `true` when the element captures a pointer (see `PointerEvent::capture_pointer`)
+    /// and with `false` when the capture ends - the pointer has been released or cancelled,
+    /// `PointerEvent::release_pointer` has been called or the element has been removed.
+    ///
+    /// End drag operations here instead of in `on_pointer_up` to prevent "stuck" drags
+    /// when the browser cancels the pointer (e.g. a touch turns into scrolling).
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let dragging = Mutable::new(false);
+    /// let handle = El::new()
+    ///     .s(Cursor::grab_signal(dragging.signal()))
+    ///     .on_pointer_down_event(|event| event.capture_pointer())
+    ///     .on_pointer_capture_change({
+    ///         let dragging = dragging.clone();
+    ///         move |captured| dragging.set_neq(captured)
+    ///     })
+    ///     .on_pointer_move_event(move |event| {
+    ///         if dragging.get() {
+    ///             println!("dragged by {}px", event.movement_x());
+    ///         }
+    ///     });
+    /// ```
+    fn on_pointer_capture_change(self, handler: impl FnOnce(bool) + Clone + 'static) -> Self {
+        let handler = move |captured| handler.clone()(captured);
+        let handler_for_lost = handler.clone();
+        self.update_raw_el(|raw_el| {
+            raw_el
+                .event_handler(move |_: events_extra::GotPointerCapture| handler(true))
+                .event_handler(move |_: events_extra::LostPointerCapture| handler_for_lost(false))
+        })
+    }
+
+    /// Tracks the "active" state of the element - the primary button or touch is pressed
+    /// on the element. The press ends when the pointer is released, cancelled (e.g. by scrolling)
+    /// or leaves the element.
+    fn on_pressed_change(self, handler: impl FnOnce(bool) + Clone + 'static) -> Self {
+        let pressed = Rc::new(Cell::new(false));
+        let set_pressed = move |new_pressed| {
+            if pressed.replace(new_pressed) != new_pressed {
+                handler.clone()(new_pressed)
+            }
+        };
+        let set_pressed_for_up = set_pressed.clone();
+        let set_pressed_for_cancel = set_pressed.clone();
+        let set_pressed_for_leave = set_pressed.clone();
+        self.update_raw_el(|raw_el| {
+            let dom_element = raw_el.dom_element().into();
+            raw_el
+                .event_handler(move |event: events_extra::PointerDown| {
+                    if event.is_primary() && matches!(event.button(), events::MouseButton::Left) {
+                        set_pressed(true)
+                    }
+                })
+                .event_handler(move |_: events_extra::PointerUp| set_pressed_for_up(false))
+                .event_handler(move |_: events_extra::PointerCancel| set_pressed_for_cancel(false))
+                .event_handler(move |event: events_extra::PointerLeave| {
+                    // we are leaving from the element itself, not only from its child
+                    if event.target().as_ref() == Some(&dom_element) {
+                        set_pressed_for_leave(false)
+                    }
+                })
+        })
+    }
+
     fn pointer_handling(self, handling: PointerHandling) -> Self
     where
         <Self::RawEl as RawEl>::DomElement: Into<web_sys::HtmlElement>,
@@ -152,6 +227,9 @@ pub struct PointerEvent {
     y: i32,
     movement_x: i32,
     movement_y: i32,
+    pointer_id: i32,
+    /// The element with the event handler.
+    element: web_sys::Element,
     pub raw_event: RawPointerEvent,
 }
 
@@ -171,6 +249,31 @@ impl PointerEvent {
     pub fn movement_y(&self) -> i32 {
         self.movement_y
     }
+
+    /// Identifies the mouse, pen or touch contact that caused the event.
+    pub fn pointer_id(&self) -> i32 {
+        self.pointer_id
+    }
+
+    /// Redirects all events of this pointer to the element until the pointer is released,
+    /// so e.g. a drag continues even when the pointer leaves the element or the browser window.
+    ///
+    /// The capture is released automatically on `pointerup` and `pointercancel`;
+    /// observe it with `on_pointer_capture_change`.
+    pub fn capture_pointer(&self) {
+        // fails when the pointer is no longer active, e.g. it has been released already
+        if let Err(error) = self.element.set_pointer_capture(self.pointer_id) {
+            crate::zwarn!("failed to capture pointer: {:?}", error);
+        }
+    }
+
+    pub fn release_pointer(&self) {
+        if self.element.has_pointer_capture(self.pointer_id) {
+            if let Err(error) = self.element.release_pointer_capture(self.pointer_id) {
+                crate::zwarn!("failed to release pointer: {:?}", error);
+            }
+        }
+    }
 }
 
 // ------ RawPointerEvent ------
@@ -1,6 +1,9 @@
 use crate::*;
 use std::borrow::Cow;
 
+mod gestures;
+pub use gestures::{PinchEvent, SwipeDirection, SwipeEvent, SwipeOptions};
+
 // ------ TouchEventAware ------
 
 pub trait TouchEventAware: UpdateRawEl + Sized {
@@ -14,6 +17,51 @@ pub trait TouchEventAware: UpdateRawEl + Sized {
             raw_el.style("touch-action", &touch_action)
         })
     }
+
+    /// Called on a fast horizontal swipe, see `on_swipe_with_options` for other options.
+    ///
+    /// Note: It sets `touch-action` to let the browser scroll only in the other axis.
+    /// The `touch-action` is combined with the one set by `on_pinch`.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let notification = El::new()
+    ///     .child("Swipe to dismiss")
+    ///     .on_swipe(|SwipeEvent { direction, velocity }| {
+    ///         println!("swiped {:?} at {}px/s", direction, velocity);
+    ///     });
+    /// ```
+    fn on_swipe(self, handler: impl FnOnce(SwipeEvent) + Clone + 'static) -> Self {
+        self.on_swipe_with_options(SwipeOptions::default(), handler)
+    }
+
+    fn on_swipe_with_options(
+        self,
+        options: SwipeOptions,
+        handler: impl FnOnce(SwipeEvent) + Clone + 'static,
+    ) -> Self {
+        self.update_raw_el(|raw_el| gestures::add_swipe_handlers(raw_el, options, handler))
+    }
+
+    /// Called while two fingers pinch the element.
+    ///
+    /// Note: It sets `touch-action` to prevent the browser from zooming the page.
+    /// The `touch-action` is combined with the one set by `on_swipe`.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let zoom = Mutable::new(1.);
+    /// let image = Image::new()
+    ///     .url("/assets/images/map.png")
+    ///     .description("Map")
+    ///     .s(Transform::with_signal(zoom.signal().map(|zoom| Transform::new().scale(zoom * 100.))))
+    ///     .on_pinch(move |PinchEvent { scale, .. }| zoom.set_neq(scale));
+    /// ```
+    fn on_pinch(self, handler: impl FnOnce(PinchEvent) + Clone + 'static) -> Self {
+        self.update_raw_el(|raw_el| gestures::add_pinch_handlers(raw_el, handler))
+    }
 }
 
 // ------ TouchHandling ------
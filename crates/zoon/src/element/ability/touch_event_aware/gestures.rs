@@ -0,0 +1,302 @@
+use crate::*;
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    rc::Rc,
+};
+
+// ------ SwipeEvent ------
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SwipeEvent {
+    pub direction: SwipeDirection,
+    /// Pixels per second
+    pub velocity: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwipeDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+// ------ SwipeOptions ------
+
+#[derive(Debug, Clone, Copy)]
+pub struct SwipeOptions {
+    min_distance: f64,
+    min_velocity: f64,
+    axis: SwipeAxis,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SwipeAxis {
+    Horizontal,
+    Vertical,
+    Both,
+}
+
+impl Default for SwipeOptions {
+    fn default() -> Self {
+        Self {
+            min_distance: 50.,
+            min_velocity: 300.,
+            axis: SwipeAxis::Horizontal,
+        }
+    }
+}
+
+impl SwipeOptions {
+    /// Horizontal swipes, at least 50px long and 300px/s fast.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pixels
+    pub fn min_distance(mut self, distance: impl Into<f64>) -> Self {
+        self.min_distance = distance.into();
+        self
+    }
+
+    /// Pixels per second
+    pub fn min_velocity(mut self, velocity: impl Into<f64>) -> Self {
+        self.min_velocity = velocity.into();
+        self
+    }
+
+    /// Detect only `Up` and `Down` swipes, the element can still be scrolled horizontally.
+    pub fn vertical(mut self) -> Self {
+        self.axis = SwipeAxis::Vertical;
+        self
+    }
+
+    /// Detect swipes in all directions. Note: The element can't be scrolled by touch.
+    pub fn any_direction(mut self) -> Self {
+        self.axis = SwipeAxis::Both;
+        self
+    }
+}
+
+// ------ PinchEvent ------
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PinchEvent {
+    /// The distance between the fingers relative to the distance when the pinch started.
+    pub scale: f64,
+    /// The point between the fingers in the viewport coordinates.
+    pub center: (f64, f64),
+}
+
+// ------ swipe ------
+
+struct SwipeStart {
+    pointer_id: i32,
+    x: i32,
+    y: i32,
+    time: f64,
+    captured: bool,
+}
+
+/// The browser takes over touches moving along the scrollable axis and cancels them
+/// (`pointercancel`), so only swipes along the other axis reach the handler.
+pub(super) fn add_swipe_handlers<RE: RawEl>(
+    raw_el: RE,
+    options: SwipeOptions,
+    handler: impl FnOnce(SwipeEvent) + Clone + 'static,
+) -> RE {
+    let touch_action = match options.axis {
+        SwipeAxis::Horizontal => "pan-y pinch-zoom",
+        SwipeAxis::Vertical => "pan-x pinch-zoom",
+        SwipeAxis::Both => "pinch-zoom",
+    };
+
+    let start = Rc::new(RefCell::new(None::<SwipeStart>));
+    let start_for_move = Rc::clone(&start);
+    let start_for_up = Rc::clone(&start);
+    let start_for_cancel = Rc::clone(&start);
+
+    let dom_element: web_sys::Element = raw_el.dom_element().into();
+    add_touch_action(raw_el, touch_action)
+        .event_handler(move |event: events_extra::PointerDown| {
+            // another finger means pinching or zooming, not swiping
+            if not(event.is_primary()) || not(matches!(event.button(), events::MouseButton::Left)) {
+                start.take();
+                return;
+            }
+            *start.borrow_mut() = Some(SwipeStart {
+                pointer_id: event.pointer_id(),
+                x: event.x(),
+                y: event.y(),
+                time: js_sys::Date::now(),
+                captured: false,
+            });
+        })
+        .event_handler(move |event: events_extra::PointerMove| {
+            let mut start = start_for_move.borrow_mut();
+            let start = match start.as_mut() {
+                Some(start) if start.pointer_id == event.pointer_id() && not(start.captured) => {
+                    start
+                }
+                _ => return,
+            };
+            let delta_x = f64::from(event.x() - start.x);
+            let delta_y = f64::from(event.y() - start.y);
+            let distance = match options.axis {
+                SwipeAxis::Horizontal => delta_x.abs(),
+                SwipeAxis::Vertical => delta_y.abs(),
+                SwipeAxis::Both => delta_x.abs().max(delta_y.abs()),
+            };
+            // Keep receiving events when the mouse leaves the element.
+            // Capturing on `pointerdown` would steal clicks and hover from the descendants.
+            if distance >= options.min_distance {
+                start.captured = true;
+                let _ = dom_element.set_pointer_capture(event.pointer_id());
+            }
+        })
+        .event_handler(move |event: events_extra::PointerUp| {
+            let start = match start_for_up.take() {
+                Some(start) if start.pointer_id == event.pointer_id() => start,
+                _ => return,
+            };
+            let delta_x = f64::from(event.x() - start.x);
+            let delta_y = f64::from(event.y() - start.y);
+            let horizontal = match options.axis {
+                SwipeAxis::Horizontal => true,
+                SwipeAxis::Vertical => false,
+                SwipeAxis::Both => delta_x.abs() >= delta_y.abs(),
+            };
+            let (distance, cross_distance) = if horizontal {
+                (delta_x, delta_y)
+            } else {
+                (delta_y, delta_x)
+            };
+            // a diagonal movement is not a swipe
+            if distance.abs() < options.min_distance || distance.abs() < cross_distance.abs() * 2. {
+                return;
+            }
+            let duration_in_seconds = (js_sys::Date::now() - start.time).max(1.) / 1000.;
+            let velocity = distance.abs() / duration_in_seconds;
+            if velocity < options.min_velocity {
+                return;
+            }
+            let direction = match (horizontal, distance > 0.) {
+                (true, true) => SwipeDirection::Right,
+                (true, false) => SwipeDirection::Left,
+                (false, true) => SwipeDirection::Down,
+                (false, false) => SwipeDirection::Up,
+            };
+            handler.clone()(SwipeEvent {
+                direction,
+                velocity,
+            })
+        })
+        .event_handler(move |_: events_extra::PointerCancel| {
+            // e.g. the browser started scrolling
+            start_for_cancel.take();
+        })
+}
+
+// ------ pinch ------
+
+pub(super) fn add_pinch_handlers<RE: RawEl>(
+    raw_el: RE,
+    handler: impl FnOnce(PinchEvent) + Clone + 'static,
+) -> RE {
+    let pointers = Rc::new(RefCell::new(BTreeMap::<i32, (f64, f64)>::new()));
+    let initial_distance = Rc::new(Cell::new(None::<f64>));
+
+    let pointers_for_move = Rc::clone(&pointers);
+    let initial_distance_for_move = Rc::clone(&initial_distance);
+
+    let remove_pointer = {
+        let pointers = Rc::clone(&pointers);
+        let initial_distance = Rc::clone(&initial_distance);
+        move |pointer_id| {
+            pointers.borrow_mut().remove(&pointer_id);
+            initial_distance.set(None);
+        }
+    };
+    let remove_pointer_for_cancel = remove_pointer.clone();
+
+    // one finger still scrolls, two fingers don't zoom the page
+    add_touch_action(raw_el, "pan-x pan-y")
+        .event_handler(move |event: events_extra::PointerDown| {
+            let mut pointers = pointers.borrow_mut();
+            pointers.insert(
+                event.pointer_id(),
+                (f64::from(event.x()), f64::from(event.y())),
+            );
+            initial_distance.set(two_pointers(&pointers).map(|(a, b)| distance(a, b)));
+        })
+        .event_handler(move |event: events_extra::PointerMove| {
+            let mut pointers = pointers_for_move.borrow_mut();
+            match pointers.get_mut(&event.pointer_id()) {
+                Some(position) => *position = (f64::from(event.x()), f64::from(event.y())),
+                None => return,
+            }
+            let (initial_distance, (a, b)) =
+                match (initial_distance_for_move.get(), two_pointers(&pointers)) {
+                    (Some(initial_distance), Some(pointers)) if initial_distance > 0. => {
+                        (initial_distance, pointers)
+                    }
+                    _ => return,
+                };
+            let pinch_event = PinchEvent {
+                scale: distance(a, b) / initial_distance,
+                center: ((a.0 + b.0) / 2., (a.1 + b.1) / 2.),
+            };
+            drop(pointers);
+            handler.clone()(pinch_event)
+        })
+        .event_handler(move |event: events_extra::PointerUp| remove_pointer(event.pointer_id()))
+        .event_handler(move |event: events_extra::PointerCancel| {
+            remove_pointer_for_cancel(event.pointer_id())
+        })
+}
+
+// ------ helpers ------
+
+/// Combines `touch_action` with the `touch-action` set by other gesture handlers,
+/// so only the gestures allowed by all of them are left to the browser.
+fn add_touch_action<RE: RawEl>(raw_el: RE, touch_action: &str) -> RE {
+    let dom_element: web_sys::Element = raw_el.dom_element().into();
+    let current_touch_action = dom_element
+        .unchecked_ref::<web_sys::HtmlElement>()
+        .style()
+        .get_property_value("touch-action")
+        .unwrap_throw();
+    let current_touch_action = match current_touch_action.as_str() {
+        "" | "auto" => return raw_el.style("touch-action", touch_action),
+        "manipulation" => "pan-x pan-y pinch-zoom".to_owned(),
+        _ => current_touch_action,
+    };
+    let combined_touch_action = touch_action
+        .split_whitespace()
+        .filter(|keyword| {
+            current_touch_action
+                .split_whitespace()
+                .any(|current_keyword| current_keyword == *keyword)
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    if combined_touch_action.is_empty() {
+        raw_el.style("touch-action", "none")
+    } else {
+        raw_el.style("touch-action", &combined_touch_action)
+    }
+}
+
+/// `Some` only when exactly two pointers are down.
+fn two_pointers(pointers: &BTreeMap<i32, (f64, f64)>) -> Option<((f64, f64), (f64, f64))> {
+    let mut positions = pointers.values().copied();
+    match (positions.next(), positions.next(), positions.next()) {
+        (Some(a), Some(b), None) => Some((a, b)),
+        _ => None,
+    }
+}
+
+fn distance((ax, ay): (f64, f64), (bx, by): (f64, f64)) -> f64 {
+    (bx - ax).hypot(by - ay)
+}
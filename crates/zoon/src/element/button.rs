@@ -170,6 +170,7 @@ impl<LabelFlag, OnPressFlag, RE: RawEl> AddNearbyElement<'_>
 {
 }
 impl<LabelFlag, OnPressFlag, RE: RawEl> HasIds for Button<LabelFlag, OnPressFlag, RE> {}
+impl<LabelFlag, OnPressFlag, RE: RawEl> Disableable for Button<LabelFlag, OnPressFlag, RE> {}
 
 // ------ ------
 //  Attributes
@@ -205,9 +206,13 @@ impl<'a, LabelFlag, OnPressFlag, RE: RawEl> Button<LabelFlag, OnPressFlag, RE> {
     where
         OnPressFlag: FlagNotSet,
     {
-        self.raw_el = self
-            .raw_el
-            .event_handler(move |_: events::Click| (on_press.clone())());
+        let dom_element = self.raw_el.dom_element();
+        self.raw_el = self.raw_el.event_handler(move |_: events::Click| {
+            // see `Disableable`
+            if not(AsRef::<web_sys::Element>::as_ref(&dom_element).has_attribute("disabled")) {
+                (on_press.clone())()
+            }
+        });
         self.into_type()
     }
 
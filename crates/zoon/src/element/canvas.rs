@@ -82,6 +82,44 @@ impl<'a, WidthFlag, HeightFlag, RE: RawEl> Canvas<WidthFlag, HeightFlag, RE> {
         self.into_type()
     }
 
+    /// Sets the canvas resolution to match its CSS size multiplied by `devicePixelRatio`
+    /// and scales the 2D context accordingly so drawing stays sharp on HiDPI screens.
+    /// The canvas fills its parent.
+    ///
+    /// Note: Resizing clears the canvas - redraw it in an `on_resize` handler
+    /// registered after this call.
+    pub fn resize_to_parent(self) -> Canvas<WidthFlagSet, HeightFlagSet, RE>
+    where
+        WidthFlag: FlagNotSet,
+        HeightFlag: FlagNotSet,
+        RE::DomElement: AsRef<web_sys::HtmlCanvasElement>,
+    {
+        let dom_element = self.raw_el.dom_element();
+        self.update_raw_el(|raw_el| {
+            raw_el
+                .style("display", "block")
+                .style("width", "100%")
+                .style("height", "100%")
+                .on_resize(move |width, height| {
+                    let canvas: &web_sys::HtmlCanvasElement = dom_element.as_ref();
+                    let device_pixel_ratio = window().device_pixel_ratio();
+                    canvas.set_width((f64::from(width) * device_pixel_ratio).round() as u32);
+                    canvas.set_height((f64::from(height) * device_pixel_ratio).round() as u32);
+                    context_2d(canvas)
+                        .set_transform(device_pixel_ratio, 0., 0., device_pixel_ratio, 0., 0.)
+                        .unwrap_throw();
+                })
+        })
+        .into_type()
+    }
+
+    pub fn context_2d(&self) -> web_sys::CanvasRenderingContext2d
+    where
+        RE::DomElement: AsRef<web_sys::HtmlCanvasElement>,
+    {
+        context_2d(self.raw_el.dom_element().as_ref())
+    }
+
     fn into_type<NewWidthFlag, NewHeightFlag>(self) -> Canvas<NewWidthFlag, NewHeightFlag, RE> {
         Canvas {
             raw_el: self.raw_el,
@@ -89,3 +127,13 @@ impl<'a, WidthFlag, HeightFlag, RE: RawEl> Canvas<WidthFlag, HeightFlag, RE> {
         }
     }
 }
+
+// ------ helpers ------
+
+fn context_2d(canvas: &web_sys::HtmlCanvasElement) -> web_sys::CanvasRenderingContext2d {
+    canvas
+        .get_context("2d")
+        .unwrap_throw()
+        .expect_throw("failed to get canvas 2d context")
+        .unchecked_into()
+}
@@ -32,9 +32,11 @@ impl
 {
     pub fn new() -> Self {
         let check_state = Mutable::new(CheckState::NotSet);
+        let raw_el = RawHtmlEl::<web_sys::HtmlDivElement>::new("div");
+        let dom_element = raw_el.dom_element();
         Self {
             check_state: check_state.clone(),
-            raw_el: RawHtmlEl::<web_sys::HtmlDivElement>::new("div")
+            raw_el: raw_el
                 .class("checkbox")
                 .attr("role", "checkbox")
                 .attr("aria-live", "polite")
@@ -52,6 +54,10 @@ impl
                 .style("display", "inline-flex")
                 .style("flex-direction", "column")
                 .event_handler(move |_: events::Click| {
+                    // see `Disableable`
+                    if dom_element.has_attribute("disabled") {
+                        return;
+                    }
                     check_state.update(|check_state| match check_state {
                         CheckState::NotSet => CheckState::FirstValue(true),
                         CheckState::FirstValue(checked) | CheckState::Value(checked) => {
@@ -123,6 +129,10 @@ impl<IdFlag, OnChangeFlag, LabelFlag, IconFlag, CheckedFlag, RE: RawEl> Keyboard
     for Checkbox<IdFlag, OnChangeFlag, LabelFlag, IconFlag, CheckedFlag, RE>
 {
 }
+impl<IdFlag, OnChangeFlag, LabelFlag, IconFlag, CheckedFlag, RE: RawEl> Disableable
+    for Checkbox<IdFlag, OnChangeFlag, LabelFlag, IconFlag, CheckedFlag, RE>
+{
+}
 impl<IdFlag, OnChangeFlag, LabelFlag, IconFlag, CheckedFlag, RE: RawEl> Focusable
     for Checkbox<IdFlag, OnChangeFlag, LabelFlag, IconFlag, CheckedFlag, RE>
 where
@@ -0,0 +1,482 @@
+use crate::*;
+use futures_signals::signal_vec::VecDiff;
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    mem, ptr,
+    rc::{Rc, Weak},
+};
+
+// ------ ChildTransition ------
+
+/// Styles animated when the child of `animated_child_signal`
+/// or an item of `animated_children_signal_vec` enters or leaves.
+///
+/// The leaving child stays mounted until its exit transition has finished,
+/// so both children are rendered for the transition `duration`.
+/// Overlay them (e.g. with `Stack`) if they shouldn't push each other.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let counter = Mutable::new(0);
+/// let counter_view = El::new().animated_child_signal(
+///     counter.signal(),
+///     ChildTransition::fade()
+///         .enter_style("transform", "translateY(-20px)")
+///         .exit_style("transform", "translateY(20px)")
+///         .duration(250),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChildTransition {
+    duration: u32,
+    enter_styles: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    exit_styles: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+}
+
+impl Default for ChildTransition {
+    fn default() -> Self {
+        Self {
+            duration: 300,
+            enter_styles: Vec::new(),
+            exit_styles: Vec::new(),
+        }
+    }
+}
+
+impl ChildTransition {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn fade() -> Self {
+        Self::new()
+            .enter_style("opacity", "0")
+            .exit_style("opacity", "0")
+    }
+
+    pub fn duration(mut self, ms: u32) -> Self {
+        self.duration = ms;
+        self
+    }
+
+    /// A style of the entering child before its enter transition starts.
+    pub fn enter_style(
+        mut self,
+        name: impl IntoCowStr<'static>,
+        value: impl IntoCowStr<'static>,
+    ) -> Self {
+        self.enter_styles
+            .push((name.into_cow_str(), value.into_cow_str()));
+        self
+    }
+
+    /// A style of the leaving child when its exit transition ends.
+    pub fn exit_style(
+        mut self,
+        name: impl IntoCowStr<'static>,
+        value: impl IntoCowStr<'static>,
+    ) -> Self {
+        self.exit_styles
+            .push((name.into_cow_str(), value.into_cow_str()));
+        self
+    }
+
+    fn transition_value(&self, properties: &[AnimatedProperty], with_moves: bool) -> String {
+        let mut names = properties
+            .iter()
+            .map(|property| property.name.as_ref())
+            .collect::<Vec<_>>();
+        if with_moves && not(names.contains(&"transform")) {
+            names.push("transform");
+        }
+        names
+            .into_iter()
+            .map(|name| crate::format!("{} {}ms", name, self.duration))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn animated_properties(&self) -> Vec<AnimatedProperty> {
+        let mut properties = Vec::<AnimatedProperty>::new();
+        for (name, _) in self.enter_styles.iter().chain(&self.exit_styles) {
+            if properties.iter().any(|property| property.name == *name) {
+                continue;
+            }
+            let value_of = |styles: &[(Cow<'static, str>, Cow<'static, str>)]| {
+                styles
+                    .iter()
+                    .rev()
+                    .find(|(style_name, _)| style_name == name)
+                    .map(|(_, value)| value.clone())
+            };
+            properties.push(AnimatedProperty {
+                name: name.clone(),
+                enter_value: value_of(&self.enter_styles),
+                exit_value: value_of(&self.exit_styles),
+            });
+        }
+        properties
+    }
+}
+
+struct AnimatedProperty {
+    name: Cow<'static, str>,
+    enter_value: Option<Cow<'static, str>>,
+    exit_value: Option<Cow<'static, str>>,
+}
+
+// ------ AnimatedChild ------
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ChildPhase {
+    Entering,
+    Entered,
+    Leaving,
+}
+
+struct AnimatedChild {
+    dom: RefCell<Option<Dom>>,
+    dom_element: RefCell<Option<web_sys::Element>>,
+    phase: Mutable<ChildPhase>,
+    transition_value: String,
+    exit_timer: RefCell<Option<Timer>>,
+}
+
+impl AnimatedChild {
+    fn new<'a>(
+        element: impl IntoElement<'a>,
+        transition: &ChildTransition,
+        entering: bool,
+        with_moves: bool,
+    ) -> Rc<Self> {
+        let properties = transition.animated_properties();
+        let initial_phase = if entering {
+            ChildPhase::Entering
+        } else {
+            ChildPhase::Entered
+        };
+        let child = Rc::new(Self {
+            dom: RefCell::new(None),
+            dom_element: RefCell::new(None),
+            phase: Mutable::new(initial_phase),
+            transition_value: transition.transition_value(&properties, with_moves),
+            exit_timer: RefCell::new(None),
+        });
+        let dom = match element.into_element().into_raw_element() {
+            RawElement::El(raw_el) => child.animate(raw_el, properties).into_dom(),
+            RawElement::SvgEl(raw_el) => child.animate(raw_el, properties).into_dom(),
+            RawElement::Text(raw_text) => child
+                .animate(RawHtmlEl::new("span").child(raw_text), properties)
+                .into_dom(),
+        };
+        *child.dom.borrow_mut() = Some(dom);
+        child
+    }
+
+    fn take_dom(&self) -> Dom {
+        self.dom
+            .borrow_mut()
+            .take()
+            .expect_throw("animated child has been already rendered")
+    }
+
+    fn animate<RE: RawEl>(&self, mut raw_el: RE, properties: Vec<AnimatedProperty>) -> RE {
+        raw_el = raw_el.style("transition", &self.transition_value);
+
+        for property in properties {
+            let AnimatedProperty {
+                name,
+                enter_value,
+                exit_value,
+            } = property;
+            raw_el = raw_el.style_signal(
+                name,
+                self.phase.signal().map(move |phase| match phase {
+                    ChildPhase::Entering => enter_value.clone(),
+                    ChildPhase::Entered => None,
+                    ChildPhase::Leaving => exit_value.clone(),
+                }),
+            );
+        }
+
+        *self.dom_element.borrow_mut() = Some(raw_el.dom_element().into());
+        let phase = self.phase.clone();
+        raw_el.after_insert(move |_| {
+            after_next_frame(move || {
+                if phase.get() == ChildPhase::Entering {
+                    phase.set(ChildPhase::Entered);
+                }
+            })
+        })
+    }
+
+    fn is_leaving(&self) -> bool {
+        self.phase.get() == ChildPhase::Leaving
+    }
+
+    /// Starts the exit transition and calls `on_left` when it ends.
+    fn leave(self: &Rc<Self>, duration: u32, on_left: impl FnOnce(&Rc<Self>) + 'static) {
+        self.phase.set(ChildPhase::Leaving);
+        let this = Rc::downgrade(self);
+        let exit_timer = Timer::once(duration, move || {
+            if let Some(this) = Weak::upgrade(&this) {
+                on_left(&this)
+            }
+        });
+        *self.exit_timer.borrow_mut() = Some(exit_timer);
+    }
+
+    fn position(&self) -> Option<(f64, f64)> {
+        let dom_element = self.dom_element.borrow();
+        let dom_element = dom_element.as_ref()?;
+        let rect = dom_element.get_bounding_client_rect();
+        Some((rect.left(), rect.top()))
+    }
+
+    /// FLIP - the child is moved back to its `first_position` by `transform`
+    /// and then it transitions to its current position.
+    /// The inverting translation is prepended to the inline `transform` set by the user
+    /// and the original value is restored afterwards.
+    fn play_move(&self, (first_x, first_y): (f64, f64)) {
+        let (last_x, last_y) = match self.position() {
+            Some(position) => position,
+            None => return,
+        };
+        let (delta_x, delta_y) = (first_x - last_x, first_y - last_y);
+        if delta_x.abs() < 0.5 && delta_y.abs() < 0.5 {
+            return;
+        }
+        let dom_element = self.dom_element.borrow();
+        let style = match dom_element.as_ref().and_then(style_declaration) {
+            Some(style) => style,
+            None => return,
+        };
+        let transform = style.get_property_value("transform").unwrap_throw();
+        style.set_property("transition", "none").unwrap_throw();
+        style
+            .set_property(
+                "transform",
+                &crate::format!("translate({}px, {}px) {}", delta_x, delta_y, transform),
+            )
+            .unwrap_throw();
+        // Forces the browser to render the inverted position before the transition starts.
+        self.position();
+        style
+            .set_property("transition", &self.transition_value)
+            .unwrap_throw();
+        if transform.is_empty() {
+            style.remove_property("transform").unwrap_throw();
+        } else {
+            style.set_property("transform", &transform).unwrap_throw();
+        }
+    }
+}
+
+fn same_child(child: &Rc<AnimatedChild>, other_child: &Rc<AnimatedChild>) -> bool {
+    ptr::eq(Rc::as_ptr(child), Rc::as_ptr(other_child))
+}
+
+// ------ AnimatedChildren ------
+
+pub(crate) struct AnimatedChildren {
+    children: MutableVec<Rc<AnimatedChild>>,
+    transition: ChildTransition,
+}
+
+impl AnimatedChildren {
+    pub(crate) fn new(transition: ChildTransition) -> Rc<Self> {
+        Rc::new(Self {
+            children: MutableVec::new(),
+            transition,
+        })
+    }
+
+    pub(crate) fn doms_signal_vec(&self) -> impl SignalVec<Item = Dom> {
+        self.children
+            .signal_vec_cloned()
+            .map(|child| child.take_dom())
+    }
+
+    /// Starts exit transitions of the current children and adds the new one.
+    pub(crate) fn swap<'a>(self: &Rc<Self>, child: impl IntoOptionElement<'a>) {
+        for leaving_child in self.children.lock_ref().iter() {
+            if leaving_child.is_leaving() {
+                continue;
+            }
+            let this = Rc::downgrade(self);
+            leaving_child.leave(self.transition.duration, move |leaving_child| {
+                if let Some(this) = Weak::upgrade(&this) {
+                    this.children
+                        .lock_mut()
+                        .retain(|child| not(same_child(child, leaving_child)));
+                }
+            });
+        }
+
+        if let Some(element) = child.into_option_element() {
+            let child = AnimatedChild::new(element, &self.transition, true, false);
+            self.children.lock_mut().push_cloned(child);
+        }
+    }
+}
+
+// ------ AnimatedItems ------
+
+/// Items of a `SignalVec` with enter, exit and move (FLIP) animations.
+pub(crate) struct AnimatedItems {
+    /// Items of the source `SignalVec`.
+    items: RefCell<Vec<Rc<AnimatedChild>>>,
+    /// The rendered items - `items` and leaving items.
+    children: MutableVec<Rc<AnimatedChild>>,
+    transition: ChildTransition,
+    initialized: Cell<bool>,
+}
+
+impl AnimatedItems {
+    pub(crate) fn new(transition: ChildTransition) -> Rc<Self> {
+        Rc::new(Self {
+            items: RefCell::new(Vec::new()),
+            children: MutableVec::new(),
+            transition,
+            initialized: Cell::new(false),
+        })
+    }
+
+    pub(crate) fn doms_signal_vec(&self) -> impl SignalVec<Item = Dom> {
+        self.children
+            .signal_vec_cloned()
+            .map(|child| child.take_dom())
+    }
+
+    pub(crate) fn apply_diff<'a>(self: &Rc<Self>, diff: VecDiff<impl IntoElement<'a>>) {
+        // Initial items appear without the enter transition.
+        let entering = self.initialized.replace(true);
+        let new_item = |element| AnimatedChild::new(element, &self.transition, entering, true);
+        let first_positions = self.positions();
+        let mut leaving_items = Vec::new();
+        {
+            let mut items = self.items.borrow_mut();
+            let mut children = self.children.lock_mut();
+            let child_index = |children: &[Rc<AnimatedChild>], item: &Rc<AnimatedChild>| {
+                children
+                    .iter()
+                    .position(|child| same_child(child, item))
+                    .unwrap_throw()
+            };
+            match diff {
+                VecDiff::Replace { values } => {
+                    leaving_items.extend(items.drain(..));
+                    for value in values {
+                        let item = new_item(value);
+                        children.push_cloned(Rc::clone(&item));
+                        items.push(item);
+                    }
+                }
+                VecDiff::InsertAt { index, value } => {
+                    let item = new_item(value);
+                    let insert_index = match items.get(index) {
+                        Some(next_item) => child_index(&children, next_item),
+                        None => children.len(),
+                    };
+                    children.insert_cloned(insert_index, Rc::clone(&item));
+                    items.insert(index, item);
+                }
+                VecDiff::UpdateAt { index, value } => {
+                    let item = new_item(value);
+                    let old_item = mem::replace(&mut items[index], Rc::clone(&item));
+                    children.insert_cloned(child_index(&children, &old_item), item);
+                    leaving_items.push(old_item);
+                }
+                VecDiff::RemoveAt { index } => {
+                    leaving_items.push(items.remove(index));
+                }
+                VecDiff::Move {
+                    old_index,
+                    new_index,
+                } => {
+                    let item = items.remove(old_index);
+                    children.remove(child_index(&children, &item));
+                    let insert_index = match items.get(new_index) {
+                        Some(next_item) => child_index(&children, next_item),
+                        None => children.len(),
+                    };
+                    children.insert_cloned(insert_index, Rc::clone(&item));
+                    items.insert(new_index, item);
+                }
+                VecDiff::Push { value } => {
+                    let item = new_item(value);
+                    children.push_cloned(Rc::clone(&item));
+                    items.push(item);
+                }
+                VecDiff::Pop {} => {
+                    leaving_items.extend(items.pop());
+                }
+                VecDiff::Clear {} => {
+                    leaving_items.extend(items.drain(..));
+                }
+            }
+        }
+        for leaving_item in leaving_items {
+            self.leave(&leaving_item);
+        }
+        play_moves(first_positions);
+    }
+
+    fn leave(self: &Rc<Self>, item: &Rc<AnimatedChild>) {
+        let this = Rc::downgrade(self);
+        item.leave(self.transition.duration, move |item| {
+            if let Some(this) = Weak::upgrade(&this) {
+                let first_positions = this.positions();
+                this.children
+                    .lock_mut()
+                    .retain(|child| not(same_child(child, item)));
+                play_moves(first_positions);
+            }
+        });
+    }
+
+    fn positions(&self) -> Vec<(Rc<AnimatedChild>, (f64, f64))> {
+        self.children
+            .lock_ref()
+            .iter()
+            .filter_map(|child| Some((Rc::clone(child), child.position()?)))
+            .collect()
+    }
+}
+
+// ------ helpers ------
+
+fn play_moves(first_positions: Vec<(Rc<AnimatedChild>, (f64, f64))>) {
+    if first_positions.is_empty() {
+        return;
+    }
+    // The task runs after the rendered children have been updated.
+    Task::start(async move {
+        for (child, first_position) in first_positions {
+            child.play_move(first_position);
+        }
+    });
+}
+
+/// The entering styles have to be rendered before they are removed to run the transition.
+fn after_next_frame(f: impl FnOnce() + 'static) {
+    let request_frame = |callback: JsValue| {
+        window()
+            .request_animation_frame(callback.unchecked_ref())
+            .unwrap_throw();
+    };
+    request_frame(Closure::once_into_js(move || {
+        request_frame(Closure::once_into_js(f))
+    }));
+}
+
+fn style_declaration(dom_element: &web_sys::Element) -> Option<web_sys::CssStyleDeclaration> {
+    if let Some(html_element) = dom_element.dyn_ref::<web_sys::HtmlElement>() {
+        return Some(html_element.style());
+    }
+    dom_element
+        .dyn_ref::<web_sys::SvgElement>()
+        .map(|svg_element| svg_element.style())
+}
@@ -0,0 +1,204 @@
+use crate::*;
+use std::{cell::Cell, iter, marker::PhantomData, rc::Rc};
+
+// ------ ------
+//   Element
+// ------ ------
+
+make_flags!(Child, Open);
+
+const DEFAULT_DURATION: u32 = 200;
+
+/// A panel that expands to its natural height (`height: auto`) and collapses to zero.
+///
+/// CSS can't transition `height: auto`, so the content is measured, the height is transitioned
+/// between `0px` and the measured pixel height, and `auto` is restored once the panel is expanded
+/// so it still follows content changes. Collapsed content is hidden (`visibility: hidden`)
+/// to remove it from the tab order. There is no animation when the user prefers reduced motion.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let expanded = Mutable::new(false);
+///
+/// let faq = Column::new()
+///     .item(Button::new().label("What is Zoon?").on_press({
+///         let expanded = expanded.clone();
+///         move || expanded.update(|expanded| !expanded)
+///     }))
+///     .item(
+///         Collapse::new()
+///             .open_signal(expanded.signal())
+///             .duration(300)
+///             .child(Paragraph::new().content("A Rust frontend framework.")),
+///     );
+/// ```
+pub struct Collapse<ChildFlag, OpenFlag, RE: RawEl> {
+    duration: Rc<Cell<u32>>,
+    raw_el: RE,
+    flags: PhantomData<(ChildFlag, OpenFlag)>,
+}
+
+impl Collapse<ChildFlagNotSet, OpenFlagNotSet, RawHtmlEl<web_sys::HtmlElement>> {
+    pub fn new() -> Self {
+        Self {
+            duration: Rc::new(Cell::new(DEFAULT_DURATION)),
+            raw_el: RawHtmlEl::new("div")
+                .class("collapse")
+                .style("overflow", "hidden"),
+            flags: PhantomData,
+        }
+    }
+}
+
+impl<ChildFlag, RE: RawEl + Into<RawElement>> Element for Collapse<ChildFlag, OpenFlagSet, RE> {
+    fn into_raw_element(self) -> RawElement {
+        self.raw_el.into()
+    }
+}
+
+impl<ChildFlag, OpenFlag, RE: RawEl> IntoIterator for Collapse<ChildFlag, OpenFlag, RE> {
+    type Item = Self;
+    type IntoIter = iter::Once<Self>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        iter::once(self)
+    }
+}
+
+impl<ChildFlag, OpenFlag, RE: RawEl> UpdateRawEl for Collapse<ChildFlag, OpenFlag, RE> {
+    type RawEl = RE;
+
+    fn update_raw_el(mut self, updater: impl FnOnce(Self::RawEl) -> Self::RawEl) -> Self {
+        self.raw_el = updater(self.raw_el);
+        self
+    }
+}
+
+// ------ ------
+//   Abilities
+// ------ ------
+
+impl<ChildFlag, OpenFlag, RE: RawEl> Styleable<'_> for Collapse<ChildFlag, OpenFlag, RE> {}
+impl<ChildFlag, OpenFlag, RE: RawEl> KeyboardEventAware for Collapse<ChildFlag, OpenFlag, RE> {}
+impl<ChildFlag, OpenFlag, RE: RawEl> MouseEventAware for Collapse<ChildFlag, OpenFlag, RE> {}
+impl<ChildFlag, OpenFlag, RE: RawEl> PointerEventAware for Collapse<ChildFlag, OpenFlag, RE> {}
+impl<ChildFlag, OpenFlag, RE: RawEl> TouchEventAware for Collapse<ChildFlag, OpenFlag, RE> {}
+impl<ChildFlag, OpenFlag, RE: RawEl> Hookable for Collapse<ChildFlag, OpenFlag, RE> {}
+impl<ChildFlag, OpenFlag, RE: RawEl> HasIds for Collapse<ChildFlag, OpenFlag, RE> {}
+impl<ChildFlag, OpenFlag, RE: RawEl> SelectableTextContent for Collapse<ChildFlag, OpenFlag, RE> {}
+
+// ------ ------
+//  Attributes
+// ------ ------
+
+impl<'a, ChildFlag, OpenFlag, RE: RawEl> Collapse<ChildFlag, OpenFlag, RE> {
+    pub fn child(
+        mut self,
+        child: impl IntoOptionElement<'a> + 'a,
+    ) -> Collapse<ChildFlagSet, OpenFlag, RE>
+    where
+        ChildFlag: FlagNotSet,
+    {
+        self.raw_el = self.raw_el.child(child);
+        self.into_type()
+    }
+
+    pub fn child_signal(
+        mut self,
+        child: impl Signal<Item = impl IntoOptionElement<'a>> + Unpin + 'static,
+    ) -> Collapse<ChildFlagSet, OpenFlag, RE>
+    where
+        ChildFlag: FlagNotSet,
+    {
+        self.raw_el = self.raw_el.child_signal(child);
+        self.into_type()
+    }
+
+    /// The first value is applied without animation.
+    pub fn open_signal(
+        mut self,
+        open: impl Signal<Item = bool> + Unpin + 'static,
+    ) -> Collapse<ChildFlag, OpenFlagSet, RE>
+    where
+        OpenFlag: FlagNotSet,
+        RE::DomElement: AsRef<web_sys::HtmlElement>,
+    {
+        let element: web_sys::HtmlElement = self.raw_el.dom_element().as_ref().clone();
+        let is_open = Rc::new(Cell::new(None));
+
+        let on_transition_end = {
+            let element = element.clone();
+            let is_open = Rc::clone(&is_open);
+            move |event: events_extra::TransitionEnd| {
+                // transitions of descendants bubble up
+                let this: &web_sys::EventTarget = element.as_ref();
+                if event.target().as_ref() != Some(this) {
+                    return;
+                }
+                match is_open.get() {
+                    Some(true) => set_style(&element, "height", ""),
+                    Some(false) => set_style(&element, "visibility", "hidden"),
+                    None => (),
+                }
+            }
+        };
+
+        let duration = Rc::clone(&self.duration);
+        let toggler = open.for_each_sync(move |open| {
+            let first_value = is_open.replace(Some(open)).is_none();
+            if first_value || duration.get() == 0 || media::prefers_reduced_motion() {
+                set_style(&element, "transition", "none");
+                set_style(&element, "height", if open { "" } else { "0px" });
+                set_style(&element, "visibility", if open { "" } else { "hidden" });
+                return;
+            }
+            let current_height = element.offset_height();
+            let target_height = if open { element.scroll_height() } else { 0 };
+            if current_height == target_height {
+                // `transitionend` wouldn't be fired
+                set_style(&element, "height", if open { "" } else { "0px" });
+                set_style(&element, "visibility", if open { "" } else { "hidden" });
+                return;
+            }
+            set_style(&element, "visibility", "");
+            // `auto` can't be transitioned, start from the explicit height
+            set_style(&element, "transition", "none");
+            set_style(&element, "height", &crate::format!("{}px", current_height));
+            // force a reflow so the transition starts from the height set above
+            element.offset_height();
+            set_style(
+                &element,
+                "transition",
+                &crate::format!("height {}ms ease", duration.get()),
+            );
+            set_style(&element, "height", &crate::format!("{}px", target_height));
+        });
+        let toggler = Task::start_droppable(toggler);
+
+        self.raw_el = self
+            .raw_el
+            .event_handler(on_transition_end)
+            .after_remove(move |_| drop(toggler));
+        self.into_type()
+    }
+
+    /// The transition duration in milliseconds, 200 by default.
+    pub fn duration(self, ms: u32) -> Self {
+        self.duration.set(ms);
+        self
+    }
+
+    fn into_type<NewChildFlag, NewOpenFlag>(self) -> Collapse<NewChildFlag, NewOpenFlag, RE> {
+        Collapse {
+            duration: self.duration,
+            raw_el: self.raw_el,
+            flags: PhantomData,
+        }
+    }
+}
+
+fn set_style(element: &web_sys::HtmlElement, name: &str, value: &str) {
+    element.style().set_property(name, value).unwrap_throw();
+}
@@ -79,6 +79,10 @@ impl ChoosableTag for Column<EmptyFlagSet, RawHtmlEl<web_sys::HtmlElement>> {
 }
 impl<EmptyFlag, RE: RawEl> Styleable<'_> for Column<EmptyFlag, RE> {}
 impl<EmptyFlag, RE: RawEl> KeyboardEventAware for Column<EmptyFlag, RE> {}
+impl<EmptyFlag, RE: RawEl> Focusable for Column<EmptyFlag, RE> where
+    RE::DomElement: AsRef<web_sys::HtmlElement>
+{
+}
 impl<EmptyFlag, RE: RawEl> MouseEventAware for Column<EmptyFlag, RE> {}
 impl<EmptyFlag, RE: RawEl> PointerEventAware for Column<EmptyFlag, RE> {}
 impl<EmptyFlag, RE: RawEl> TouchEventAware for Column<EmptyFlag, RE> {}
@@ -123,6 +127,26 @@ impl<'a, EmptyFlag, RE: RawEl> Column<EmptyFlag, RE> {
         self.into_type()
     }
 
+    /// Animates added, removed and moved items. See `ChildTransition`.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let todos = MutableVec::new_with_values(vec!["Buy milk".to_owned()]);
+    /// let todo_list = Column::new().animated_items_signal_vec(
+    ///     todos.signal_vec_cloned(),
+    ///     ChildTransition::fade().enter_style("transform", "scale(0.9)"),
+    /// );
+    /// ```
+    pub fn animated_items_signal_vec(
+        mut self,
+        items: impl SignalVec<Item = impl IntoElement<'a>> + Unpin + 'static,
+        transition: ChildTransition,
+    ) -> Column<EmptyFlagNotSet, RE> {
+        self.raw_el = self.raw_el.animated_children_signal_vec(items, transition);
+        self.into_type()
+    }
+
     fn into_type<NewEmptyFlag>(self) -> Column<NewEmptyFlag, RE> {
         Column {
             raw_el: self.raw_el,
@@ -0,0 +1,187 @@
+use crate::*;
+use std::{iter, marker::PhantomData};
+
+// ------ LoadState ------
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadState<T, E> {
+    Loading,
+    Loaded(T),
+    Failed(E),
+}
+
+impl<T, E> Default for LoadState<T, E> {
+    fn default() -> Self {
+        Self::Loading
+    }
+}
+
+impl<T, E> From<Result<T, E>> for LoadState<T, E> {
+    fn from(result: Result<T, E>) -> Self {
+        match result {
+            Ok(value) => Self::Loaded(value),
+            Err(error) => Self::Failed(error),
+        }
+    }
+}
+
+// ------ ------
+//    Element
+// ------ ------
+
+make_flags!(Child);
+
+/// Renders the loading view (a [Spinner] by default) until the data are loaded,
+/// then the loaded or the error view.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// async fn load_username() -> Result<String, String> {
+///     Ok("Martin".to_owned())
+/// }
+///
+/// let greeting = Deferred::new().future(
+///     load_username(),
+///     |username| Text::new(format!("Hello {}!", username)),
+///     |error| Text::new(format!("Failed to load the user: {}", error)),
+/// );
+/// ```
+/// # Example
+/// Reload the data by setting the state back to `Loading`.
+/// ```no_run
+/// use zoon::*;
+///
+/// let state = Mutable::new(LoadState::<Vec<String>, String>::Loading);
+/// let list = Deferred::new()
+///     .loading(|| "Loading users...")
+///     .state_signal(
+///         state.signal_cloned(),
+///         |users| Column::new().items(users.into_iter().map(Text::new)),
+///         |error| Text::new(error),
+///     );
+/// state.set(LoadState::Loaded(vec!["Martin".to_owned()]));
+/// ```
+pub struct Deferred<ChildFlag, RE: RawEl> {
+    raw_el: RE,
+    loading: Box<dyn FnMut() -> Option<RawElement>>,
+    flags: PhantomData<ChildFlag>,
+}
+
+impl Deferred<ChildFlagNotSet, RawHtmlEl<web_sys::HtmlElement>> {
+    pub fn new() -> Self {
+        Self {
+            raw_el: RawHtmlEl::<web_sys::HtmlElement>::new("div")
+                .class("deferred")
+                .attr("aria-live", "polite")
+                .style("display", "inline-flex")
+                .style("flex-direction", "column"),
+            loading: Box::new(|| Some(Spinner::new().into_raw_element())),
+            flags: PhantomData,
+        }
+    }
+}
+
+impl<RE: RawEl + Into<RawElement>> Element for Deferred<ChildFlagSet, RE> {
+    fn into_raw_element(self) -> RawElement {
+        self.raw_el.into()
+    }
+}
+
+impl<ChildFlag, RE: RawEl> IntoIterator for Deferred<ChildFlag, RE> {
+    type Item = Self;
+    type IntoIter = iter::Once<Self>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        iter::once(self)
+    }
+}
+
+impl<ChildFlag, RE: RawEl> UpdateRawEl for Deferred<ChildFlag, RE> {
+    type RawEl = RE;
+
+    fn update_raw_el(mut self, updater: impl FnOnce(Self::RawEl) -> Self::RawEl) -> Self {
+        self.raw_el = updater(self.raw_el);
+        self
+    }
+}
+
+// ------ ------
+//   Abilities
+// ------ ------
+
+impl<ChildFlag, RE: RawEl> Styleable<'_> for Deferred<ChildFlag, RE> {}
+impl<ChildFlag, RE: RawEl> Hookable for Deferred<ChildFlag, RE> {}
+impl<ChildFlag, RE: RawEl> HasIds for Deferred<ChildFlag, RE> {}
+
+// ------ ------
+//  Attributes
+// ------ ------
+
+impl<ChildFlag, RE: RawEl> Deferred<ChildFlag, RE> {
+    /// Replaces the default [Spinner].
+    pub fn loading<L: IntoOptionElement<'static> + 'static>(
+        mut self,
+        mut view: impl FnMut() -> L + 'static,
+    ) -> Self
+    where
+        ChildFlag: FlagNotSet,
+    {
+        self.loading =
+            Box::new(move || view().into_option_element().map(Element::into_raw_element));
+        self
+    }
+
+    pub fn future<T, E, C, F>(
+        self,
+        future: impl Future<Output = Result<T, E>> + 'static,
+        loaded: impl FnMut(T) -> C + 'static,
+        failed: impl FnMut(E) -> F + 'static,
+    ) -> Deferred<ChildFlagSet, RE>
+    where
+        ChildFlag: FlagNotSet,
+        C: IntoOptionElement<'static> + 'static,
+        F: IntoOptionElement<'static> + 'static,
+    {
+        let state = signal::from_future(Box::pin(future)).map(|result| match result {
+            Some(result) => LoadState::from(result),
+            None => LoadState::Loading,
+        });
+        self.state_signal(state, loaded, failed)
+    }
+
+    pub fn state_signal<T, E, C, F>(
+        mut self,
+        state: impl Signal<Item = LoadState<T, E>> + Unpin + 'static,
+        mut loaded: impl FnMut(T) -> C + 'static,
+        mut failed: impl FnMut(E) -> F + 'static,
+    ) -> Deferred<ChildFlagSet, RE>
+    where
+        ChildFlag: FlagNotSet,
+        C: IntoOptionElement<'static> + 'static,
+        F: IntoOptionElement<'static> + 'static,
+    {
+        let mut loading = self.loading;
+        self.loading = Box::new(|| None);
+        let child = state.map(move |state| match state {
+            LoadState::Loading => loading(),
+            LoadState::Loaded(value) => loaded(value)
+                .into_option_element()
+                .map(Element::into_raw_element),
+            LoadState::Failed(error) => failed(error)
+                .into_option_element()
+                .map(Element::into_raw_element),
+        });
+        self.raw_el = self.raw_el.child_signal(child);
+        self.into_type()
+    }
+
+    fn into_type<NewChildFlag>(self) -> Deferred<NewChildFlag, RE> {
+        Deferred {
+            raw_el: self.raw_el,
+            loading: self.loading,
+            flags: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use crate::*;
+use std::{cell::Cell, iter, marker::PhantomData, rc::Rc};
+
+// ------ ------
+//   Element
+// ------ ------
+
+make_flags!(Child, Open);
+
+/// A modal `<dialog>` with a native backdrop and focus trapping.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let open = Mutable::new(false);
+/// let open_for_close = open.clone();
+///
+/// let dialog = Dialog::new()
+///     .open_signal(open.signal())
+///     .on_close(move || open_for_close.set_neq(false))
+///     .close_on_backdrop_click()
+///     .s_backdrop(Background::new().color(hsluv!(0, 0, 0, 50)))
+///     .child("Press Esc or click outside to close me");
+/// ```
+pub struct Dialog<ChildFlag, OpenFlag, RE: RawEl> {
+    raw_el: RE,
+    flags: PhantomData<(ChildFlag, OpenFlag)>,
+}
+
+impl Dialog<ChildFlagNotSet, OpenFlagNotSet, RawHtmlEl<web_sys::HtmlDialogElement>> {
+    pub fn new() -> Self {
+        Self {
+            raw_el: RawHtmlEl::<web_sys::HtmlDialogElement>::new("dialog").class("dialog"),
+            flags: PhantomData,
+        }
+    }
+}
+
+impl<ChildFlag, OpenFlag, RE: RawEl + Into<RawElement>> Element
+    for Dialog<ChildFlag, OpenFlag, RE>
+{
+    fn into_raw_element(self) -> RawElement {
+        self.raw_el.into()
+    }
+}
+
+impl<ChildFlag, OpenFlag, RE: RawEl> IntoIterator for Dialog<ChildFlag, OpenFlag, RE> {
+    type Item = Self;
+    type IntoIter = iter::Once<Self>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        iter::once(self)
+    }
+}
+
+impl<ChildFlag, OpenFlag, RE: RawEl> UpdateRawEl for Dialog<ChildFlag, OpenFlag, RE> {
+    type RawEl = RE;
+
+    fn update_raw_el(mut self, updater: impl FnOnce(Self::RawEl) -> Self::RawEl) -> Self {
+        self.raw_el = updater(self.raw_el);
+        self
+    }
+}
+
+// ------ ------
+//   Abilities
+// ------ ------
+
+impl<ChildFlag, OpenFlag, RE: RawEl> Styleable<'_> for Dialog<ChildFlag, OpenFlag, RE> {}
+impl<ChildFlag, OpenFlag, RE: RawEl> KeyboardEventAware for Dialog<ChildFlag, OpenFlag, RE> {}
+impl<ChildFlag, OpenFlag, RE: RawEl> MouseEventAware for Dialog<ChildFlag, OpenFlag, RE> {}
+impl<ChildFlag, OpenFlag, RE: RawEl> PointerEventAware for Dialog<ChildFlag, OpenFlag, RE> {}
+impl<ChildFlag, OpenFlag, RE: RawEl> TouchEventAware for Dialog<ChildFlag, OpenFlag, RE> {}
+impl<ChildFlag, OpenFlag, RE: RawEl> Hookable for Dialog<ChildFlag, OpenFlag, RE> {}
+impl<ChildFlag, OpenFlag, RE: RawEl> HasIds for Dialog<ChildFlag, OpenFlag, RE> {}
+impl<ChildFlag, OpenFlag, RE: RawEl> SelectableTextContent for Dialog<ChildFlag, OpenFlag, RE> {}
+
+// ------ ------
+//  Attributes
+// ------ ------
+
+impl<'a, ChildFlag, OpenFlag, RE: RawEl> Dialog<ChildFlag, OpenFlag, RE> {
+    pub fn child(
+        mut self,
+        child: impl IntoOptionElement<'a> + 'a,
+    ) -> Dialog<ChildFlagSet, OpenFlag, RE>
+    where
+        ChildFlag: FlagNotSet,
+    {
+        self.raw_el = self.raw_el.child(child);
+        self.into_type()
+    }
+
+    pub fn child_signal(
+        mut self,
+        child: impl Signal<Item = impl IntoOptionElement<'a>> + Unpin + 'static,
+    ) -> Dialog<ChildFlagSet, OpenFlag, RE>
+    where
+        ChildFlag: FlagNotSet,
+    {
+        self.raw_el = self.raw_el.child_signal(child);
+        self.into_type()
+    }
+
+    /// Calls `showModal()` when the signal changes to `true` and `close()` when it changes to `false`.
+    /// Pair it with `on_close` to keep your state in sync when the user dismisses the dialog.
+    pub fn open_signal(
+        mut self,
+        open: impl Signal<Item = bool> + Unpin + 'static,
+    ) -> Dialog<ChildFlag, OpenFlagSet, RE>
+    where
+        OpenFlag: FlagNotSet,
+        RE::DomElement: AsRef<web_sys::HtmlDialogElement>,
+    {
+        // `showModal()` fails on detached elements so we have to wait for the insertion.
+        let open_setter = Rc::new(Cell::new(None));
+        let open_setter_for_insert = Rc::clone(&open_setter);
+
+        self.raw_el = self
+            .raw_el
+            .after_insert(move |ws_element| {
+                let task = Task::start_droppable(open.for_each_sync(move |open| {
+                    let dialog: &web_sys::HtmlDialogElement = ws_element.as_ref();
+                    if open {
+                        if not(dialog.open()) {
+                            dialog.show_modal().unwrap_throw();
+                        }
+                    } else if dialog.open() {
+                        dialog.close();
+                    }
+                }));
+                open_setter_for_insert.set(Some(task));
+            })
+            .after_remove(move |_| drop(open_setter));
+        self.into_type()
+    }
+
+    /// Fired when the dialog has been closed - by `open_signal`, the Esc key or a backdrop click.
+    pub fn on_close(self, on_close: impl FnOnce() + Clone + 'static) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el.event_handler(move |_: events_extra::Close| on_close.clone()())
+        })
+    }
+
+    pub fn close_on_backdrop_click(self) -> Self
+    where
+        RE::DomElement: AsRef<web_sys::HtmlDialogElement>,
+    {
+        let dom_element = self.raw_el.dom_element();
+        self.update_raw_el(|raw_el| {
+            raw_el.event_handler(move |event: events::Click| {
+                let dialog: &web_sys::HtmlDialogElement = dom_element.as_ref();
+                // Clicks on the backdrop target the dialog itself.
+                if event.dyn_target::<web_sys::HtmlDialogElement>().as_ref() != Some(dialog) {
+                    return;
+                }
+                let rect = dialog.get_bounding_client_rect();
+                let (x, y) = (f64::from(event.x()), f64::from(event.y()));
+                let inside =
+                    x >= rect.left() && x <= rect.right() && y >= rect.top() && y <= rect.bottom();
+                if not(inside) {
+                    dialog.close();
+                }
+            })
+        })
+    }
+
+    /// Styles the `::backdrop` pseudo-element rendered behind the modal dialog.
+    pub fn s_backdrop(self, style: impl Style<'a>) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el.style_group(style.merge_with_group(StyleGroup::new("::backdrop")))
+        })
+    }
+
+    fn into_type<NewChildFlag, NewOpenFlag>(self) -> Dialog<NewChildFlag, NewOpenFlag, RE> {
+        Dialog {
+            raw_el: self.raw_el,
+            flags: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use crate::*;
+use std::{iter, marker::PhantomData};
+
+// ------ ------
+//   Element
+// ------ ------
+
+make_flags!(Summary, Content, Open, OnToggle);
+
+/// A collapsible section built on `<details>` and `<summary>`.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let opened = Mutable::new(None);
+///
+/// let accordion = Column::new().items((0..3).map(|index| {
+///     Disclosure::new()
+///         .summary(format!("Section {}", index))
+///         .content("Content")
+///         .exclusive_group(opened.clone(), index)
+/// }));
+/// ```
+pub struct Disclosure<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE: RawEl> {
+    raw_el: RE,
+    flags: PhantomData<(SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag)>,
+}
+
+impl
+    Disclosure<
+        SummaryFlagNotSet,
+        ContentFlagNotSet,
+        OpenFlagNotSet,
+        OnToggleFlagNotSet,
+        RawHtmlEl<web_sys::HtmlDetailsElement>,
+    >
+{
+    pub fn new() -> Self {
+        Self {
+            raw_el: RawHtmlEl::<web_sys::HtmlDetailsElement>::new("details").class("disclosure"),
+            flags: PhantomData,
+        }
+    }
+}
+
+impl<ContentFlag, OpenFlag, OnToggleFlag, RE: RawEl + Into<RawElement>> Element
+    for Disclosure<SummaryFlagSet, ContentFlag, OpenFlag, OnToggleFlag, RE>
+{
+    fn into_raw_element(self) -> RawElement {
+        self.raw_el.into()
+    }
+}
+
+impl<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE: RawEl> IntoIterator
+    for Disclosure<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE>
+{
+    type Item = Self;
+    type IntoIter = iter::Once<Self>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        iter::once(self)
+    }
+}
+
+impl<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE: RawEl> UpdateRawEl
+    for Disclosure<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE>
+{
+    type RawEl = RE;
+
+    fn update_raw_el(mut self, updater: impl FnOnce(Self::RawEl) -> Self::RawEl) -> Self {
+        self.raw_el = updater(self.raw_el);
+        self
+    }
+}
+
+// ------ ------
+//   Abilities
+// ------ ------
+
+impl<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE: RawEl> Styleable<'_>
+    for Disclosure<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE>
+{
+}
+impl<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE: RawEl> KeyboardEventAware
+    for Disclosure<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE>
+{
+}
+impl<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE: RawEl> MouseEventAware
+    for Disclosure<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE>
+{
+}
+impl<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE: RawEl> PointerEventAware
+    for Disclosure<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE>
+{
+}
+impl<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE: RawEl> TouchEventAware
+    for Disclosure<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE>
+{
+}
+impl<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE: RawEl> Hookable
+    for Disclosure<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE>
+{
+}
+impl<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE: RawEl> AddNearbyElement<'_>
+    for Disclosure<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE>
+{
+}
+impl<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE: RawEl> HasIds
+    for Disclosure<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE>
+{
+}
+impl<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE: RawEl> SelectableTextContent
+    for Disclosure<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE>
+{
+}
+
+// ------ ------
+//  Attributes
+// ------ ------
+
+impl<'a, SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE: RawEl>
+    Disclosure<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlag, RE>
+{
+    pub fn summary(
+        mut self,
+        summary: impl IntoOptionElement<'a> + 'a,
+    ) -> Disclosure<SummaryFlagSet, ContentFlag, OpenFlag, OnToggleFlag, RE>
+    where
+        SummaryFlag: FlagNotSet,
+    {
+        self.raw_el = self.raw_el.child(
+            RawHtmlEl::new("summary")
+                .class("summary")
+                .style("cursor", "pointer")
+                .child(summary),
+        );
+        self.into_type()
+    }
+
+    pub fn content(
+        mut self,
+        content: impl IntoOptionElement<'a> + 'a,
+    ) -> Disclosure<SummaryFlag, ContentFlagSet, OpenFlag, OnToggleFlag, RE>
+    where
+        ContentFlag: FlagNotSet,
+    {
+        self.raw_el = self.raw_el.child(content);
+        self.into_type()
+    }
+
+    pub fn content_signal(
+        mut self,
+        content: impl Signal<Item = impl IntoOptionElement<'a>> + Unpin + 'static,
+    ) -> Disclosure<SummaryFlag, ContentFlagSet, OpenFlag, OnToggleFlag, RE>
+    where
+        ContentFlag: FlagNotSet,
+    {
+        self.raw_el = self.raw_el.child_signal(content);
+        self.into_type()
+    }
+
+    pub fn open(
+        mut self,
+        open: bool,
+    ) -> Disclosure<SummaryFlag, ContentFlag, OpenFlagSet, OnToggleFlag, RE>
+    where
+        OpenFlag: FlagNotSet,
+    {
+        if open {
+            self.raw_el = self.raw_el.attr("open", "");
+        }
+        self.into_type()
+    }
+
+    pub fn open_signal(
+        mut self,
+        open: impl Signal<Item = bool> + Unpin + 'static,
+    ) -> Disclosure<SummaryFlag, ContentFlag, OpenFlagSet, OnToggleFlag, RE>
+    where
+        OpenFlag: FlagNotSet,
+        RE::DomElement: AsRef<web_sys::HtmlDetailsElement>,
+    {
+        let dom_element = self.raw_el.dom_element();
+        let open_setter = Task::start_droppable(open.for_each_sync(move |open| {
+            let details: &web_sys::HtmlDetailsElement = dom_element.as_ref();
+            if details.open() != open {
+                details.set_open(open);
+            }
+        }));
+        self.raw_el = self.raw_el.after_remove(move |_| drop(open_setter));
+        self.into_type()
+    }
+
+    /// Fired from the native `toggle` event with the new `open` state,
+    /// both for user interactions and changes made by `open_signal`.
+    pub fn on_toggle(
+        mut self,
+        on_toggle: impl FnOnce(bool) + Clone + 'static,
+    ) -> Disclosure<SummaryFlag, ContentFlag, OpenFlag, OnToggleFlagSet, RE>
+    where
+        OnToggleFlag: FlagNotSet,
+        RE::DomElement: AsRef<web_sys::HtmlDetailsElement>,
+    {
+        let dom_element = self.raw_el.dom_element();
+        self.raw_el = self.raw_el.event_handler(move |_: events_extra::Toggle| {
+            let details: &web_sys::HtmlDetailsElement = dom_element.as_ref();
+            on_toggle.clone()(details.open())
+        });
+        self.into_type()
+    }
+
+    /// Only one `Disclosure` sharing the same `group` is open at a time.
+    /// `group` contains the `index` of the open `Disclosure`.
+    pub fn exclusive_group(
+        self,
+        group: Mutable<Option<usize>>,
+        index: usize,
+    ) -> Disclosure<SummaryFlag, ContentFlag, OpenFlagSet, OnToggleFlagSet, RE>
+    where
+        OpenFlag: FlagNotSet,
+        OnToggleFlag: FlagNotSet,
+        RE::DomElement: AsRef<web_sys::HtmlDetailsElement>,
+    {
+        let open_signal = group
+            .signal()
+            .map(move |open_index| open_index == Some(index));
+        self.open_signal(open_signal).on_toggle(move |open| {
+            let mut open_index = group.lock_mut();
+            if open {
+                *open_index = Some(index);
+            } else if *open_index == Some(index) {
+                *open_index = None;
+            }
+        })
+    }
+
+    fn into_type<NewSummaryFlag, NewContentFlag, NewOpenFlag, NewOnToggleFlag>(
+        self,
+    ) -> Disclosure<NewSummaryFlag, NewContentFlag, NewOpenFlag, NewOnToggleFlag, RE> {
+        Disclosure {
+            raw_el: self.raw_el,
+            flags: PhantomData,
+        }
+    }
+}
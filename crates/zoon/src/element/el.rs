@@ -75,6 +75,10 @@ impl ChoosableTag for El<ChildFlagNotSet, RawHtmlEl<web_sys::HtmlElement>> {
 }
 impl<ChildFlag, RE: RawEl> Styleable<'_> for El<ChildFlag, RE> {}
 impl<ChildFlag, RE: RawEl> KeyboardEventAware for El<ChildFlag, RE> {}
+impl<ChildFlag, RE: RawEl> Focusable for El<ChildFlag, RE> where
+    RE::DomElement: AsRef<web_sys::HtmlElement>
+{
+}
 impl<ChildFlag, RE: RawEl> MouseEventAware for El<ChildFlag, RE> {}
 impl<ChildFlag, RE: RawEl> PointerEventAware for El<ChildFlag, RE> {}
 impl<ChildFlag, RE: RawEl> TouchEventAware for El<ChildFlag, RE> {}
@@ -109,6 +113,18 @@ impl<'a, ChildFlag, RE: RawEl> El<ChildFlag, RE> {
         self.into_type()
     }
 
+    pub fn animated_child_signal(
+        mut self,
+        child: impl Signal<Item = impl IntoOptionElement<'a>> + Unpin + 'static,
+        transition: ChildTransition,
+    ) -> El<ChildFlagSet, RE>
+    where
+        ChildFlag: FlagNotSet,
+    {
+        self.raw_el = self.raw_el.animated_child_signal(child, transition);
+        self.into_type()
+    }
+
     fn into_type<NewChildFlag>(self) -> El<NewChildFlag, RE> {
         El {
             raw_el: self.raw_el,
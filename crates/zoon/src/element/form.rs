@@ -0,0 +1,285 @@
+use crate::*;
+use std::{iter, marker::PhantomData, rc::Rc};
+
+// ------ ------
+//   Element
+// ------ ------
+
+make_flags!(Empty, OnSubmit);
+
+/// A `<form>` laid out as a column.
+/// Field values are collected from the descendant form controls by their `name` attribute,
+/// e.g. from a `TextInput` updated with `raw_el.attr("name", "email")`.
+///
+/// Values and validity are refreshed on the `input` and `change` events bubbling from the fields
+/// and once the form is inserted. Values set programmatically (e.g. by `TextInput::text_signal`)
+/// don't fire these events.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let form = Form::new();
+/// let valid = form.valid_signal();
+/// let submit = form.submit_handle();
+///
+/// let form = form
+///     .on_submit(|values| println!("email: {:?}", values.get("email")))
+///     .item(
+///         TextInput::new()
+///             .label_hidden("Email")
+///             .input_type(InputType::text())
+///             .update_raw_el(|raw_el| raw_el.attr("name", "email").attr("required", "")),
+///     )
+///     .item(
+///         Button::new()
+///             .label_signal(valid.map_bool(|| "Send", || "Fill in your email"))
+///             .on_press(move || submit.submit()),
+///     );
+/// ```
+pub struct Form<EmptyFlag, OnSubmitFlag, RE: RawEl> {
+    values: Mutable<FormValues>,
+    valid: Mutable<bool>,
+    raw_el: RE,
+    flags: PhantomData<(EmptyFlag, OnSubmitFlag)>,
+}
+
+impl Form<EmptyFlagSet, OnSubmitFlagNotSet, RawHtmlEl<web_sys::HtmlFormElement>> {
+    pub fn new() -> Self {
+        let values = Mutable::new(FormValues::default());
+        let valid = Mutable::new(true);
+        let raw_el = RawHtmlEl::<web_sys::HtmlFormElement>::new("form");
+        let form = raw_el.dom_element();
+
+        let refresh = Rc::new({
+            let values = values.clone();
+            let valid = valid.clone();
+            move || {
+                values.set_neq(FormValues::from_form(&form));
+                valid.set_neq(form.check_validity());
+            }
+        });
+        let refresh_on_input = Rc::clone(&refresh);
+        let refresh_on_change = Rc::clone(&refresh);
+        Self {
+            values,
+            valid,
+            raw_el: raw_el
+                .class("form")
+                .style("display", "inline-flex")
+                .style("flex-direction", "column")
+                .event_handler(move |_: events::Input| refresh_on_input())
+                .event_handler(move |_: events::Change| refresh_on_change())
+                .after_insert(move |_| refresh()),
+            flags: PhantomData,
+        }
+    }
+}
+
+impl<OnSubmitFlag, RE: RawEl + Into<RawElement>> Element
+    for Form<EmptyFlagNotSet, OnSubmitFlag, RE>
+{
+    fn into_raw_element(self) -> RawElement {
+        self.raw_el.into()
+    }
+}
+
+impl<EmptyFlag, OnSubmitFlag, RE: RawEl> IntoIterator for Form<EmptyFlag, OnSubmitFlag, RE> {
+    type Item = Self;
+    type IntoIter = iter::Once<Self>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        iter::once(self)
+    }
+}
+
+impl<EmptyFlag, OnSubmitFlag, RE: RawEl> UpdateRawEl for Form<EmptyFlag, OnSubmitFlag, RE> {
+    type RawEl = RE;
+
+    fn update_raw_el(mut self, updater: impl FnOnce(Self::RawEl) -> Self::RawEl) -> Self {
+        self.raw_el = updater(self.raw_el);
+        self
+    }
+}
+
+// ------ ------
+//   Abilities
+// ------ ------
+
+impl<EmptyFlag, OnSubmitFlag, RE: RawEl> Styleable<'_> for Form<EmptyFlag, OnSubmitFlag, RE> {}
+impl<EmptyFlag, OnSubmitFlag, RE: RawEl> KeyboardEventAware for Form<EmptyFlag, OnSubmitFlag, RE> {}
+impl<EmptyFlag, OnSubmitFlag, RE: RawEl> MouseEventAware for Form<EmptyFlag, OnSubmitFlag, RE> {}
+impl<EmptyFlag, OnSubmitFlag, RE: RawEl> PointerEventAware for Form<EmptyFlag, OnSubmitFlag, RE> {}
+impl<EmptyFlag, OnSubmitFlag, RE: RawEl> TouchEventAware for Form<EmptyFlag, OnSubmitFlag, RE> {}
+impl<EmptyFlag, OnSubmitFlag, RE: RawEl> MutableViewport for Form<EmptyFlag, OnSubmitFlag, RE> {}
+impl<EmptyFlag, OnSubmitFlag, RE: RawEl> ResizableViewport for Form<EmptyFlag, OnSubmitFlag, RE> {}
+impl<EmptyFlag, OnSubmitFlag, RE: RawEl> Hookable for Form<EmptyFlag, OnSubmitFlag, RE> {}
+impl<EmptyFlag, OnSubmitFlag, RE: RawEl> AddNearbyElement<'_>
+    for Form<EmptyFlag, OnSubmitFlag, RE>
+{
+}
+impl<EmptyFlag, OnSubmitFlag, RE: RawEl> HasIds for Form<EmptyFlag, OnSubmitFlag, RE> {}
+
+// ------ ------
+//  Attributes
+// ------ ------
+
+impl<'a, EmptyFlag, OnSubmitFlag, RE: RawEl> Form<EmptyFlag, OnSubmitFlag, RE> {
+    pub fn item(
+        mut self,
+        item: impl IntoOptionElement<'a> + 'a,
+    ) -> Form<EmptyFlagNotSet, OnSubmitFlag, RE> {
+        self.raw_el = self.raw_el.child(item);
+        self.into_type()
+    }
+
+    pub fn item_signal(
+        mut self,
+        item: impl Signal<Item = impl IntoOptionElement<'a>> + Unpin + 'static,
+    ) -> Form<EmptyFlagNotSet, OnSubmitFlag, RE> {
+        self.raw_el = self.raw_el.child_signal(item);
+        self.into_type()
+    }
+
+    pub fn items(
+        mut self,
+        items: impl IntoIterator<Item = impl IntoElement<'a> + 'a>,
+    ) -> Form<EmptyFlagNotSet, OnSubmitFlag, RE> {
+        self.raw_el = self.raw_el.children(items);
+        self.into_type()
+    }
+
+    pub fn items_signal_vec(
+        mut self,
+        items: impl SignalVec<Item = impl IntoElement<'a>> + Unpin + 'static,
+    ) -> Form<EmptyFlagNotSet, OnSubmitFlag, RE> {
+        self.raw_el = self.raw_el.children_signal_vec(items);
+        self.into_type()
+    }
+
+    /// The page isn't reloaded, the handler is called with the collected values instead.
+    /// Browsers don't submit forms with invalid fields (see `valid_signal`)
+    /// unless the `novalidate` attribute is set.
+    pub fn on_submit(
+        mut self,
+        on_submit: impl FnOnce(FormValues) + Clone + 'static,
+    ) -> Form<EmptyFlag, OnSubmitFlagSet, RE>
+    where
+        OnSubmitFlag: FlagNotSet,
+        RE::DomElement: AsRef<web_sys::HtmlFormElement>,
+    {
+        let form = self.raw_el.dom_element();
+        let values = self.values.clone();
+        self.raw_el = self.raw_el.event_handler_with_options(
+            EventOptions::new().prevent_default(),
+            move |_: events_extra::Submit| {
+                let submitted_values = FormValues::from_form(form.as_ref());
+                values.set_neq(submitted_values.clone());
+                (on_submit.clone())(submitted_values)
+            },
+        );
+        self.into_type()
+    }
+
+    /// Values of all named fields. See `Form` for when they are refreshed.
+    pub fn values_signal(&self) -> impl Signal<Item = FormValues> {
+        self.values.signal_cloned()
+    }
+
+    /// The result of the native constraint validation (`form.checkValidity()`),
+    /// e.g. `required`, `pattern` or custom validity set by `TextInput::validation`.
+    pub fn valid_signal(&self) -> impl Signal<Item = bool> {
+        self.valid.signal()
+    }
+
+    /// Submits the form from outside of it or from elements that aren't native submit buttons,
+    /// e.g. `Button`.
+    pub fn submit_handle(&self) -> FormSubmitHandle
+    where
+        RE::DomElement: AsRef<web_sys::HtmlFormElement>,
+    {
+        FormSubmitHandle {
+            form: self.raw_el.dom_element().as_ref().clone(),
+        }
+    }
+
+    fn into_type<NewEmptyFlag, NewOnSubmitFlag>(self) -> Form<NewEmptyFlag, NewOnSubmitFlag, RE> {
+        Form {
+            values: self.values,
+            valid: self.valid,
+            raw_el: self.raw_el,
+            flags: PhantomData,
+        }
+    }
+}
+
+// ------ FormValues ------
+
+/// Name-value pairs in the document order. File inputs are skipped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormValues(Vec<(String, String)>);
+
+impl FormValues {
+    fn from_form(form: &web_sys::HtmlFormElement) -> Self {
+        let form_data = web_sys::FormData::new_with_form(form).unwrap_throw();
+        let entries = js_sys::try_iter(&form_data)
+            .unwrap_throw()
+            .expect_throw("FormData is not iterable");
+        let values = entries
+            .filter_map(|entry| {
+                let entry: js_sys::Array = entry.ok()?.unchecked_into();
+                Some((entry.get(0).as_string()?, entry.get(1).as_string()?))
+            })
+            .collect();
+        Self(values)
+    }
+
+    /// The first value of the field with the given name.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.get_all(name).next()
+    }
+
+    /// All values of the fields with the given name, e.g. checked checkboxes.
+    pub fn get_all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> {
+        self.iter()
+            .filter(move |(field_name, _)| *field_name == name)
+            .map(|(_, value)| value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
+// ------ FormSubmitHandle ------
+
+#[derive(Clone)]
+pub struct FormSubmitHandle {
+    form: web_sys::HtmlFormElement,
+}
+
+impl FormSubmitHandle {
+    /// Validates the fields and fires the `submit` event like a native submit button
+    /// (`form.requestSubmit()`).
+    pub fn submit(&self) {
+        let request_submit = Reflect::get(&self.form, &"requestSubmit".into()).unwrap_throw();
+        if request_submit.is_function() {
+            request_submit
+                .unchecked_into::<js_sys::Function>()
+                .call0(&self.form)
+                .unwrap_throw();
+        } else if self.form.report_validity() {
+            // `form.submit()` would skip the `submit` event
+            let event = web_sys::CustomEvent::new_with_event_init_dict(
+                "submit",
+                web_sys::CustomEventInit::new()
+                    .bubbles(true)
+                    .cancelable(true),
+            )
+            .unwrap_throw();
+            self.form.dispatch_event(&event).unwrap_throw();
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use crate::*;
+use std::{cell::Cell, iter, marker::PhantomData, rc::Rc};
+
+// ------ ------
+//   Element
+// ------ ------
+
+make_flags!(Target, Child);
+
+/// Mounts its child under another DOM node (`<body>` by default) so overlays
+/// like modals or tooltips can escape `overflow: hidden` or `transform` ancestors.
+///
+/// The `Portal` itself renders an invisible placeholder. The child is mounted
+/// when the placeholder is inserted and removed together with it,
+/// so the child follows the lifecycle of the element that contains the `Portal`.
+/// Signals and event handlers of the child work as usual, however
+/// native DOM events bubble through the target's ancestors, not the `Portal`'s.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let tooltip = Portal::new()
+///     .target("#overlays")
+///     .child(El::new().s(Align::center()).child("Tooltip"));
+/// ```
+pub struct Portal<TargetFlag, ChildFlag, RE: RawEl> {
+    raw_el: RE,
+    target: PortalTarget,
+    flags: PhantomData<(TargetFlag, ChildFlag)>,
+}
+
+impl Portal<TargetFlagNotSet, ChildFlagNotSet, RawHtmlEl<web_sys::HtmlElement>> {
+    pub fn new() -> Self {
+        Self {
+            raw_el: RawHtmlEl::<web_sys::HtmlElement>::new("div")
+                .class("portal")
+                .style("display", "none"),
+            target: PortalTarget::Body,
+            flags: PhantomData,
+        }
+    }
+}
+
+impl<TargetFlag, RE: RawEl + Into<RawElement>> Element for Portal<TargetFlag, ChildFlagSet, RE> {
+    fn into_raw_element(self) -> RawElement {
+        self.raw_el.into()
+    }
+}
+
+impl<TargetFlag, ChildFlag, RE: RawEl> IntoIterator for Portal<TargetFlag, ChildFlag, RE> {
+    type Item = Self;
+    type IntoIter = iter::Once<Self>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        iter::once(self)
+    }
+}
+
+impl<TargetFlag, ChildFlag, RE: RawEl> UpdateRawEl for Portal<TargetFlag, ChildFlag, RE> {
+    type RawEl = RE;
+
+    fn update_raw_el(mut self, updater: impl FnOnce(Self::RawEl) -> Self::RawEl) -> Self {
+        self.raw_el = updater(self.raw_el);
+        self
+    }
+}
+
+// ------ ------
+//   Abilities
+// ------ ------
+
+impl<TargetFlag, ChildFlag, RE: RawEl> Hookable for Portal<TargetFlag, ChildFlag, RE> {}
+
+// ------ ------
+//  Attributes
+// ------ ------
+
+impl<'a, TargetFlag, ChildFlag, RE: RawEl> Portal<TargetFlag, ChildFlag, RE> {
+    /// The first element matching the CSS `selector` when the `Portal` is inserted.
+    /// The child isn't mounted when there is no such element.
+    pub fn target(mut self, selector: impl IntoCowStr<'a>) -> Portal<TargetFlagSet, ChildFlag, RE>
+    where
+        TargetFlag: FlagNotSet,
+        ChildFlag: FlagNotSet,
+    {
+        self.target = PortalTarget::Selector(selector.into_cow_str().into_owned());
+        self.into_type()
+    }
+
+    pub fn target_element(
+        mut self,
+        element: impl AsRef<web_sys::Element>,
+    ) -> Portal<TargetFlagSet, ChildFlag, RE>
+    where
+        TargetFlag: FlagNotSet,
+        ChildFlag: FlagNotSet,
+    {
+        self.target = PortalTarget::Element(element.as_ref().clone());
+        self.into_type()
+    }
+
+    pub fn child(mut self, child: impl IntoElement<'a> + 'a) -> Portal<TargetFlag, ChildFlagSet, RE>
+    where
+        ChildFlag: FlagNotSet,
+    {
+        self.raw_el = mount(
+            self.raw_el,
+            self.target.clone(),
+            child.into_element().into_raw_element(),
+        );
+        self.into_type()
+    }
+
+    pub fn child_signal(
+        mut self,
+        child: impl Signal<Item = impl IntoOptionElement<'a>> + Unpin + 'static,
+    ) -> Portal<TargetFlag, ChildFlagSet, RE>
+    where
+        ChildFlag: FlagNotSet,
+    {
+        let container = RawHtmlEl::<web_sys::HtmlElement>::new("div")
+            .style("display", "contents")
+            .child_signal(child);
+        self.raw_el = mount(self.raw_el, self.target.clone(), container.into());
+        self.into_type()
+    }
+
+    fn into_type<NewTargetFlag, NewChildFlag>(self) -> Portal<NewTargetFlag, NewChildFlag, RE> {
+        Portal {
+            raw_el: self.raw_el,
+            target: self.target,
+            flags: PhantomData,
+        }
+    }
+}
+
+// ------ PortalTarget ------
+
+#[derive(Clone)]
+enum PortalTarget {
+    Body,
+    Selector(String),
+    Element(web_sys::Element),
+}
+
+impl PortalTarget {
+    fn resolve(self) -> Option<web_sys::Node> {
+        match self {
+            Self::Body => Some(dom::body().unchecked_into()),
+            Self::Selector(selector) => {
+                let element = document().query_selector(&selector).ok().flatten();
+                if element.is_none() {
+                    crate::zwarn!("portal target `{}` not found", selector);
+                }
+                element.map(Into::into)
+            }
+            Self::Element(element) => Some(element.into()),
+        }
+    }
+}
+
+// ------ helpers ------
+
+fn mount<RE: RawEl>(placeholder: RE, target: PortalTarget, child: RawElement) -> RE {
+    let child = child.into_dom();
+    let dom_handle = Rc::new(Cell::new(None));
+    let inserted_dom_handle = dom_handle.clone();
+    placeholder
+        .after_insert(move |_| {
+            if let Some(target) = target.resolve() {
+                inserted_dom_handle.set(Some(dominator::append_dom(&target, child)));
+            }
+        })
+        .after_remove(move |_| {
+            if let Some(dom_handle) = dom_handle.take() {
+                dominator::DomHandle::discard(dom_handle);
+            }
+        })
+}
@@ -1,17 +1,25 @@
 use crate::{
     css_property::{CssPropertyName, CssPropertyValue},
+    element::child_transition::{AnimatedChildren, AnimatedItems},
+    event_options::ControlledEvent,
     *,
 };
 use lang::Lang;
 use once_cell::race::OnceBox;
 use std::mem::ManuallyDrop;
-use std::{cell::Cell, mem, rc::Rc};
+use std::{
+    borrow::Cow,
+    cell::Cell,
+    mem,
+    rc::Rc,
+    sync::atomic::{AtomicU32, Ordering},
+};
 
 mod raw_html_el;
 mod raw_svg_el;
 
 pub use raw_html_el::RawHtmlEl;
-pub use raw_svg_el::RawSvgEl;
+pub use raw_svg_el::{MeetOrSlice, RawSvgEl};
 
 // ------ class_ids ------
 
@@ -23,20 +31,74 @@ fn class_id_generator() -> &'static ClassIdGenerator {
 #[derive(Default)]
 struct ClassIdGenerator {
     index_generator: IndexGenerator,
+    // used instead of `index_generator` with the feature `deterministic_class_ids`
+    monotonic_index: AtomicU32,
 }
 
 impl ClassIdGenerator {
     fn next_class_id(&self) -> ClassId {
-        ClassId::new(["_", &self.index_generator.next_index().to_string()].concat())
+        let index = if cfg!(feature = "deterministic_class_ids") {
+            self.monotonic_index.fetch_add(1, Ordering::SeqCst)
+        } else {
+            self.index_generator.next_index()
+        };
+        ClassId::new(["_", &index.to_string()].concat())
     }
 
     fn remove_class_id(&self, class_id: ClassId) {
         let class_id = class_id.take().unwrap_throw();
+        // removed ids would be reused in the order the elements have been removed
+        if cfg!(feature = "deterministic_class_ids") {
+            return;
+        }
         self.index_generator
             .remove_index(class_id[1..].parse().unwrap_throw());
     }
 }
 
+/// Restarts the element class ids (`_0`, `_1`, ...) so the same elements get
+/// the same classes in every DOM snapshot test.
+///
+/// Available with the feature `deterministic_class_ids`. The class ids are never reused
+/// with the feature, so the ids depend only on the order the elements are created in.
+/// Call the function only when no element created before is mounted.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let app = start_app("app", || El::new().child("Hello!"));
+/// let snapshot = document().get_element_by_id("app").unwrap().inner_html();
+/// app.unmount();
+/// reset_class_ids();
+/// let app = start_app("app", || El::new().child("Hello!"));
+/// let new_snapshot = document().get_element_by_id("app").unwrap().inner_html();
+/// assert_eq!(snapshot, new_snapshot);
+/// ```
+#[cfg(feature = "deterministic_class_ids")]
+pub fn reset_class_ids() {
+    class_id_generator()
+        .monotonic_index
+        .store(0, Ordering::SeqCst);
+}
+
+// ------ data attributes ------
+
+/// `"userId"` or `"user-id"` -> `"data-user-id"`
+fn data_attribute_name(key: &str) -> String {
+    let key = key.strip_prefix("data-").unwrap_or(key);
+    let mut name = String::with_capacity("data-".len() + key.len() + 2);
+    name.push_str("data-");
+    for character in key.chars() {
+        if character.is_ascii_uppercase() {
+            name.push('-');
+            name.push(character.to_ascii_lowercase());
+        } else {
+            name.push(character);
+        }
+    }
+    name
+}
+
 // ------ UpdateRawEl ------
 
 pub trait UpdateRawEl {
@@ -98,6 +160,71 @@ pub trait RawEl: Sized {
         })
     }
 
+    /// Removes the attribute, e.g. one set by `attr` or one already present
+    /// on the element passed to `from_dom_element`.
+    ///
+    /// Note: An active `attr_signal` with the same name sets the attribute again
+    /// on its next value.
+    fn remove_attr(self, name: &str) -> Self {
+        let dom_element = self.dom_element();
+        AsRef::<web_sys::Element>::as_ref(&dom_element)
+            .remove_attribute(name)
+            .unwrap_throw();
+        self
+    }
+
+    /// Sets `tabindex`:
+    /// - `0` - The element is reachable by Tab in the document order.
+    /// - `-1` - The element is focusable only programmatically (e.g. by `focus`) or by a click,
+    /// it's skipped by Tab. Useful for inactive items in composite widgets like menus.
+    /// - Positive values move the element before all `0` elements in the Tab order.
+    /// Avoid them, they break the natural order expected by keyboard users.
+    fn tab_index(self, index: i32) -> Self {
+        self.attr("tabindex", &index.to_string())
+    }
+
+    /// `None` removes `tabindex`.
+    fn tab_index_signal(
+        self,
+        index: impl Signal<Item = impl Into<Option<i32>>> + Unpin + 'static,
+    ) -> Self {
+        self.attr_signal("tabindex", index.map(|index| index.into()))
+    }
+
+    /// A shorthand for `tab_index(0)` (`true`) or `tab_index(-1)` (`false`).
+    fn focusable(self, focusable: bool) -> Self {
+        self.tab_index(if focusable { 0 } else { -1 })
+    }
+
+    /// Sets a `data-*` attribute. The key can be written like in `dataset` (`"userId"`)
+    /// or like in HTML (`"user-id"`), both set the attribute `data-user-id`.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let row = RawHtmlEl::new("tr").data("userId", "42");
+    /// assert_eq!(row.data_value("user-id").as_deref(), Some("42"));
+    /// ```
+    fn data(self, key: &str, value: &str) -> Self {
+        self.attr(&data_attribute_name(key), value)
+    }
+
+    /// `None` removes the attribute.
+    fn data_signal<'a>(
+        self,
+        key: &str,
+        value: impl Signal<Item = impl IntoOptionCowStr<'a>> + Unpin + 'static,
+    ) -> Self {
+        self.attr_signal(data_attribute_name(key), value)
+    }
+
+    /// Reads a `data-*` attribute, e.g. from server-rendered markup.
+    /// See `data` for the accepted key formats.
+    fn data_value(&self, key: &str) -> Option<String> {
+        AsRef::<web_sys::Element>::as_ref(&self.dom_element())
+            .get_attribute(&data_attribute_name(key))
+    }
+
     fn prop(self, name: &str, value: &str) -> Self
     where
         Self::DomElement: AsRef<JsValue>,
@@ -121,6 +248,11 @@ pub trait RawEl: Sized {
         })
     }
 
+    /// Events from `zoon::events` (re-exported from Dominator) and `zoon::events_extra` expose
+    /// `target()`, `dyn_target()` and `prevent_default()` plus event-specific getters
+    /// (e.g. `x()` or `button()` for mouse events).
+    /// Use `event_handler_with_options` with `EventOptions::new().stop_propagation()`
+    /// to control the propagation.
     fn event_handler<E: StaticEvent>(self, handler: impl FnOnce(E) + Clone + 'static) -> Self {
         self.event_handler_with_options(EventOptions::default(), handler)
     }
@@ -130,12 +262,45 @@ pub trait RawEl: Sized {
         options: EventOptions,
         handler: impl FnOnce(E) + Clone + 'static,
     ) -> Self {
+        if options.controls_event() {
+            let handler = move |event: ControlledEvent<E>| {
+                options.control_event(&event.raw_event);
+                handler.clone()(event.event)
+            };
+            return self.update_dom_builder(|dom_builder| {
+                dom_builder.event_with_options(&options.into(), handler)
+            });
+        }
         let handler = move |event: E| handler.clone()(event);
         self.update_dom_builder(|dom_builder| {
             dom_builder.event_with_options(&options.into(), handler)
         })
     }
 
+    /// Listens for `CustomEvent`s named `name` (e.g. dispatched by a JS library)
+    /// and deserializes their `detail`. See also `dispatch_custom_event`.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let el = RawHtmlEl::new("div").on_custom_event("my-lib:ready", |version: String| {
+    ///     println!("my-lib {} is ready", version);
+    /// });
+    /// ```
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    fn on_custom_event<T: serde::de::DeserializeOwned>(
+        self,
+        name: &str,
+        handler: impl FnOnce(T) + Clone + 'static,
+    ) -> Self {
+        let listener = crate::custom_event::CustomEventListener::new(
+            self.dom_element().into(),
+            name.to_owned(),
+            handler,
+        );
+        self.after_remove(move |_| drop(listener))
+    }
+
     fn global_event_handler<E: StaticEvent>(
         self,
         handler: impl FnOnce(E) + Clone + 'static,
@@ -148,6 +313,15 @@ pub trait RawEl: Sized {
         options: EventOptions,
         handler: impl FnOnce(E) + Clone + 'static,
     ) -> Self {
+        if options.controls_event() {
+            let handler = move |event: ControlledEvent<E>| {
+                options.control_event(&event.raw_event);
+                handler.clone()(event.event)
+            };
+            return self.update_dom_builder(|dom_builder| {
+                dom_builder.global_event_with_options(&options.into(), handler)
+            });
+        }
         let handler = move |event: E| handler.clone()(event);
         self.update_dom_builder(|dom_builder| {
             dom_builder.global_event_with_options(&options.into(), handler)
@@ -176,6 +350,24 @@ pub trait RawEl: Sized {
         })
     }
 
+    /// Like `child_signal`, but the children play the `transition` styles
+    /// when they enter or leave. See `ChildTransition`.
+    fn animated_child_signal<'a>(
+        self,
+        child: impl Signal<Item = impl IntoOptionElement<'a>> + Unpin + 'static,
+        transition: ChildTransition,
+    ) -> Self {
+        let animated_children = AnimatedChildren::new(transition);
+        let child_swapper = Task::start_droppable(child.for_each_sync({
+            let animated_children = Rc::clone(&animated_children);
+            move |child| animated_children.swap(child)
+        }));
+        self.update_dom_builder(|dom_builder| {
+            dom_builder.children_signal_vec(animated_children.doms_signal_vec())
+        })
+        .after_remove(move |_| drop(child_swapper))
+    }
+
     fn children<'a>(self, children: impl IntoIterator<Item = impl IntoElement<'a> + 'a>) -> Self {
         self.update_dom_builder(|dom_builder| {
             dom_builder.children(
@@ -197,6 +389,29 @@ pub trait RawEl: Sized {
         })
     }
 
+    /// Like `children_signal_vec`, but the children play the `transition` styles
+    /// when they enter or leave and they transition to their new positions when moved.
+    ///
+    /// Moves are animated by the `transform` style, it's overridden while the child is moving.
+    fn animated_children_signal_vec<'a>(
+        self,
+        children: impl SignalVec<Item = impl IntoElement<'a>> + Unpin + 'static,
+        transition: ChildTransition,
+    ) -> Self {
+        let animated_items = AnimatedItems::new(transition);
+        let diff_applier = Task::start_droppable(children.for_each({
+            let animated_items = Rc::clone(&animated_items);
+            move |diff| {
+                animated_items.apply_diff(diff);
+                async {}
+            }
+        }));
+        self.update_dom_builder(|dom_builder| {
+            dom_builder.children_signal_vec(animated_items.doms_signal_vec())
+        })
+        .after_remove(move |_| drop(diff_applier))
+    }
+
     fn style(self, name: &str, value: &str) -> Self {
         self.update_dom_builder(|dom_builder| {
             dom_builder.style(CssPropertyName::new(name), CssPropertyValue::new(value))
@@ -237,6 +452,8 @@ pub trait RawEl: Sized {
         if group.selector.is_empty() {
             let StyleGroup {
                 selector: _,
+                layer: _,
+                shared: _,
                 static_css_props,
                 dynamic_css_props,
                 static_css_classes: _,
@@ -244,6 +461,8 @@ pub trait RawEl: Sized {
                 resize_handlers,
             } = group;
 
+            let dynamic_css_props = with_static_fallbacks(dynamic_css_props, &static_css_props);
+
             for (name, CssPropValue { value, important }) in static_css_props {
                 if important {
                     self = self.style_important(name, &value);
@@ -264,6 +483,13 @@ pub trait RawEl: Sized {
             return self;
         }
 
+        // identical static styles share one rule
+        if group.shared && group.dynamic_css_props.is_empty() {
+            let shared_group_handle = global_styles().style_group_shared(group);
+            self = self.class(shared_group_handle.class());
+            return self.after_remove(|_| drop(shared_group_handle));
+        }
+
         group.selector = self.class_id().map(|class_id| {
             [".", class_id.unwrap_throw(), &group.selector]
                 .concat()
@@ -285,8 +511,8 @@ pub trait RawEl: Sized {
         self.update_dom_builder(|dom_builder| dom_builder.after_removed(handler))
     }
 
-    fn class(self, class: &str) -> Self {
-        self.update_dom_builder(|dom_builder| dom_builder.class(class))
+    fn class<'a>(self, class: impl IntoCowStr<'a>) -> Self {
+        self.update_dom_builder(|dom_builder| dom_builder.class(&*class.into_cow_str()))
     }
 
     fn class_signal<'a>(
@@ -299,10 +525,62 @@ pub trait RawEl: Sized {
         })
     }
 
-    fn on_resize(mut self, handler: impl FnOnce(U32Width, U32Height) + Clone + 'static) -> Self {
-        // @TODO should we create one global ResizeObserver to improve performance?
-        // Inspiration: https://gist.github.com/Pauan/d9dcf0b47fc03c7a49b95f29ff8ef3c3
+    /// Removes the class, e.g. one set by `class` or one already present
+    /// on the element passed to `from_dom_element`.
+    ///
+    /// Note: An active `class_signal` with the same class doesn't fight the removal -
+    /// it adds the class again only when it emits `true`.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let element = document().get_element_by_id("legacy_widget").unwrap_throw();
+    /// let widget = RawHtmlEl::<web_sys::HtmlElement>::from_dom_element(element.unchecked_into())
+    ///     .remove_class("hidden")
+    ///     .remove_attr("aria-hidden");
+    /// ```
+    fn remove_class<'a>(self, class: impl IntoCowStr<'a>) -> Self {
+        let dom_element = self.dom_element();
+        AsRef::<web_sys::Element>::as_ref(&dom_element)
+            .class_list()
+            .remove_1(&class.into_cow_str())
+            .unwrap_throw();
+        self
+    }
 
+    fn classes<'a>(mut self, classes: impl IntoIterator<Item = impl IntoCowStr<'a>>) -> Self {
+        for class in classes {
+            self = self.class(class);
+        }
+        self
+    }
+
+    /// Keeps the element's classes in sync with the last emitted list -
+    /// classes missing in the new list are removed.
+    ///
+    /// Note: Don't combine it with other class methods setting the same class names.
+    fn class_signal_list(
+        self,
+        classes: impl Signal<Item = Vec<Cow<'static, str>>> + Unpin + 'static,
+    ) -> Self {
+        let dom_element = self.dom_element();
+        let mut current_classes = Vec::<Cow<'static, str>>::new();
+        let class_list_updater = Task::start_droppable(classes.for_each_sync(move |classes| {
+            let class_list = AsRef::<web_sys::Element>::as_ref(&dom_element).class_list();
+            for class in &current_classes {
+                if not(classes.contains(class)) {
+                    class_list.remove_1(class).unwrap_throw();
+                }
+            }
+            for class in &classes {
+                class_list.add_1(class).unwrap_throw();
+            }
+            current_classes = classes;
+        }));
+        self.after_remove(move |_| drop(class_list_updater))
+    }
+
+    fn on_resize(mut self, handler: impl FnOnce(U32Width, U32Height) + Clone + 'static) -> Self {
         let resize_observer = Rc::new(Cell::new(None));
         let resize_observer_for_insert = Rc::clone(&resize_observer);
 
@@ -316,9 +594,148 @@ pub trait RawEl: Sized {
         })
     }
 
+    /// Reports changes of the element's DOM made by the browser or by other scripts,
+    /// e.g. by an embedded non-Zoon widget. Changes made by Zoon are reported as well.
+    ///
+    /// The observer starts once the element is inserted and is disconnected on removal.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let container = RawHtmlEl::new("div").on_dom_mutation(
+    ///     MutationConfig::new().child_list().subtree(),
+    ///     |mutations| println!("{} mutations", mutations.len()),
+    /// );
+    /// ```
+    fn on_dom_mutation(
+        mut self,
+        config: MutationConfig,
+        handler: impl FnOnce(Vec<DomMutation>) + Clone + 'static,
+    ) -> Self {
+        let mutation_observer = Rc::new(Cell::new(None));
+        let mutation_observer_for_insert = Rc::clone(&mutation_observer);
+
+        self = self.after_insert(move |ws_element| {
+            let observer = MutationObserver::new(ws_element.as_ref(), &config, handler);
+            mutation_observer_for_insert.set(Some(observer));
+        });
+
+        self.after_remove(move |_| {
+            drop(mutation_observer);
+        })
+    }
+
+    /// Calls the handler with the element's bounding rectangle (relative to the viewport)
+    /// when the element is resized, the page or any scrollable container is scrolled
+    /// or the window is resized.
+    fn on_measure(self, handler: impl FnOnce(web_sys::DomRect) + Clone + 'static) -> Self {
+        let dom_element = self.dom_element();
+        let measure = move || {
+            let element: &web_sys::Element = dom_element.as_ref();
+            handler.clone()(element.get_bounding_client_rect())
+        };
+        // `scroll` doesn't bubble, the capturing listener on `document`
+        // is called for the page and for all scrollable containers.
+        let scroll_listener = Rc::new(Closure::wrap(Box::new(measure.clone()) as Box<dyn Fn()>));
+        let scroll_listener_for_remove = Rc::clone(&scroll_listener);
+        let measure_on_window_resize = measure.clone();
+        self.on_resize(move |_, _| measure())
+            .global_event_handler(move |_: events::Resize| measure_on_window_resize())
+            .after_insert(move |_| {
+                document()
+                    .add_event_listener_with_callback_and_bool(
+                        "scroll",
+                        scroll_listener.as_ref().as_ref().unchecked_ref(),
+                        true,
+                    )
+                    .unwrap_throw();
+            })
+            .after_remove(move |_| {
+                document()
+                    .remove_event_listener_with_callback_and_bool(
+                        "scroll",
+                        scroll_listener_for_remove.as_ref().as_ref().unchecked_ref(),
+                        true,
+                    )
+                    .unwrap_throw();
+            })
+    }
+
+    /// The current bounding rectangle relative to the viewport.
+    /// All values are zero before the element is inserted into the document.
+    fn bounding_rect(&self) -> web_sys::DomRect {
+        AsRef::<web_sys::Element>::as_ref(&self.dom_element()).get_bounding_client_rect()
+    }
+
+    /// See `dom::scroll_into_view`. Call it on an inserted element.
+    fn scroll_into_view(&self, options: ScrollIntoViewOptions) {
+        dom::scroll_into_view(self.dom_element().as_ref(), options)
+    }
+
+    /// See `dom::scroll_into_view_if_needed`. Call it on an inserted element.
+    fn scroll_into_view_if_needed(&self, options: ScrollIntoViewOptions) -> bool {
+        dom::scroll_into_view_if_needed(self.dom_element().as_ref(), options)
+    }
+
+    /// Scrolls the element into view (if needed) when the signal emits `true`,
+    /// e.g. to jump to an invalid form field.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let email_error = Mutable::new(None::<String>);
+    /// let email_field = Column::new()
+    ///     .update_raw_el(|raw_el| {
+    ///         raw_el.scroll_into_view_signal(
+    ///             email_error.signal_ref(Option::is_some),
+    ///             ScrollIntoViewOptions::new().smooth().block(ScrollAlignment::Center),
+    ///         )
+    ///     })
+    ///     .item("Email")
+    ///     .item_signal(email_error.signal_cloned());
+    /// ```
+    fn scroll_into_view_signal(
+        self,
+        scroll: impl Signal<Item = bool> + Unpin + 'static,
+        options: ScrollIntoViewOptions,
+    ) -> Self {
+        let dom_element = self.dom_element();
+        let scroller = Task::start_droppable(scroll.for_each_sync(move |scroll| {
+            if scroll {
+                dom::scroll_into_view_if_needed(dom_element.as_ref(), options);
+            }
+        }));
+        self.after_remove(move |_| drop(scroller))
+    }
+
+    /// The `(width, height)` of the element's content including the overflowing part
+    /// (`scrollWidth` and `scrollHeight`).
+    fn content_size(&self) -> (f64, f64) {
+        let dom_element = self.dom_element();
+        let element: &web_sys::Element = dom_element.as_ref();
+        (
+            f64::from(element.scroll_width()),
+            f64::from(element.scroll_height()),
+        )
+    }
+
+    /// The resolved value of the CSS property, see `dom::computed_style`.
+    /// Call it after the element has been inserted, detached elements have no computed style.
+    fn computed_style(&self, name: &str) -> String {
+        let dom_element = self.dom_element();
+        dom::computed_style(dom_element.as_ref(), name)
+    }
+
     fn class_id(&self) -> ClassId;
 
-    fn inner_markup(self, markup: impl AsRef<str>) -> Self {
+    /// Replaces the element's content with the given HTML markup.
+    ///
+    /// Warning: The markup isn't sanitized, never pass user-supplied strings (XSS).
+    /// Use `inner_text` for plain text.
+    fn inner_markup(self, markup: impl AsRef<str>) -> Self
+    where
+        Self: RawMarkupForbidden_UseInnerTextOrSanitizeWithAmmonia,
+    {
         let dom_element = self.dom_element();
         let parent: &web_sys::Element = dom_element.as_ref();
         parent.set_inner_html(markup.as_ref());
@@ -328,7 +745,10 @@ pub trait RawEl: Sized {
     fn inner_markup_signal<'a>(
         self,
         markup: impl Signal<Item = impl IntoCowStr<'a>> + Unpin + 'static,
-    ) -> Self {
+    ) -> Self
+    where
+        Self: RawMarkupForbidden_UseInnerTextOrSanitizeWithAmmonia,
+    {
         let parent: web_sys::Element = self.dom_element().into();
         let inner_html_updater = markup.for_each_sync(move |markup| {
             parent.set_inner_html(&markup.into_cow_str());
@@ -337,20 +757,158 @@ pub trait RawEl: Sized {
         self.after_remove(move |_| drop(inner_html_updater))
     }
 
+    /// Appends the nodes parsed from the given HTML markup after the current children,
+    /// e.g. a static chunk of markup next to reactive children. All root nodes are appended.
+    ///
+    /// Warning: The markup isn't sanitized, never pass user-supplied strings (XSS).
+    fn append_markup(self, markup: impl AsRef<str>) -> Self
+    where
+        Self: RawMarkupForbidden_UseInnerTextOrSanitizeWithAmmonia,
+    {
+        self.append_markup_with(markup, |children| children)
+    }
+
+    /// Like `append_markup`, but the appended root HTML elements are passed to the `updater`,
+    /// similarly to `update_html_child`. The returned elements live as long as `self`.
+    /// Root text nodes and SVG elements are appended, but not passed to the `updater`.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let likes = Mutable::new(0);
+    ///
+    /// let card = RawHtmlEl::new("div")
+    ///     .child_signal(likes.signal())
+    ///     .append_markup_with("<p>Static <b>note</b></p><button>Like</button>", |mut children| {
+    ///         if let Some(button) = children.pop() {
+    ///             children.push(button.event_handler(move |_: events::Click| {
+    ///                 likes.update(|likes| likes + 1)
+    ///             }));
+    ///         }
+    ///         children
+    ///     });
+    /// ```
+    fn append_markup_with(
+        self,
+        markup: impl AsRef<str>,
+        updater: impl FnOnce(
+            Vec<RawHtmlEl<web_sys::HtmlElement>>,
+        ) -> Vec<RawHtmlEl<web_sys::HtmlElement>>,
+    ) -> Self
+    where
+        Self: RawMarkupForbidden_UseInnerTextOrSanitizeWithAmmonia,
+    {
+        let fragment = parse_markup(markup.as_ref());
+
+        let mut children = Vec::new();
+        let mut next_child = fragment.first_element_child();
+        while let Some(child) = next_child {
+            next_child = child.next_element_sibling();
+            if let Ok(child) = child.dyn_into::<web_sys::HtmlElement>() {
+                children.push(RawHtmlEl::from_dom_element(child));
+            }
+        }
+
+        let dom_element = self.dom_element();
+        let parent: &web_sys::Node = dom_element.as_ref();
+        // appending a fragment moves all its nodes
+        parent.append_child(&fragment).unwrap_throw();
+
+        let children = updater(children);
+        if children.is_empty() {
+            return self;
+        }
+        self.after_remove(move |_| drop(children))
+    }
+
+    /// Replaces the element's content with the given text (`textContent`).
+    /// The text is never interpreted as markup, so it's safe for user-supplied strings.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let comment = RawHtmlEl::new("p").inner_text("<b>not bold</b>");
+    /// ```
+    fn inner_text(self, text: impl AsRef<str>) -> Self {
+        let dom_element = self.dom_element();
+        let node: &web_sys::Node = dom_element.as_ref();
+        node.set_text_content(Some(text.as_ref()));
+        self
+    }
+
+    fn inner_text_signal<'a>(
+        self,
+        text: impl Signal<Item = impl IntoCowStr<'a>> + Unpin + 'static,
+    ) -> Self {
+        let node: web_sys::Node = AsRef::<web_sys::Node>::as_ref(&self.dom_element()).clone();
+        let text_content_updater = text.for_each_sync(move |text| {
+            node.set_text_content(Some(&text.into_cow_str()));
+        });
+        let text_content_updater = Task::start_droppable(text_content_updater);
+        self.after_remove(move |_| drop(text_content_updater))
+    }
+
     fn from_markup(markup: impl AsRef<str>) -> Option<Self>
     where
         Self::DomElement: JsCast,
+        Self: RawMarkupForbidden_UseInnerTextOrSanitizeWithAmmonia,
     {
-        // https://grrr.tech/posts/create-dom-node-from-html-string/
+        let element = parse_markup(markup.as_ref()).first_element_child()?;
+        Some(Self::from_dom_element(element.dyn_into().ok()?))
+    }
 
-        let template: web_sys::HtmlTemplateElement = document()
-            .create_element("template")
-            .unwrap_throw()
-            .unchecked_into();
+    // ------ dangerous_* aliases ------
+    // Raw markup isn't sanitized, the names make its injection visible in code reviews.
+    // Enable the feature `forbid_raw_markup` to make all raw markup methods fail to compile.
 
-        template.set_inner_html(markup.as_ref().trim());
-        let element = template.content().first_element_child()?;
-        Some(Self::from_dom_element(element.dyn_into().ok()?))
+    /// Alias of `inner_markup`.
+    fn dangerous_inner_markup(self, markup: impl AsRef<str>) -> Self
+    where
+        Self: RawMarkupForbidden_UseInnerTextOrSanitizeWithAmmonia,
+    {
+        self.inner_markup(markup)
+    }
+
+    /// Alias of `inner_markup_signal`.
+    fn dangerous_inner_markup_signal<'a>(
+        self,
+        markup: impl Signal<Item = impl IntoCowStr<'a>> + Unpin + 'static,
+    ) -> Self
+    where
+        Self: RawMarkupForbidden_UseInnerTextOrSanitizeWithAmmonia,
+    {
+        self.inner_markup_signal(markup)
+    }
+
+    /// Alias of `append_markup`.
+    fn dangerous_append_markup(self, markup: impl AsRef<str>) -> Self
+    where
+        Self: RawMarkupForbidden_UseInnerTextOrSanitizeWithAmmonia,
+    {
+        self.append_markup(markup)
+    }
+
+    /// Alias of `append_markup_with`.
+    fn dangerous_append_markup_with(
+        self,
+        markup: impl AsRef<str>,
+        updater: impl FnOnce(
+            Vec<RawHtmlEl<web_sys::HtmlElement>>,
+        ) -> Vec<RawHtmlEl<web_sys::HtmlElement>>,
+    ) -> Self
+    where
+        Self: RawMarkupForbidden_UseInnerTextOrSanitizeWithAmmonia,
+    {
+        self.append_markup_with(markup, updater)
+    }
+
+    /// Alias of `from_markup`.
+    fn dangerous_from_markup(markup: impl AsRef<str>) -> Option<Self>
+    where
+        Self::DomElement: JsCast,
+        Self: RawMarkupForbidden_UseInnerTextOrSanitizeWithAmmonia,
+    {
+        Self::from_markup(markup)
     }
 
     fn find_html_child(
@@ -412,3 +970,45 @@ pub trait RawEl: Sized {
     where
         Self::DomElement: AsRef<web_sys::HtmlElement>;
 }
+
+// ------ RawMarkupForbidden_UseInnerTextOrSanitizeWithAmmonia ------
+
+/// Implemented for all types unless the feature `forbid_raw_markup` is enabled.
+/// Then calls of the raw markup methods (`inner_markup`, `from_markup`, etc.) fail to compile
+/// with an error naming this trait - use `inner_text` for plain text, or disable the feature
+/// and sanitize the markup first, e.g. with the crate `ammonia`.
+#[allow(non_camel_case_types)]
+pub trait RawMarkupForbidden_UseInnerTextOrSanitizeWithAmmonia {}
+
+#[cfg(not(feature = "forbid_raw_markup"))]
+impl<T> RawMarkupForbidden_UseInnerTextOrSanitizeWithAmmonia for T {}
+
+// ------ parse_markup ------
+
+fn parse_markup(markup: &str) -> web_sys::DocumentFragment {
+    // https://grrr.tech/posts/create-dom-node-from-html-string/
+
+    let template: web_sys::HtmlTemplateElement = document()
+        .create_element("template")
+        .unwrap_throw()
+        .unchecked_into();
+
+    template.set_inner_html(markup.trim());
+    template.content()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_data_attribute_name() {
+        assert_eq!(data_attribute_name("id"), "data-id");
+        assert_eq!(data_attribute_name("userId"), "data-user-id");
+        assert_eq!(data_attribute_name("user-id"), "data-user-id");
+        assert_eq!(data_attribute_name("data-user-id"), "data-user-id");
+        assert_eq!(data_attribute_name("dataUserId"), "data-data-user-id");
+        assert_eq!(data_attribute_name("XId"), "data--x-id");
+        assert_eq!(data_attribute_name(""), "data-");
+    }
+}
@@ -1,6 +1,7 @@
 use super::class_id_generator;
 use crate::*;
 use std::iter;
+use strum::IntoStaticStr;
 
 // ------ ------
 //   Element
@@ -11,10 +12,56 @@ pub struct RawSvgEl<DomElement: Into<web_sys::SvgElement>> {
     dom_builder: DomBuilder<DomElement>,
 }
 
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+const XLINK_NAMESPACE: &str = "http://www.w3.org/1999/xlink";
+
 impl RawSvgEl<web_sys::SvgElement> {
     pub fn new(tag: &str) -> Self {
         <Self as RawEl>::new(tag)
     }
+
+    /// Creates a `<use>` element referencing a symbol, e.g. from a sprite registered
+    /// by `register_sprite`. Both `href` and the legacy `xlink:href` are set.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// RawSvgEl::register_sprite(
+    ///     "icons",
+    ///     r#"<symbol id="icon-search" viewBox="0 0 24 24"><circle cx="10" cy="10" r="7"/></symbol>"#,
+    /// );
+    /// let icon = RawSvgEl::new("svg")
+    ///     .view_box(0., 0., 24., 24.)
+    ///     .child(RawSvgEl::use_ref("#icon-search"));
+    /// ```
+    pub fn use_ref(href: &str) -> Self {
+        let use_el = Self::new("use").attr("href", href);
+        use_el
+            .dom_element()
+            .set_attribute_ns(Some(XLINK_NAMESPACE), "xlink:href", href)
+            .unwrap_throw();
+        use_el
+    }
+
+    /// Appends a hidden `<svg id="{id}">` with the given `<symbol>`s to `<body>`.
+    /// Does nothing if an element with the `id` already exists,
+    /// so the sprite can be registered from multiple places.
+    pub fn register_sprite(id: &str, symbols_markup: &str) {
+        let document = document();
+        if document.get_element_by_id(id).is_some() {
+            return;
+        }
+        let sprite = document
+            .create_element_ns(Some(SVG_NAMESPACE), "svg")
+            .unwrap_throw();
+        sprite.set_id(id);
+        sprite.set_attribute("aria-hidden", "true").unwrap_throw();
+        sprite
+            .set_attribute("style", "display: none")
+            .unwrap_throw();
+        sprite.set_inner_html(symbols_markup);
+        dom::body().append_child(&sprite).unwrap_throw();
+    }
 }
 
 impl<DomElement: Into<web_sys::SvgElement> + Clone + JsCast> RawSvgEl<DomElement> {
@@ -135,3 +182,124 @@ where
         unimplemented!();
     }
 }
+
+// ------ ------
+//  Attributes
+// ------ ------
+
+impl<DomElement: Into<web_sys::SvgElement>> RawSvgEl<DomElement>
+where
+    Self: RawEl,
+{
+    /// Sets `viewBox` - the area of the user coordinate system mapped to the element's viewport.
+    pub fn view_box(self, min_x: f64, min_y: f64, width: f64, height: f64) -> Self {
+        self.attr(
+            "viewBox",
+            &format!("{} {} {} {}", min_x, min_y, width, height),
+        )
+    }
+
+    /// Sets the path data, e.g. `"M 10 10 L 90 90"`.
+    pub fn d<'a>(self, path: impl IntoCowStr<'a>) -> Self {
+        self.attr("d", &path.into_cow_str())
+    }
+
+    /// The attribute is removed when the signal returns `None`.
+    pub fn d_signal<'a>(
+        self,
+        path: impl Signal<Item = impl IntoOptionCowStr<'a>> + Unpin + 'static,
+    ) -> Self {
+        self.attr_signal("d", path)
+    }
+
+    /// `None` sets `fill="none"`.
+    pub fn fill(self, color: impl Into<Option<HSLuv>>) -> Self {
+        self.attr("fill", &svg_paint(color.into()))
+    }
+
+    pub fn fill_signal(
+        self,
+        color: impl Signal<Item = impl Into<Option<HSLuv>>> + Unpin + 'static,
+    ) -> Self {
+        self.attr_signal("fill", color.map(|color| svg_paint(color.into())))
+    }
+
+    /// `None` sets `stroke="none"`.
+    pub fn stroke(self, color: impl Into<Option<HSLuv>>) -> Self {
+        self.attr("stroke", &svg_paint(color.into()))
+    }
+
+    pub fn stroke_signal(
+        self,
+        color: impl Signal<Item = impl Into<Option<HSLuv>>> + Unpin + 'static,
+    ) -> Self {
+        self.attr_signal("stroke", color.map(|color| svg_paint(color.into())))
+    }
+
+    pub fn stroke_width(self, width: f64) -> Self {
+        self.attr("stroke-width", &width.to_string())
+    }
+
+    /// Sets how the `viewBox` is fitted into the element's viewport
+    /// when their aspect ratios differ.
+    ///
+    /// The `viewBox` defines the drawing's coordinate system while `width` and `height`
+    /// define the viewport, so an `svg` with a `viewBox` and a relative width
+    /// scales with its container - see `responsive`.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let chart = RawSvgEl::new("svg")
+    ///     .view_box(0., 0., 400., 200.)
+    ///     .preserve_aspect_ratio(Align::new().left().bottom(), MeetOrSlice::Meet);
+    /// ```
+    pub fn preserve_aspect_ratio(self, align: Align, meet_or_slice: MeetOrSlice) -> Self {
+        let meet_or_slice: &str = meet_or_slice.into();
+        let value = [align.to_svg_align().as_str(), " ", meet_or_slice].concat();
+        self.attr("preserveAspectRatio", &value)
+    }
+
+    /// Stretches the drawing to the viewport without preserving its aspect ratio
+    /// (`preserveAspectRatio="none"`).
+    pub fn stretch(self) -> Self {
+        self.attr("preserveAspectRatio", "none")
+    }
+
+    /// Makes an `svg` with a `viewBox` as wide as its container, with the height
+    /// derived from the `viewBox`'s aspect ratio and the drawing centered.
+    pub fn responsive(self) -> Self {
+        self.style("width", "100%")
+            .style("height", "auto")
+            .attr("preserveAspectRatio", "xMidYMid meet")
+    }
+
+    pub fn cx(self, cx: f64) -> Self {
+        self.attr("cx", &cx.to_string())
+    }
+
+    pub fn cy(self, cy: f64) -> Self {
+        self.attr("cy", &cy.to_string())
+    }
+
+    pub fn r(self, r: f64) -> Self {
+        self.attr("r", &r.to_string())
+    }
+}
+
+// ------ MeetOrSlice ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum MeetOrSlice {
+    /// The whole `viewBox` is visible, the viewport may have empty space.
+    Meet,
+    /// The whole viewport is covered, the `viewBox` may be clipped.
+    Slice,
+}
+
+// ------ helpers ------
+
+fn svg_paint(color: Option<HSLuv>) -> String {
+    color.map_or_else(|| "none".to_owned(), |color| color.to_string())
+}
@@ -77,6 +77,10 @@ impl ChoosableTag for Row<EmptyFlagSet, MultilineFlagNotSet, RawHtmlEl<web_sys::
 }
 impl<EmptyFlag, MultilineFlag, RE: RawEl> Styleable<'_> for Row<EmptyFlag, MultilineFlag, RE> {}
 impl<EmptyFlag, MultilineFlag, RE: RawEl> KeyboardEventAware for Row<EmptyFlag, MultilineFlag, RE> {}
+impl<EmptyFlag, MultilineFlag, RE: RawEl> Focusable for Row<EmptyFlag, MultilineFlag, RE> where
+    RE::DomElement: AsRef<web_sys::HtmlElement>
+{
+}
 impl<EmptyFlag, MultilineFlag, RE: RawEl> MouseEventAware for Row<EmptyFlag, MultilineFlag, RE> {}
 impl<EmptyFlag, MultilineFlag, RE: RawEl> PointerEventAware for Row<EmptyFlag, MultilineFlag, RE> {}
 impl<EmptyFlag, MultilineFlag, RE: RawEl> TouchEventAware for Row<EmptyFlag, MultilineFlag, RE> {}
@@ -130,11 +134,54 @@ impl<'a, EmptyFlag, MultilineFlag, RE: RawEl> Row<EmptyFlag, MultilineFlag, RE>
         self.into_type()
     }
 
-    pub fn multiline(mut self) -> Row<EmptyFlag, MultilineFlagSet, RE>
+    /// Animates added, removed and moved items. See `ChildTransition`.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let todos = MutableVec::new_with_values(vec!["Buy milk".to_owned()]);
+    /// let todo_list = Row::new().animated_items_signal_vec(
+    ///     todos.signal_vec_cloned(),
+    ///     ChildTransition::fade().enter_style("transform", "scale(0.9)"),
+    /// );
+    /// ```
+    pub fn animated_items_signal_vec(
+        mut self,
+        items: impl SignalVec<Item = impl IntoElement<'a>> + Unpin + 'static,
+        transition: ChildTransition,
+    ) -> Row<EmptyFlagNotSet, MultilineFlag, RE> {
+        self.raw_el = self.raw_el.animated_children_signal_vec(items, transition);
+        self.into_type()
+    }
+
+    /// Items overflowing the row wrap to the next line.
+    /// `Spacing` sets the space between the lines as well.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let tags = Row::new()
+    ///     .multiline()
+    ///     .s(Spacing::new(8))
+    ///     .items(["rust", "wasm", "frontend", "fullstack"]);
+    /// ```
+    pub fn multiline(self) -> Row<EmptyFlag, MultilineFlagSet, RE>
     where
         MultilineFlag: FlagNotSet,
     {
-        self.raw_el = self.raw_el.style("flex-wrap", "wrap");
+        self.wrap("wrap")
+    }
+
+    /// Like `multiline`, but the overflowing items wrap to a line above.
+    pub fn multiline_reverse(self) -> Row<EmptyFlag, MultilineFlagSet, RE>
+    where
+        MultilineFlag: FlagNotSet,
+    {
+        self.wrap("wrap-reverse")
+    }
+
+    fn wrap(mut self, flex_wrap: &str) -> Row<EmptyFlag, MultilineFlagSet, RE> {
+        self.raw_el = self.raw_el.style("flex-wrap", flex_wrap);
         self.raw_el = self.raw_el.style("flex-basis", "0");
         self.raw_el = self.raw_el.style("flex-grow", "1");
         self.into_type()
@@ -0,0 +1,390 @@
+use crate::*;
+use std::{iter, marker::PhantomData};
+
+// ------ ------
+//    Element
+// ------ ------
+
+make_flags!(Id, OnChange, Value, Label, Min, Max, Step);
+
+/// A range input (`<input type="range">`).
+/// # Example
+/// ```no_run
+/// use zoon::{named_color::*, *};
+///
+/// let value = Mutable::new(20.);
+///
+/// let slider = Slider::new()
+///     .label_hidden("radius")
+///     .min(0.)
+///     .max(75.)
+///     .step(1.)
+///     .value_signal(value.signal())
+///     .on_change(move |new_value| value.set_neq(new_value))
+///     .track_s(Background::new().color(GRAY_3))
+///     .thumb_s(Background::new().color(BLUE_5));
+/// let radius_label = Text::with_signal(slider.current_value_signal());
+/// ```
+pub struct Slider<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE: RawEl>
+{
+    current_value: Mutable<f64>,
+    raw_el: RE,
+    flags: PhantomData<(
+        IdFlag,
+        OnChangeFlag,
+        ValueFlag,
+        LabelFlag,
+        MinFlag,
+        MaxFlag,
+        StepFlag,
+    )>,
+}
+
+impl
+    Slider<
+        IdFlagNotSet,
+        OnChangeFlagNotSet,
+        ValueFlagNotSet,
+        LabelFlagNotSet,
+        MinFlagNotSet,
+        MaxFlagNotSet,
+        StepFlagNotSet,
+        RawHtmlEl<web_sys::HtmlInputElement>,
+    >
+{
+    pub fn new() -> Self {
+        run_once!(|| {
+            global_styles().style_group(
+                StyleGroup::new(".slider")
+                    .style("appearance", "none")
+                    .style("height", "6px")
+                    .style("margin", "8px 0")
+                    .style("border-radius", "3px")
+                    .style("background-color", "lightgray"),
+            );
+        });
+        let raw_el = RawHtmlEl::<web_sys::HtmlInputElement>::new("input")
+            .class("slider")
+            .attr("type", "range")
+            .style("cursor", "pointer");
+        let dom_element = raw_el.dom_element();
+        let current_value = Mutable::new(dom_element.value_as_number());
+        Self {
+            raw_el: raw_el.event_handler({
+                let current_value = current_value.clone();
+                move |_: events::Input| current_value.set_neq(dom_element.value_as_number())
+            }),
+            current_value,
+            flags: PhantomData,
+        }
+    }
+}
+
+impl<OnChangeFlag, ValueFlag, MinFlag, MaxFlag, StepFlag, RE: RawEl + Into<RawElement>> Element
+    for Slider<IdFlagSet, OnChangeFlag, ValueFlag, LabelFlagNotSet, MinFlag, MaxFlag, StepFlag, RE>
+{
+    fn into_raw_element(self) -> RawElement {
+        self.raw_el.into()
+    }
+}
+
+impl<OnChangeFlag, ValueFlag, MinFlag, MaxFlag, StepFlag, RE: RawEl + Into<RawElement>> Element
+    for Slider<IdFlagNotSet, OnChangeFlag, ValueFlag, LabelFlagSet, MinFlag, MaxFlag, StepFlag, RE>
+{
+    fn into_raw_element(self) -> RawElement {
+        self.raw_el.into()
+    }
+}
+
+impl<OnChangeFlag, ValueFlag, MinFlag, MaxFlag, StepFlag, RE: RawEl + Into<RawElement>> Element
+    for Slider<IdFlagSet, OnChangeFlag, ValueFlag, LabelFlagSet, MinFlag, MaxFlag, StepFlag, RE>
+{
+    fn into_raw_element(self) -> RawElement {
+        self.raw_el.into()
+    }
+}
+
+impl<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE: RawEl> IntoIterator
+    for Slider<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE>
+{
+    type Item = Self;
+    type IntoIter = iter::Once<Self>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        iter::once(self)
+    }
+}
+
+impl<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE: RawEl> UpdateRawEl
+    for Slider<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE>
+{
+    type RawEl = RE;
+
+    fn update_raw_el(mut self, updater: impl FnOnce(Self::RawEl) -> Self::RawEl) -> Self {
+        self.raw_el = updater(self.raw_el);
+        self
+    }
+}
+
+// ------ ------
+//   Abilities
+// ------ ------
+
+impl<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE: RawEl>
+    Styleable<'_>
+    for Slider<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE>
+{
+}
+impl<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE: RawEl>
+    KeyboardEventAware
+    for Slider<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE>
+{
+}
+impl<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE: RawEl> Disableable
+    for Slider<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE>
+{
+}
+impl<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE: RawEl> Focusable
+    for Slider<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE>
+where
+    RE::DomElement: AsRef<web_sys::HtmlElement>,
+{
+}
+impl<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE: RawEl>
+    MouseEventAware
+    for Slider<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE>
+{
+}
+impl<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE: RawEl>
+    PointerEventAware
+    for Slider<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE>
+{
+}
+impl<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE: RawEl>
+    TouchEventAware
+    for Slider<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE>
+{
+}
+impl<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE: RawEl> Hookable
+    for Slider<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE>
+{
+}
+impl<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE: RawEl>
+    AddNearbyElement<'_>
+    for Slider<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE>
+{
+}
+impl<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE: RawEl> HasIds
+    for Slider<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE>
+{
+}
+
+// ------ ------
+//  Attributes
+// ------ ------
+
+impl<'a, IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE: RawEl>
+    Slider<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE>
+{
+    pub fn id(
+        mut self,
+        id: impl IntoCowStr<'a>,
+    ) -> Slider<IdFlagSet, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE>
+    where
+        IdFlag: FlagNotSet,
+    {
+        self.raw_el = self.raw_el.id(id);
+        self.into_type()
+    }
+
+    /// Default: 0
+    pub fn min(
+        mut self,
+        min: f64,
+    ) -> Slider<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlagSet, MaxFlag, StepFlag, RE>
+    where
+        MinFlag: FlagNotSet,
+    {
+        self.raw_el = self.raw_el.attr("min", &min.to_string());
+        self.refresh_current_value();
+        self.into_type()
+    }
+
+    /// Default: 100
+    pub fn max(
+        mut self,
+        max: f64,
+    ) -> Slider<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlagSet, StepFlag, RE>
+    where
+        MaxFlag: FlagNotSet,
+    {
+        self.raw_el = self.raw_el.attr("max", &max.to_string());
+        self.refresh_current_value();
+        self.into_type()
+    }
+
+    /// Default: 1
+    pub fn step(
+        mut self,
+        step: f64,
+    ) -> Slider<IdFlag, OnChangeFlag, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlagSet, RE>
+    where
+        StepFlag: FlagNotSet,
+    {
+        self.raw_el = self.raw_el.attr("step", &step.to_string());
+        self.refresh_current_value();
+        self.into_type()
+    }
+
+    /// Note: Set `min`, `max` and `step` before `value`
+    /// otherwise the browser may clamp or round the value with default limits.
+    pub fn value(
+        self,
+        value: f64,
+    ) -> Slider<IdFlag, OnChangeFlag, ValueFlagSet, LabelFlag, MinFlag, MaxFlag, StepFlag, RE>
+    where
+        ValueFlag: FlagNotSet,
+        RE::DomElement: AsRef<web_sys::HtmlInputElement>,
+    {
+        self.raw_el
+            .dom_element()
+            .as_ref()
+            .set_value_as_number(value);
+        self.refresh_current_value();
+        self.into_type()
+    }
+
+    /// The `value` property is updated on every change
+    /// so the slider stays controlled by the signal.
+    pub fn value_signal(
+        self,
+        value: impl Signal<Item = f64> + Unpin + 'static,
+    ) -> Slider<IdFlag, OnChangeFlag, ValueFlagSet, LabelFlag, MinFlag, MaxFlag, StepFlag, RE>
+    where
+        ValueFlag: FlagNotSet,
+        RE::DomElement: AsRef<web_sys::HtmlInputElement>,
+    {
+        let dom_element = self.raw_el.dom_element();
+        let current_value = self.current_value.clone();
+        let value_setter = Task::start_droppable(value.for_each_sync(move |value| {
+            let input = dom_element.as_ref();
+            input.set_value_as_number(value);
+            // the browser clamps and rounds the value
+            current_value.set_neq(input.value_as_number());
+        }));
+        self.after_remove(move |_| drop(value_setter)).into_type()
+    }
+
+    pub fn on_change(
+        mut self,
+        on_change: impl FnOnce(f64) + Clone + 'static,
+    ) -> Slider<IdFlag, OnChangeFlagSet, ValueFlag, LabelFlag, MinFlag, MaxFlag, StepFlag, RE>
+    where
+        OnChangeFlag: FlagNotSet,
+        RE::DomElement: AsRef<web_sys::HtmlInputElement>,
+    {
+        let dom_element = self.raw_el.dom_element();
+        self.raw_el = self.raw_el.event_handler(move |_: events::Input| {
+            (on_change.clone())(dom_element.as_ref().value_as_number())
+        });
+        self.into_type()
+    }
+
+    /// The value set by the user or by `value` / `value_signal`,
+    /// clamped and rounded by the browser according to `min`, `max` and `step`.
+    pub fn current_value_signal(&self) -> impl Signal<Item = f64> {
+        self.current_value.signal()
+    }
+
+    /// Styles the slider's track (`::-webkit-slider-runnable-track` or `::-moz-range-track`).
+    pub fn track_s(mut self, style: impl Style<'a> + 'a) -> Self {
+        let selector = if supports_moz_range_selectors() {
+            "::-moz-range-track"
+        } else {
+            "::-webkit-slider-runnable-track"
+        };
+        self.raw_el = self
+            .raw_el
+            .style_group(style.merge_with_group(StyleGroup::new(selector)));
+        self
+    }
+
+    /// Styles the slider's thumb (`::-webkit-slider-thumb` or `::-moz-range-thumb`).
+    pub fn thumb_s(mut self, style: impl Style<'a> + 'a) -> Self {
+        let group = if supports_moz_range_selectors() {
+            StyleGroup::new("::-moz-range-thumb")
+        } else {
+            // WebKit ignores most thumb styles without it
+            StyleGroup::new("::-webkit-slider-thumb").style("appearance", "none")
+        };
+        self.raw_el = self.raw_el.style_group(style.merge_with_group(group));
+        self
+    }
+
+    pub fn label_hidden(
+        mut self,
+        label: impl IntoCowStr<'a>,
+    ) -> Slider<IdFlag, OnChangeFlag, ValueFlag, LabelFlagSet, MinFlag, MaxFlag, StepFlag, RE>
+    where
+        LabelFlag: FlagNotSet,
+    {
+        self.raw_el = self.raw_el.attr("aria-label", &label.into_cow_str());
+        self.into_type()
+    }
+
+    fn into_type<
+        NewIdFlag,
+        NewOnChangeFlag,
+        NewValueFlag,
+        NewLabelFlag,
+        NewMinFlag,
+        NewMaxFlag,
+        NewStepFlag,
+    >(
+        self,
+    ) -> Slider<
+        NewIdFlag,
+        NewOnChangeFlag,
+        NewValueFlag,
+        NewLabelFlag,
+        NewMinFlag,
+        NewMaxFlag,
+        NewStepFlag,
+        RE,
+    > {
+        Slider {
+            current_value: self.current_value,
+            raw_el: self.raw_el,
+            flags: PhantomData,
+        }
+    }
+
+    fn refresh_current_value(&self) {
+        let dom_element: web_sys::Element = self.raw_el.dom_element().into();
+        let value = dom_element
+            .unchecked_ref::<web_sys::HtmlInputElement>()
+            .value_as_number();
+        self.current_value.set_neq(value);
+    }
+}
+
+/// Firefox styles range inputs with `::-moz-range-*` pseudo-elements,
+/// other browsers with `::-webkit-slider-*`. Unsupported pseudo-elements
+/// would make the whole rule invalid.
+fn supports_moz_range_selectors() -> bool {
+    let css = match Reflect::get(&window(), &"CSS".into()) {
+        Ok(css) if css.is_object() => css,
+        _ => return false,
+    };
+    Reflect::get(&css, &"supports".into())
+        .ok()
+        .and_then(|supports| supports.dyn_into::<js_sys::Function>().ok())
+        .and_then(|supports| {
+            supports
+                .call1(&css, &"selector(::-moz-range-thumb)".into())
+                .ok()
+        })
+        .and_then(|supported| supported.as_bool())
+        .unwrap_or_default()
+}
@@ -0,0 +1,105 @@
+use crate::*;
+use std::iter;
+
+// ------ ------
+//    Element
+// ------ ------
+
+/// A rotating ring indicating that something is loading.
+///
+/// The ring is drawn by the current text color, change it by `Font::color`.
+/// The rotation is stopped for users who prefer reduced motion.
+/// # Example
+/// ```no_run
+/// use zoon::{named_color::*, *};
+///
+/// let loading = Row::new()
+///     .s(Spacing::new(10))
+///     .item(Spinner::new().s(Font::new().color(BLUE_5)))
+///     .item("Loading...");
+/// ```
+pub struct Spinner<RE: RawEl> {
+    raw_el: RE,
+}
+
+impl Spinner<RawHtmlEl<web_sys::HtmlElement>> {
+    pub fn new() -> Self {
+        Self {
+            raw_el: RawHtmlEl::<web_sys::HtmlElement>::new("div")
+                .class("spinner")
+                .attr("role", "status")
+                .attr("aria-label", "Loading")
+                .style("width", "24px")
+                .style("height", "24px")
+                .style("flex-shrink", "0")
+                .style("box-sizing", "border-box")
+                .style("border", "3px solid currentColor")
+                .style("border-right-color", "transparent")
+                .style("border-radius", "50%")
+                .after_insert(rotate),
+        }
+    }
+}
+
+impl<RE: RawEl + Into<RawElement>> Element for Spinner<RE> {
+    fn into_raw_element(self) -> RawElement {
+        self.raw_el.into()
+    }
+}
+
+impl<RE: RawEl> IntoIterator for Spinner<RE> {
+    type Item = Self;
+    type IntoIter = iter::Once<Self>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        iter::once(self)
+    }
+}
+
+impl<RE: RawEl> UpdateRawEl for Spinner<RE> {
+    type RawEl = RE;
+
+    fn update_raw_el(mut self, updater: impl FnOnce(Self::RawEl) -> Self::RawEl) -> Self {
+        self.raw_el = updater(self.raw_el);
+        self
+    }
+}
+
+// ------ ------
+//   Abilities
+// ------ ------
+
+impl<RE: RawEl> Styleable<'_> for Spinner<RE> {}
+impl<RE: RawEl> Hookable for Spinner<RE> {}
+impl<RE: RawEl> HasIds for Spinner<RE> {}
+
+// ------ rotate ------
+
+/// Infinite rotation by the Web Animations API, so no `@keyframes` rule has to be inserted.
+/// The animation is removed together with the element.
+fn rotate(element: web_sys::HtmlElement) {
+    if media::prefers_reduced_motion() {
+        return;
+    }
+    let animate = match Reflect::get(&element, &"animate".into()) {
+        Ok(animate) if animate.is_function() => animate.unchecked_into::<js_sys::Function>(),
+        // old browsers
+        _ => return,
+    };
+
+    let keyframe = |transform: &str| {
+        let keyframe = js_sys::Object::new();
+        Reflect::set(&keyframe, &"transform".into(), &transform.into()).unwrap_throw();
+        keyframe
+    };
+    let keyframes = js_sys::Array::of2(&keyframe("rotate(0deg)"), &keyframe("rotate(360deg)"));
+
+    let options = js_sys::Object::new();
+    Reflect::set(&options, &"duration".into(), &800.into()).unwrap_throw();
+    Reflect::set(&options, &"iterations".into(), &f64::INFINITY.into()).unwrap_throw();
+
+    if let Err(error) = animate.call2(&element, &keyframes, &options) {
+        crate::zwarn!("failed to animate the spinner: {:?}", error);
+    }
+}
@@ -85,6 +85,10 @@ impl ChoosableTag for Stack<EmptyFlagSet, RawHtmlEl<web_sys::HtmlElement>> {
 }
 impl<EmptyFlag, RE: RawEl> Styleable<'_> for Stack<EmptyFlag, RE> {}
 impl<EmptyFlag, RE: RawEl> KeyboardEventAware for Stack<EmptyFlag, RE> {}
+impl<EmptyFlag, RE: RawEl> Focusable for Stack<EmptyFlag, RE> where
+    RE::DomElement: AsRef<web_sys::HtmlElement>
+{
+}
 impl<EmptyFlag, RE: RawEl> MouseEventAware for Stack<EmptyFlag, RE> {}
 impl<EmptyFlag, RE: RawEl> PointerEventAware for Stack<EmptyFlag, RE> {}
 impl<EmptyFlag, RE: RawEl> TouchEventAware for Stack<EmptyFlag, RE> {}
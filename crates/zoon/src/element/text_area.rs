@@ -1,5 +1,5 @@
 use crate::{text_input::PlaceholderText, *};
-use std::{iter, marker::PhantomData};
+use std::{cell::RefCell, convert::TryFrom, iter, marker::PhantomData, rc::Rc, time::Duration};
 
 // ------ ------
 //    Element
@@ -16,6 +16,7 @@ pub struct TextArea<
     ReadOnlyFlag,
     RE: RawEl,
 > {
+    valid: Mutable<bool>,
     raw_el: RE,
     flags: PhantomData<(
         IdFlag,
@@ -40,6 +41,7 @@ impl
 {
     pub fn new() -> Self {
         Self {
+            valid: Mutable::new(true),
             raw_el: RawHtmlEl::<web_sys::HtmlTextAreaElement>::new("textarea").class("text_area"),
             flags: PhantomData,
         }
@@ -125,6 +127,11 @@ impl<IdFlag, OnChangeFlag, PlaceholderFlag, TextFlag, LabelFlag, ReadOnlyFlag, R
     for TextArea<IdFlag, OnChangeFlag, PlaceholderFlag, TextFlag, LabelFlag, ReadOnlyFlag, RE>
 {
 }
+impl<IdFlag, OnChangeFlag, PlaceholderFlag, TextFlag, LabelFlag, ReadOnlyFlag, RE: RawEl>
+    Disableable
+    for TextArea<IdFlag, OnChangeFlag, PlaceholderFlag, TextFlag, LabelFlag, ReadOnlyFlag, RE>
+{
+}
 impl<IdFlag, OnChangeFlag, PlaceholderFlag, TextFlag, LabelFlag, ReadOnlyFlag, RE: RawEl> Focusable
     for TextArea<IdFlag, OnChangeFlag, PlaceholderFlag, TextFlag, LabelFlag, ReadOnlyFlag, RE>
 where
@@ -270,6 +277,107 @@ impl<'a, IdFlag, OnChangeFlag, PlaceholderFlag, TextFlag, LabelFlag, ReadOnlyFla
         self.into_type()
     }
 
+    /// The handler is called with the current text once the user stops typing for `delay`.
+    pub fn on_change_debounced(
+        mut self,
+        delay: Duration,
+        on_change: impl FnOnce(String) + Clone + 'static,
+    ) -> TextArea<IdFlag, OnChangeFlagSet, PlaceholderFlag, TextFlag, LabelFlag, ReadOnlyFlag, RE>
+    where
+        OnChangeFlag: FlagNotSet,
+        RE::DomElement: AsRef<web_sys::HtmlTextAreaElement>,
+    {
+        let ms = u32::try_from(delay.as_millis()).unwrap_or(u32::MAX);
+        let dom_element = self.raw_el.dom_element();
+        let timer = Rc::new(RefCell::new(None));
+        self.raw_el = self.raw_el.event_handler(move |_: events::Input| {
+            let text = dom_element.as_ref().value();
+            let on_change = on_change.clone();
+            // The previous `Timer` is dropped and thus cancelled.
+            *timer.borrow_mut() = Some(Timer::once(ms, move || on_change(text)));
+        });
+        self.into_type()
+    }
+
+    /// Validates the text once the text area is inserted and on every change.
+    /// The error message is set as the text area's custom validity (`:invalid` CSS selector)
+    /// and passed together with `Ok(())` results to `on_validity_change`.
+    /// See `valid_signal` for the validation result as a signal.
+    pub fn validation(
+        mut self,
+        validator: impl Fn(&str) -> Result<(), String> + 'static,
+        on_validity_change: impl FnOnce(Result<(), String>) + Clone + 'static,
+    ) -> Self
+    where
+        RE::DomElement: AsRef<web_sys::HtmlTextAreaElement>,
+    {
+        let dom_element = self.raw_el.dom_element();
+        let valid = self.valid.clone();
+        let validate = Rc::new(move || {
+            let text_area: &web_sys::HtmlTextAreaElement = dom_element.as_ref();
+            let validity = validator(&text_area.value());
+            let error = validity.as_ref().err().map(String::as_str);
+            text_area.set_custom_validity(error.unwrap_or_default());
+            if error.is_some() {
+                text_area
+                    .set_attribute("aria-invalid", "true")
+                    .unwrap_throw();
+            } else {
+                text_area.remove_attribute("aria-invalid").unwrap_throw();
+            }
+            // native constraints like `required` are included
+            valid.set_neq(text_area.check_validity());
+            (on_validity_change.clone())(validity)
+        });
+        let validate_on_input = Rc::clone(&validate);
+        self.raw_el = self
+            .raw_el
+            .after_insert(move |_| validate())
+            .event_handler(move |_: events::Input| validate_on_input());
+        self
+    }
+
+    /// `false` while the text area is invalid, `true` until the first validation.
+    /// The text area is validated by `validation`.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let text_area = TextArea::new()
+    ///     .label_hidden("Name")
+    ///     .validation(
+    ///         |text| if text.is_empty() { Err("Required".to_owned()) } else { Ok(()) },
+    ///         |_| (),
+    ///     );
+    /// let valid = text_area.valid_signal();
+    /// ```
+    pub fn valid_signal(&self) -> impl Signal<Item = bool> {
+        self.valid.signal()
+    }
+
+    /// The text area grows and shrinks with its content instead of showing a scrollbar.
+    pub fn auto_grow(mut self) -> Self
+    where
+        RE::DomElement: AsRef<web_sys::HtmlTextAreaElement>,
+    {
+        fn fit_height(text_area: &web_sys::HtmlTextAreaElement) {
+            let style = text_area.style();
+            // Reset the height first to allow shrinking.
+            style.set_property("height", "auto").unwrap_throw();
+            style
+                .set_property("height", &px(text_area.scroll_height()))
+                .unwrap_throw();
+        }
+
+        let dom_element = self.raw_el.dom_element();
+        self.raw_el = self
+            .raw_el
+            .style("overflow-y", "hidden")
+            .after_insert(|dom_element| fit_height(dom_element.as_ref()))
+            .event_handler(move |_: events::Input| fit_height(dom_element.as_ref()));
+        self
+    }
+
     pub fn label_hidden(
         mut self,
         label: impl IntoCowStr<'a>,
@@ -300,6 +408,7 @@ impl<'a, IdFlag, OnChangeFlag, PlaceholderFlag, TextFlag, LabelFlag, ReadOnlyFla
         RE,
     > {
         TextArea {
+            valid: self.valid,
             raw_el: self.raw_el,
             flags: PhantomData,
         }
@@ -1,5 +1,7 @@
 use crate::*;
-use std::{borrow::Cow, iter, marker::PhantomData};
+use std::{
+    borrow::Cow, cell::RefCell, convert::TryFrom, iter, marker::PhantomData, rc::Rc, time::Duration,
+};
 
 mod input_type;
 pub use input_type::*;
@@ -20,6 +22,7 @@ pub struct TextInput<
     ReadOnlyFlag,
     RE: RawEl,
 > {
+    valid: Mutable<bool>,
     raw_el: RE,
     flags: PhantomData<(
         IdFlag,
@@ -46,6 +49,7 @@ impl
 {
     pub fn new() -> Self {
         Self {
+            valid: Mutable::new(true),
             raw_el: RawHtmlEl::<web_sys::HtmlInputElement>::new("input").class("text_input"),
             flags: PhantomData,
         }
@@ -231,6 +235,28 @@ impl<
     >
 {
 }
+impl<
+        IdFlag,
+        OnChangeFlag,
+        PlaceholderFlag,
+        TextFlag,
+        LabelFlag,
+        InputTypeFlag,
+        ReadOnlyFlag,
+        RE: RawEl,
+    > Disableable
+    for TextInput<
+        IdFlag,
+        OnChangeFlag,
+        PlaceholderFlag,
+        TextFlag,
+        LabelFlag,
+        InputTypeFlag,
+        ReadOnlyFlag,
+        RE,
+    >
+{
+}
 impl<
         IdFlag,
         OnChangeFlag,
@@ -618,6 +644,91 @@ impl<
         self.into_type()
     }
 
+    /// The handler is called with the current text once the user stops typing for `delay`.
+    pub fn on_change_debounced(
+        mut self,
+        delay: Duration,
+        on_change: impl FnOnce(String) + Clone + 'static,
+    ) -> TextInput<
+        IdFlag,
+        OnChangeFlagSet,
+        PlaceholderFlag,
+        TextFlag,
+        LabelFlag,
+        InputTypeFlag,
+        ReadOnlyFlag,
+        RE,
+    >
+    where
+        OnChangeFlag: FlagNotSet,
+        RE::DomElement: AsRef<web_sys::HtmlInputElement>,
+    {
+        let ms = u32::try_from(delay.as_millis()).unwrap_or(u32::MAX);
+        let dom_element = self.raw_el.dom_element();
+        let timer = Rc::new(RefCell::new(None));
+        self.raw_el = self.raw_el.event_handler(move |_: events::Input| {
+            let text = dom_element.as_ref().value();
+            let on_change = on_change.clone();
+            // The previous `Timer` is dropped and thus cancelled.
+            *timer.borrow_mut() = Some(Timer::once(ms, move || on_change(text)));
+        });
+        self.into_type()
+    }
+
+    /// Validates the text once the input is inserted and on every change.
+    /// The error message is set as the input's custom validity (`:invalid` CSS selector)
+    /// and passed together with `Ok(())` results to `on_validity_change`.
+    /// See `valid_signal` for the validation result as a signal.
+    pub fn validation(
+        mut self,
+        validator: impl Fn(&str) -> Result<(), String> + 'static,
+        on_validity_change: impl FnOnce(Result<(), String>) + Clone + 'static,
+    ) -> Self
+    where
+        RE::DomElement: AsRef<web_sys::HtmlInputElement>,
+    {
+        let dom_element = self.raw_el.dom_element();
+        let valid = self.valid.clone();
+        let validate = Rc::new(move || {
+            let input: &web_sys::HtmlInputElement = dom_element.as_ref();
+            let validity = validator(&input.value());
+            let error = validity.as_ref().err().map(String::as_str);
+            input.set_custom_validity(error.unwrap_or_default());
+            if error.is_some() {
+                input.set_attribute("aria-invalid", "true").unwrap_throw();
+            } else {
+                input.remove_attribute("aria-invalid").unwrap_throw();
+            }
+            // native constraints like `required` are included
+            valid.set_neq(input.check_validity());
+            (on_validity_change.clone())(validity)
+        });
+        let validate_on_input = Rc::clone(&validate);
+        self.raw_el = self
+            .raw_el
+            .after_insert(move |_| validate())
+            .event_handler(move |_: events::Input| validate_on_input());
+        self
+    }
+
+    /// `false` while the input is invalid, `true` until the first validation.
+    /// The input is validated by `validation`.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let input = TextInput::new()
+    ///     .label_hidden("Name")
+    ///     .validation(
+    ///         |text| if text.is_empty() { Err("Required".to_owned()) } else { Ok(()) },
+    ///         |_| (),
+    ///     );
+    /// let valid = input.valid_signal();
+    /// ```
+    pub fn valid_signal(&self) -> impl Signal<Item = bool> {
+        self.valid.signal()
+    }
+
     pub fn label_hidden(
         mut self,
         label: impl IntoCowStr<'a>,
@@ -659,6 +770,7 @@ impl<
         RE,
     > {
         TextInput {
+            valid: self.valid,
             raw_el: self.raw_el,
             flags: PhantomData,
         }
@@ -1,7 +1,12 @@
+use crate::*;
+
 #[derive(Copy, Clone, Debug, Default)]
 pub struct EventOptions {
     bubbles: bool,
     preventable: bool,
+    prevent_default: bool,
+    stop_propagation: bool,
+    stop_immediate_propagation: bool,
 }
 
 impl EventOptions {
@@ -18,6 +23,43 @@ impl EventOptions {
         self.preventable = true;
         self
     }
+
+    /// Calls `event.preventDefault()` before the handler. Implies `preventable`.
+    pub fn prevent_default(mut self) -> Self {
+        self.preventable = true;
+        self.prevent_default = true;
+        self
+    }
+
+    /// Calls `event.stopPropagation()` before the handler,
+    /// e.g. to not trigger a clickable card by clicking its close button.
+    pub fn stop_propagation(mut self) -> Self {
+        self.stop_propagation = true;
+        self
+    }
+
+    /// Calls `event.stopImmediatePropagation()` before the handler so other handlers
+    /// for the same event on the same element aren't called either.
+    pub fn stop_immediate_propagation(mut self) -> Self {
+        self.stop_immediate_propagation = true;
+        self
+    }
+
+    pub(crate) fn controls_event(&self) -> bool {
+        self.prevent_default || self.stop_propagation || self.stop_immediate_propagation
+    }
+
+    pub(crate) fn control_event(&self, event: &web_sys::Event) {
+        if self.prevent_default {
+            event.prevent_default();
+        }
+        if self.stop_propagation {
+            event.stop_propagation();
+        }
+        if self.stop_immediate_propagation {
+            event.stop_immediate_propagation();
+        }
+    }
 }
 
 impl From<EventOptions> for dominator::EventOptions {
@@ -28,3 +70,23 @@ impl From<EventOptions> for dominator::EventOptions {
         }
     }
 }
+
+// ------ ControlledEvent ------
+
+/// Gives access to the raw `web_sys::Event` behind any `StaticEvent`.
+pub(crate) struct ControlledEvent<E> {
+    pub(crate) raw_event: web_sys::Event,
+    pub(crate) event: E,
+}
+
+impl<E: StaticEvent> StaticEvent for ControlledEvent<E> {
+    const EVENT_TYPE: &'static str = E::EVENT_TYPE;
+
+    #[inline]
+    fn unchecked_from_event(event: web_sys::Event) -> Self {
+        Self {
+            raw_event: event.clone(),
+            event: E::unchecked_from_event(event),
+        }
+    }
+}
@@ -86,8 +86,36 @@ macro_rules! make_mouse_event {
     };
 }
 
+make_event!(Close, "close" => web_sys::Event);
+make_event!(Cancel, "cancel" => web_sys::Event);
+make_event!(Toggle, "toggle" => web_sys::Event);
+make_event!(Submit, "submit" => web_sys::Event);
+make_event!(TransitionEnd, "transitionend" => web_sys::Event);
+
+// focusin and focusout bubble, unlike focus and blur
+
+make_event!(FocusIn, "focusin" => web_sys::FocusEvent);
+make_event!(FocusOut, "focusout" => web_sys::FocusEvent);
+
+impl FocusIn {
+    /// The element losing focus.
+    #[inline]
+    pub fn related_target(&self) -> Option<web_sys::EventTarget> {
+        self.event.related_target()
+    }
+}
+
+impl FocusOut {
+    /// The element receiving focus.
+    #[inline]
+    pub fn related_target(&self) -> Option<web_sys::EventTarget> {
+        self.event.related_target()
+    }
+}
+
 make_mouse_event!(MouseOver, "mouseover");
 make_mouse_event!(MouseOut, "mouseout");
+make_mouse_event!(ContextMenu, "contextmenu");
 
 make_event!(WheelEvent, "wheel" => web_sys::WheelEvent);
 
@@ -267,3 +295,5 @@ make_pointer_event!(PointerMove, "pointermove");
 make_pointer_event!(PointerEnter, "pointerenter");
 make_pointer_event!(PointerLeave, "pointerleave");
 make_pointer_event!(PointerCancel, "pointercancel");
+make_pointer_event!(GotPointerCapture, "gotpointercapture");
+make_pointer_event!(LostPointerCapture, "lostpointercapture");
@@ -0,0 +1,269 @@
+use crate::*;
+use std::{cell::RefCell, error::Error, fmt, rc::Rc};
+
+// ------ Position ------
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Position {
+    /// Degrees
+    pub latitude: f64,
+    /// Degrees
+    pub longitude: f64,
+    /// Meters
+    pub accuracy: f64,
+    /// Meters
+    pub altitude: Option<f64>,
+    /// Meters
+    pub altitude_accuracy: Option<f64>,
+    /// Degrees clockwise from true north
+    pub heading: Option<f64>,
+    /// Meters per second
+    pub speed: Option<f64>,
+    /// Milliseconds since the Unix epoch
+    pub timestamp: f64,
+}
+
+impl From<web_sys::Position> for Position {
+    fn from(position: web_sys::Position) -> Self {
+        let coords = position.coords();
+        Self {
+            latitude: coords.latitude(),
+            longitude: coords.longitude(),
+            accuracy: coords.accuracy(),
+            altitude: coords.altitude(),
+            altitude_accuracy: coords.altitude_accuracy(),
+            heading: coords.heading(),
+            speed: coords.speed(),
+            timestamp: position.timestamp(),
+        }
+    }
+}
+
+// ------ GeoError ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeoError {
+    PermissionDenied,
+    PositionUnavailable,
+    Timeout,
+    /// The browser doesn't provide the Geolocation API.
+    NotSupported,
+}
+
+impl From<web_sys::PositionError> for GeoError {
+    fn from(error: web_sys::PositionError) -> Self {
+        match error.code() {
+            web_sys::PositionError::PERMISSION_DENIED => Self::PermissionDenied,
+            web_sys::PositionError::TIMEOUT => Self::Timeout,
+            _ => Self::PositionUnavailable,
+        }
+    }
+}
+
+impl fmt::Display for GeoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeoError::PermissionDenied => write!(f, "geolocation permission denied"),
+            GeoError::PositionUnavailable => write!(f, "position unavailable"),
+            GeoError::Timeout => write!(f, "geolocation timed out"),
+            GeoError::NotSupported => write!(f, "geolocation is not supported"),
+        }
+    }
+}
+
+impl Error for GeoError {}
+
+// ------ GeoOptions ------
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GeoOptions {
+    high_accuracy: bool,
+    timeout: Option<u32>,
+    maximum_age: Option<u32>,
+}
+
+impl GeoOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prefer precise positions (e.g. GPS) at the cost of time and battery.
+    pub fn high_accuracy(mut self) -> Self {
+        self.high_accuracy = true;
+        self
+    }
+
+    /// Fail with `GeoError::Timeout` when the position isn't known in time.
+    pub fn timeout(mut self, ms: u32) -> Self {
+        self.timeout = Some(ms);
+        self
+    }
+
+    /// Accept a cached position not older than `ms`.
+    pub fn maximum_age(mut self, ms: u32) -> Self {
+        self.maximum_age = Some(ms);
+        self
+    }
+
+    fn to_native(self) -> web_sys::PositionOptions {
+        let mut options = web_sys::PositionOptions::new();
+        options.enable_high_accuracy(self.high_accuracy);
+        if let Some(timeout) = self.timeout {
+            options.timeout(timeout);
+        }
+        if let Some(maximum_age) = self.maximum_age {
+            options.maximum_age(maximum_age);
+        }
+        options
+    }
+}
+
+// ------ current_position ------
+
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// Task::start(async {
+///     match geolocation::current_position().await {
+///         Ok(position) => println!("{}, {}", position.latitude, position.longitude),
+///         Err(error) => println!("{}", error),
+///     }
+/// });
+/// ```
+pub async fn current_position() -> Result<Position, GeoError> {
+    current_position_with_options(GeoOptions::default()).await
+}
+
+pub async fn current_position_with_options(options: GeoOptions) -> Result<Position, GeoError> {
+    let geolocation = native_geolocation()?;
+
+    let (sender, receiver) = oneshot::channel();
+    let sender = Rc::new(RefCell::new(Some(sender)));
+    let send = move |result| {
+        if let Some(sender) = sender.borrow_mut().take() {
+            let _ = sender.send(result);
+        }
+    };
+    // The callbacks have to outlive this future, it may be dropped before the browser calls them.
+    // Only one of them is called, the other one is leaked.
+    let on_success = Closure::once_into_js({
+        let send = send.clone();
+        move |position: web_sys::Position| send(Ok(position.into()))
+    });
+    let on_error =
+        Closure::once_into_js(move |error: web_sys::PositionError| send(Err(error.into())));
+
+    geolocation
+        .get_current_position_with_error_callback_and_options(
+            on_success.unchecked_ref(),
+            Some(on_error.unchecked_ref()),
+            &options.to_native(),
+        )
+        .map_err(|_| GeoError::NotSupported)?;
+
+    receiver.await.unwrap_or(Err(GeoError::PositionUnavailable))
+}
+
+// ------ watch_position ------
+
+/// Returns the watched position (`None` until the first fix or error)
+/// and a handle that stops watching when dropped.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let (position, watch_handle) = geolocation::watch_position();
+/// let position_text = Text::with_signal(position.map(|position| match position {
+///     Some(Ok(position)) => format!("{}, {}", position.latitude, position.longitude),
+///     Some(Err(error)) => error.to_string(),
+///     None => "Locating...".to_owned(),
+/// }));
+/// ```
+pub fn watch_position() -> (
+    impl Signal<Item = Option<Result<Position, GeoError>>>,
+    WatchHandle,
+) {
+    watch_position_with_options(GeoOptions::default())
+}
+
+pub fn watch_position_with_options(
+    options: GeoOptions,
+) -> (
+    impl Signal<Item = Option<Result<Position, GeoError>>>,
+    WatchHandle,
+) {
+    let position = Mutable::new(None);
+    let signal = position.signal();
+
+    let geolocation = match native_geolocation() {
+        Ok(geolocation) => geolocation,
+        Err(error) => {
+            position.set(Some(Err(error)));
+            return (signal, WatchHandle::default());
+        }
+    };
+
+    let on_success = Closure::wrap(Box::new({
+        let position = position.clone();
+        move |new_position: web_sys::Position| position.set(Some(Ok(new_position.into())))
+    }) as Box<dyn Fn(web_sys::Position)>);
+    let on_error = Closure::wrap(Box::new({
+        let position = position.clone();
+        move |error: web_sys::PositionError| position.set(Some(Err(error.into())))
+    }) as Box<dyn Fn(web_sys::PositionError)>);
+
+    let watch_id = geolocation.watch_position_with_error_callback_and_options(
+        on_success.as_ref().unchecked_ref(),
+        Some(on_error.as_ref().unchecked_ref()),
+        &options.to_native(),
+    );
+    let watch_id = match watch_id {
+        Ok(watch_id) => watch_id,
+        Err(_) => {
+            position.set(Some(Err(GeoError::NotSupported)));
+            return (signal, WatchHandle::default());
+        }
+    };
+
+    let watch_handle = WatchHandle {
+        watch: Some(Watch {
+            geolocation,
+            watch_id,
+            _on_success: on_success,
+            _on_error: on_error,
+        }),
+    };
+    (signal, watch_handle)
+}
+
+// ------ WatchHandle ------
+
+#[derive(Default)]
+pub struct WatchHandle {
+    watch: Option<Watch>,
+}
+
+struct Watch {
+    geolocation: web_sys::Geolocation,
+    watch_id: i32,
+    _on_success: Closure<dyn Fn(web_sys::Position)>,
+    _on_error: Closure<dyn Fn(web_sys::PositionError)>,
+}
+
+impl Drop for WatchHandle {
+    fn drop(&mut self) {
+        if let Some(watch) = &self.watch {
+            watch.geolocation.clear_watch(watch.watch_id);
+        }
+    }
+}
+
+// ------ helpers ------
+
+fn native_geolocation() -> Result<web_sys::Geolocation, GeoError> {
+    window()
+        .navigator()
+        .geolocation()
+        .map_err(|_| GeoError::NotSupported)
+}
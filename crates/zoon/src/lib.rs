@@ -7,9 +7,15 @@ pub mod routing;
 #[cfg(feature = "web_storage")]
 pub mod web_storage;
 
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+mod custom_event;
+
+pub mod a11y;
 mod class_id;
+mod computed;
 pub mod console;
 mod cow_str;
+mod crash_overlay;
 mod css_property;
 pub mod dom;
 mod dom_builder_ext;
@@ -18,21 +24,37 @@ mod element;
 mod event_options;
 pub mod events_extra;
 mod futures_signals_ext;
+pub mod geolocation;
 mod index_generator;
+pub mod logging;
+pub mod media;
 mod monotonic_ids;
 mod mutable;
 mod mutable_vec;
+mod mutation_observer;
+pub mod network;
 mod not;
+pub mod page;
+pub mod pwa;
 mod resize_observer;
+mod roving_focus;
 mod style;
 mod task;
+pub mod theme;
 mod timer;
-mod viewport;
+mod toasts;
+pub mod viewport;
+mod watched_state;
 
 pub use class_id::ClassId;
+pub use computed::{Computed, Dependency, Tracker};
 pub use cow_str::{IntoCowStr, IntoOptionCowStr};
+pub use crash_overlay::set_crash_overlay;
 pub use css_property::VENDOR_PREFIXES;
-pub use dom::{document, history, load_script, load_stylesheet, window};
+pub use dom::{
+    document, history, load_script, load_stylesheet, window, ScrollAlignment, ScrollBehavior,
+    ScrollIntoViewOptions,
+};
 pub use dom_builder_ext::DomBuilderExt;
 pub use dominator::{self, events, traits::StaticEvent, Dom, DomBuilder};
 pub use either::{Either, IntoEither};
@@ -58,32 +80,35 @@ pub use lang::Lang;
 pub use monotonic_ids::MonotonicIds;
 pub use mutable::Mutable;
 pub use mutable_vec::MutableVec;
+pub use mutation_observer::{DomMutation, MutationConfig, MutationObserver};
 pub use not::not;
 pub use once_cell;
 pub use paste::paste;
 pub use pin_project::pin_project;
 pub use resize_observer::ResizeObserver;
+pub use roving_focus::RovingFocus;
 pub use send_wrapper::SendWrapper;
 pub use std::future::Future;
 pub use strum;
 pub use style::*;
-pub use task::{Task, TaskHandle};
+pub use task::{Cancelled, Task, TaskHandle, TaskHandleGroup, TaskJoinHandle};
 pub use timer::Timer;
+pub use toasts::{toasts, Toast, ToastId, ToastKind, Toasts};
 pub use viewport::{Scene, Viewport};
 pub use wasm_bindgen::{self, prelude::*, JsCast};
 pub use wasm_bindgen_futures::{self, JsFuture};
 pub use web_sys;
 
 #[cfg(feature = "connection")]
-pub use connection::{Connection, SendUpMsgError};
+pub use connection::{Connection, QueueConfig, SendUpMsgError, UpMsgResponse};
 
 #[cfg(feature = "routing")]
 pub use route_macro::route;
 #[cfg(feature = "routing")]
-pub use routing::{FromRouteSegments, RouteSegment, Router};
+pub use routing::{FromRouteSegments, QueryParams, RouteSegment, Router};
 
 #[cfg(feature = "moonlight")]
-pub use moonlight::{self, AuthToken, CorId, EntityId, Wrapper};
+pub use moonlight::{self, AuthToken, CorId, DecodingError, EntityId, SessionId, Wrapper};
 
 #[cfg(feature = "panic_hook")]
 pub use console_error_panic_hook;
@@ -114,6 +139,9 @@ pub use web_storage::{local_storage, session_storage, LocalStorage, SessionStora
 #[cfg(feature = "serde_json")]
 pub use serde_json;
 
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+pub use custom_event::dispatch_custom_event;
+
 #[cfg(feature = "serde-lite")]
 pub use serde_lite::{self, Deserialize, Serialize};
 
@@ -202,21 +230,144 @@ macro_rules! element_vec {
 
 // ------ start_app ------
 
+/// Mounts the app into the element with the given id or into `<body>`.
+/// See `start_app_in`.
 pub fn start_app<'a, E: Element, I: IntoIterator<Item = E>>(
     browser_element_id: impl Into<Option<&'a str>>,
     view_root: impl FnOnce() -> I,
-) {
-    #[cfg(feature = "panic_hook")]
-    #[cfg(debug_assertions)]
-    console_error_panic_hook::set_once();
-
+) -> App {
     let parent = browser_element_id
         .into()
         // @TODO we need a better error message
         .map(dominator::get_id)
         .unwrap_or_else(|| dominator::body().unchecked_into());
 
-    for element in view_root() {
-        dominator::append_dom(&parent, element.into_raw_element().into_dom());
+    start_app_in(parent, view_root)
+}
+
+/// Mounts the app into the given element.
+/// Useful for embedding Zoon widgets into non-Zoon pages;
+/// several independent apps can run on one page.
+///
+/// Note: Global singletons like `global_styles()` are shared by all apps on the page.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let widget_container = document().get_element_by_id("widget").unwrap_throw();
+/// let app = start_app_in(widget_container, || El::new().child("Widget"));
+/// ```
+pub fn start_app_in<E: Element, I: IntoIterator<Item = E>>(
+    parent: impl AsRef<web_sys::Element>,
+    view_root: impl FnOnce() -> I,
+) -> App {
+    #[cfg(feature = "panic_hook")]
+    #[cfg(debug_assertions)]
+    console_error_panic_hook::set_once();
+
+    let parent = parent.as_ref();
+    let dom_handles = view_root()
+        .into_iter()
+        .map(|element| dominator::append_dom(parent, element.into_raw_element().into_dom()))
+        .collect();
+    App {
+        dom_handles,
+        owned_resources: Vec::new(),
+    }
+}
+
+// ------ App ------
+
+/// A handle to an app mounted by `start_app`, `start_app_in` or `start_app_adopting_markup`.
+/// The app keeps running when the handle is dropped.
+///
+/// Resources owned by elements (e.g. tasks started by `*_signal` methods) are released
+/// when the app is unmounted. Other resources the app creates - droppable tasks,
+/// style groups or listeners - can be passed to `own` to be released with the app.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let mut app = start_app("app", || El::new().child("Hello!"));
+/// app.own(global_styles().style_group_droppable(
+///     StyleGroup::new("body").style("background-color", "black"),
+/// ));
+/// app.own(Task::start_droppable(async { /* ... */ }));
+/// // later
+/// app.unmount();
+/// ```
+pub struct App {
+    dom_handles: Vec<dominator::DomHandle>,
+    owned_resources: Vec<Box<dyn std::any::Any>>,
+}
+
+impl App {
+    /// The `resource` is dropped when the app is unmounted.
+    pub fn own(&mut self, resource: impl std::any::Any) -> &mut Self {
+        self.owned_resources.push(Box::new(resource));
+        self
+    }
+
+    /// Removes the app's root elements from the document, runs their `after_remove` hooks
+    /// and drops all resources passed to `own`.
+    ///
+    /// Note: Tasks started by `Task::start` can't be cancelled,
+    /// use `Task::start_droppable` with `own` instead.
+    pub fn unmount(self) {
+        for dom_handle in self.dom_handles {
+            dom_handle.discard();
+        }
+        drop(self.owned_resources);
+    }
+}
+
+// ------ start_app_adopting_markup ------
+
+/// Mounts the app onto an existing root element with its server-rendered markup
+/// instead of rendering the app from scratch, so there is no flash of re-rendered content.
+///
+/// Note: It's markup adoption, not full hydration - the view isn't matched to the markup.
+/// `view_root` gets the root element and adopts the server-rendered descendants explicitly,
+/// usually with `update_html_child`, to attach event handlers and reactive bindings to them.
+/// Children added by `view_root` are appended after the existing ones.
+///
+/// The root element is detached and reattached in place when the app is mounted,
+/// so `after_insert` hooks run as with `start_app`. `App::unmount` removes the root element.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let counter = Mutable::new(0);
+///
+/// let app = start_app_adopting_markup("app", |root| {
+///     root.update_html_child("#counter-value", |child| {
+///         child.child_signal(counter.signal())
+///     })
+/// });
+/// ```
+pub fn start_app_adopting_markup<'a, RE: RawEl + IntoDom>(
+    browser_element_id: impl Into<Option<&'a str>>,
+    view_root: impl FnOnce(RawHtmlEl<web_sys::HtmlElement>) -> RE,
+) -> App {
+    #[cfg(feature = "panic_hook")]
+    #[cfg(debug_assertions)]
+    console_error_panic_hook::set_once();
+
+    let root: web_sys::HtmlElement = browser_element_id
+        .into()
+        .map(dominator::get_id)
+        .unwrap_or_else(|| dominator::body().unchecked_into())
+        .unchecked_into();
+    let parent = root
+        .parent_node()
+        .expect_throw("the root element has to be attached to the document");
+
+    let root_dom = view_root(RawHtmlEl::from_dom_element(root.clone())).into_dom();
+    // `replace_dom` with the same node keeps the node in place
+    // and runs the insert callbacks like `append_dom` does.
+    let dom_handle = dominator::replace_dom(&parent, &root, root_dom);
+    App {
+        dom_handles: vec![dom_handle],
+        owned_resources: Vec::new(),
     }
 }
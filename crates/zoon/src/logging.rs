@@ -0,0 +1,100 @@
+use crate::*;
+use std::{cell::RefCell, fmt};
+
+// ------ Level ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = match self {
+            Self::Trace => "TRACE",
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        };
+        f.write_str(level)
+    }
+}
+
+// ------ logger ------
+
+type Logger = Box<dyn Fn(Level, &str)>;
+
+thread_local! {
+    static LOGGER: RefCell<Option<Logger>> = RefCell::new(None);
+}
+
+/// Replaces the default sink (browser console) with a custom one.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// logging::set_logger(|level, message| {
+///     if level >= logging::Level::Warn {
+///         // send the message to your backend
+///     }
+///     logging::console_logger(level, message);
+/// });
+/// ```
+pub fn set_logger(logger: impl Fn(Level, &str) + 'static) {
+    LOGGER.with(|current_logger| *current_logger.borrow_mut() = Some(Box::new(logger)));
+}
+
+/// Restores the default sink (browser console).
+pub fn reset_logger() {
+    LOGGER.with(|current_logger| *current_logger.borrow_mut() = None);
+}
+
+pub fn log(level: Level, message: &str) {
+    LOGGER.with(|logger| match logger.try_borrow().as_deref() {
+        Ok(Some(logger)) => logger(level, message),
+        // no custom logger or a message logged from the custom logger itself
+        _ => console_logger(level, message),
+    })
+}
+
+/// The default sink, mapping levels to `console.*` methods.
+pub fn console_logger(level: Level, message: &str) {
+    match level {
+        Level::Trace | Level::Debug => console::debug(message),
+        Level::Info => console::log(message),
+        Level::Warn => console::warn(message),
+        Level::Error => console::error(message),
+    }
+}
+
+// ------ macros ------
+
+#[macro_export]
+macro_rules! ztrace {
+    ($($arg:tt)*) => ($crate::logging::log($crate::logging::Level::Trace, &$crate::format!($($arg)*)))
+}
+
+#[macro_export]
+macro_rules! zdebug {
+    ($($arg:tt)*) => ($crate::logging::log($crate::logging::Level::Debug, &$crate::format!($($arg)*)))
+}
+
+#[macro_export]
+macro_rules! zinfo {
+    ($($arg:tt)*) => ($crate::logging::log($crate::logging::Level::Info, &$crate::format!($($arg)*)))
+}
+
+#[macro_export]
+macro_rules! zwarn {
+    ($($arg:tt)*) => ($crate::logging::log($crate::logging::Level::Warn, &$crate::format!($($arg)*)))
+}
+
+#[macro_export]
+macro_rules! zerror {
+    ($($arg:tt)*) => ($crate::logging::log($crate::logging::Level::Error, &$crate::format!($($arg)*)))
+}
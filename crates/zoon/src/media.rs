@@ -0,0 +1,108 @@
+use crate::watched_state::{watched_state_signal, WatchedState};
+use crate::*;
+use std::{cell::RefCell, rc::Weak};
+
+// ------ Contrast ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Contrast {
+    NoPreference,
+    More,
+    Less,
+    /// E.g. forced colors mode with a palette that is neither high nor low contrast.
+    Custom,
+}
+
+/// The `prefers-contrast` media feature.
+pub fn prefers_contrast() -> Contrast {
+    if matches("(prefers-contrast: more)") {
+        Contrast::More
+    } else if matches("(prefers-contrast: less)") {
+        Contrast::Less
+    } else if matches("(prefers-contrast: custom)") {
+        Contrast::Custom
+    } else {
+        Contrast::NoPreference
+    }
+}
+
+/// Changes when the user changes the contrast preference in the system settings.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let hint = Paragraph::new()
+///     .s(Font::new().color_signal(media::prefers_contrast_signal().map(|contrast| {
+///         match contrast {
+///             media::Contrast::More => hsluv!(0, 0, 0),
+///             _ => hsluv!(0, 0, 40),
+///         }
+///     })))
+///     .content("Optional");
+/// ```
+pub fn prefers_contrast_signal() -> impl Signal<Item = Contrast> {
+    thread_local! {
+        static CONTRAST: RefCell<Weak<WatchedState<Contrast>>> = RefCell::new(Weak::new());
+    }
+    watched_state_signal(&CONTRAST, || {
+        WatchedState::new(
+            prefers_contrast,
+            vec![
+                (media_query_list("(prefers-contrast: more)"), "change"),
+                (media_query_list("(prefers-contrast: less)"), "change"),
+                (media_query_list("(prefers-contrast: custom)"), "change"),
+            ],
+        )
+    })
+}
+
+// ------ forced_colors ------
+
+/// `true` in forced colors mode (e.g. Windows High Contrast),
+/// the browser replaces the page colors with a limited system palette.
+/// See `ForcedColorAdjust` to keep colors where they carry meaning.
+pub fn forced_colors() -> bool {
+    matches("(forced-colors: active)")
+}
+
+/// Changes when forced colors mode is turned on or off.
+pub fn forced_colors_signal() -> impl Signal<Item = bool> {
+    thread_local! {
+        static FORCED_COLORS: RefCell<Weak<WatchedState<bool>>> = RefCell::new(Weak::new());
+    }
+    watched_state_signal(&FORCED_COLORS, || {
+        WatchedState::new(
+            forced_colors,
+            vec![(media_query_list("(forced-colors: active)"), "change")],
+        )
+    })
+}
+
+// ------ prefers_reduced_motion ------
+
+/// The user asked the system to minimize non-essential motion.
+pub fn prefers_reduced_motion() -> bool {
+    matches("(prefers-reduced-motion: reduce)")
+}
+
+// ------ helpers ------
+
+fn matches(query: &str) -> bool {
+    window()
+        .match_media(query)
+        .ok()
+        .flatten()
+        .map(|media_query_list| media_query_list.matches())
+        .unwrap_or_default()
+}
+
+/// Listeners for its `change` event are called when `matches` changes.
+/// Browsers without the media feature return a list that never matches nor changes.
+fn media_query_list(query: &str) -> web_sys::EventTarget {
+    window()
+        .match_media(query)
+        .ok()
+        .flatten()
+        .expect_throw("failed to create MediaQueryList")
+        .into()
+}
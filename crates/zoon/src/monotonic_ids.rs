@@ -4,27 +4,183 @@ use std::{
     sync::{Arc, Mutex, MutexGuard},
 };
 
+/// Maps ids to indices in an ordered collection (e.g. rules in a `CssStyleSheet`)
+/// where items are inserted and removed by their index.
+///
+/// Ids of removed items are reused, so the number of slots is bounded by the peak
+/// number of live ids. Both adding and removing ids are `O(log n)`.
 #[derive(Default)]
 pub struct MonotonicIds {
-    ids: Arc<Mutex<Vec<u32>>>,
+    slots: Arc<Mutex<IdSlots>>,
     generator: IndexGenerator,
 }
 
 impl MonotonicIds {
     /// u32 is both id and index
-    pub fn add_new_id(&self) -> (u32, MutexGuard<Vec<u32>>) {
-        let mut ids = self.ids.lock().unwrap_throw();
+    pub fn add_new_id(&self) -> (u32, MutexGuard<IdSlots>) {
+        let mut slots = self.slots.lock().unwrap_throw();
         let id = self.generator.next_index();
-        ids.insert(usize::try_from(id).unwrap_throw(), id);
-        (id, ids)
+        let index = slots.insert(usize::try_from(id).unwrap_throw());
+        // `IndexGenerator` returns the lowest free id, so all lower ids are live.
+        debug_assert_eq!(usize::try_from(id).unwrap_throw(), index);
+        (id, slots)
     }
 
     /// usize is index
-    pub fn remove_id(&self, id: u32) -> (usize, MutexGuard<Vec<u32>>) {
-        let mut ids = self.ids.lock().unwrap_throw();
+    pub fn remove_id(&self, id: u32) -> (usize, MutexGuard<IdSlots>) {
+        let mut slots = self.slots.lock().unwrap_throw();
         self.generator.remove_index(id);
-        let index = ids.binary_search(&id).unwrap_throw();
-        ids.remove(index);
-        (index, ids)
+        let index = slots.remove(usize::try_from(id).unwrap_throw());
+        (index, slots)
+    }
+}
+
+// ------ IdSlots ------
+
+/// Live ids with a Fenwick tree for fast "how many live ids are lower than `id`" queries.
+#[derive(Default)]
+pub struct IdSlots {
+    live: Vec<bool>,
+    // 1-based Fenwick tree over `live`
+    tree: Vec<u32>,
+}
+
+impl IdSlots {
+    /// Returns the index of the inserted id.
+    fn insert(&mut self, id: usize) -> usize {
+        if id >= self.live.len() {
+            self.grow(id + 1);
+        }
+        assert!(not(self.live[id]), "id {} is already live", id);
+        self.live[id] = true;
+        let mut node = id + 1;
+        while node < self.tree.len() {
+            self.tree[node] += 1;
+            node += lowest_bit(node);
+        }
+        self.live_count_below(id)
+    }
+
+    /// Returns the index of the removed id.
+    fn remove(&mut self, id: usize) -> usize {
+        assert!(
+            self.live.get(id).copied().unwrap_or_default(),
+            "id {} is not live",
+            id
+        );
+        self.live[id] = false;
+        let mut node = id + 1;
+        while node < self.tree.len() {
+            self.tree[node] -= 1;
+            node += lowest_bit(node);
+        }
+        self.live_count_below(id)
+    }
+
+    fn live_count_below(&self, id: usize) -> usize {
+        let mut count = 0;
+        let mut node = id;
+        while node > 0 {
+            count += self.tree[node];
+            node -= lowest_bit(node);
+        }
+        usize::try_from(count).unwrap_throw()
+    }
+
+    fn grow(&mut self, min_len: usize) {
+        let len = min_len.max(self.live.len() * 2);
+        self.live.resize(len, false);
+        self.tree = vec![0; len + 1];
+        for node in 1..=len {
+            self.tree[node] += u32::from(self.live[node - 1]);
+            let parent = node + lowest_bit(node);
+            if parent <= len {
+                self.tree[parent] += self.tree[node];
+            }
+        }
+    }
+}
+
+fn lowest_bit(node: usize) -> usize {
+    node & node.wrapping_neg()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_returns_live_count_below() {
+        let mut slots = IdSlots::default();
+
+        assert_eq!(slots.insert(0), 0);
+        assert_eq!(slots.insert(5), 1);
+        assert_eq!(slots.insert(2), 1);
+        assert_eq!(slots.insert(1), 1);
+
+        assert_eq!(slots.live_count_below(0), 0);
+        assert_eq!(slots.live_count_below(3), 3);
+        assert_eq!(slots.live_count_below(6), 4);
+    }
+
+    #[test]
+    fn test_remove_returns_index_of_removed_id() {
+        // ------ ARRANGE ------
+        let mut slots = IdSlots::default();
+        for id in 0..4 {
+            slots.insert(id);
+        }
+
+        // ------ ACT & ASSERT ------
+        // the last id
+        assert_eq!(slots.remove(3), 3);
+        // the first id
+        assert_eq!(slots.remove(0), 0);
+        assert_eq!(slots.remove(2), 1);
+        assert_eq!(slots.remove(1), 0);
+        assert_eq!(slots.live_count_below(4), 0);
+    }
+
+    #[test]
+    fn test_reinsert_removed_id() {
+        let mut slots = IdSlots::default();
+        for id in 0..3 {
+            slots.insert(id);
+        }
+
+        assert_eq!(slots.remove(1), 1);
+        assert_eq!(slots.insert(1), 1);
+        assert_eq!(slots.live_count_below(3), 3);
+    }
+
+    #[test]
+    fn test_grow_keeps_live_ids() {
+        let mut slots = IdSlots::default();
+        // grows from 1 to 2, 4, 8 and 16 slots
+        for id in 0..16 {
+            assert_eq!(slots.insert(id), id);
+        }
+
+        assert_eq!(slots.live_count_below(16), 16);
+        assert_eq!(slots.remove(15), 15);
+        assert_eq!(slots.remove(7), 7);
+        assert_eq!(slots.insert(100), 14);
+        assert_eq!(slots.live_count_below(101), 15);
+    }
+
+    #[test]
+    #[should_panic(expected = "id 1 is already live")]
+    fn test_insert_live_id() {
+        let mut slots = IdSlots::default();
+        slots.insert(1);
+        slots.insert(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "id 3 is not live")]
+    fn test_remove_id_out_of_bounds() {
+        let mut slots = IdSlots::default();
+        slots.insert(0);
+        slots.remove(3);
     }
 }
@@ -2,7 +2,7 @@ use crate::*;
 use futures_signals::signal_vec::{
     MutableVec as FSMutableVec, MutableVecLockMut, MutableVecLockRef,
 };
-use std::ops::Deref;
+use std::{cmp::Ordering, ops::Deref, rc::Rc};
 
 #[derive(Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -28,6 +28,51 @@ impl<T> MutableVec<T> {
     pub fn use_ref(&self, f: impl FnOnce(&MutableVecLockRef<T>)) {
         f(&self.lock_ref())
     }
+
+    /// Sorted items; the source vec isn't mutated.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let names = MutableVec::new_with_values(vec!["Bob".to_owned(), "Alice".to_owned()]);
+    /// let name_list = Column::new().items_signal_vec(names.sorted_by_signal(Ord::cmp));
+    /// ```
+    pub fn sorted_by_signal(
+        &self,
+        compare: impl FnMut(&T, &T) -> Ordering + 'static,
+    ) -> impl SignalVec<Item = T>
+    where
+        T: Clone + 'static,
+    {
+        self.signal_vec_cloned().sort_by_cloned(compare)
+    }
+
+    /// Items passing the latest predicate. An item is added or removed
+    /// when the source vec or the predicate changes; the source vec isn't mutated.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let names = MutableVec::new_with_values(vec!["Bob".to_owned(), "Alice".to_owned()]);
+    /// let search = Mutable::new(String::new());
+    /// let predicate = search.signal_cloned().map(|search| {
+    ///     move |name: &String| name.to_lowercase().contains(&search.to_lowercase())
+    /// });
+    /// let name_list = Column::new().items_signal_vec(names.filtered_signal(predicate));
+    /// ```
+    pub fn filtered_signal<P: Fn(&T) -> bool + 'static>(
+        &self,
+        predicate: impl Signal<Item = P> + 'static,
+    ) -> impl SignalVec<Item = T>
+    where
+        T: Clone + 'static,
+    {
+        let predicate = Rc::new(Broadcaster::new(predicate));
+        self.signal_vec_cloned().filter_signal_cloned(move |item| {
+            let item = item.clone();
+            predicate.signal_ref(move |predicate| predicate(&item))
+        })
+    }
 }
 
 impl<T> Deref for MutableVec<T> {
@@ -0,0 +1,189 @@
+use crate::*;
+
+// ------ MutationObserver ------
+
+pub struct MutationObserver {
+    observer: web_sys::MutationObserver,
+    _callback: Closure<dyn Fn(js_sys::Array)>,
+}
+
+impl MutationObserver {
+    #[must_use]
+    pub fn new(
+        ws_node: &web_sys::Node,
+        config: &MutationConfig,
+        on_mutation: impl FnOnce(Vec<DomMutation>) + Clone + 'static,
+    ) -> Self {
+        let callback = move |records: js_sys::Array| {
+            let mutations = records
+                .iter()
+                .map(|record| DomMutation::from_record(record.unchecked_into()))
+                .collect();
+            on_mutation.clone()(mutations);
+        };
+        let callback = Closure::wrap(Box::new(callback) as Box<dyn Fn(js_sys::Array)>);
+
+        let observer =
+            web_sys::MutationObserver::new(callback.as_ref().unchecked_ref()).unwrap_throw();
+        observer
+            .observe_with_options(ws_node, &config.to_init())
+            .unwrap_throw();
+        Self {
+            observer,
+            _callback: callback,
+        }
+    }
+}
+
+impl Drop for MutationObserver {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+// ------ MutationConfig ------
+
+/// What changes should be reported by `MutationObserver`.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let config = MutationConfig::new()
+///     .child_list()
+///     .subtree()
+///     .attribute_filter(["class", "style"]);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct MutationConfig {
+    child_list: bool,
+    attributes: bool,
+    character_data: bool,
+    subtree: bool,
+    old_values: bool,
+    attribute_filter: Option<Vec<String>>,
+}
+
+impl MutationConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Report added and removed child nodes.
+    pub fn child_list(mut self) -> Self {
+        self.child_list = true;
+        self
+    }
+
+    /// Report attribute changes.
+    pub fn attributes(mut self) -> Self {
+        self.attributes = true;
+        self
+    }
+
+    /// Report only changes of the given attributes. Implies `attributes`.
+    pub fn attribute_filter<'a>(
+        mut self,
+        names: impl IntoIterator<Item = impl IntoCowStr<'a>>,
+    ) -> Self {
+        self.attributes = true;
+        self.attribute_filter = Some(
+            names
+                .into_iter()
+                .map(|name| name.into_cow_str().into_owned())
+                .collect(),
+        );
+        self
+    }
+
+    /// Report text changes.
+    pub fn character_data(mut self) -> Self {
+        self.character_data = true;
+        self
+    }
+
+    /// Observe all descendants, not only the observed node itself.
+    pub fn subtree(mut self) -> Self {
+        self.subtree = true;
+        self
+    }
+
+    /// Pass previous attribute values and texts to `DomMutation`s.
+    pub fn old_values(mut self) -> Self {
+        self.old_values = true;
+        self
+    }
+
+    fn to_init(&self) -> web_sys::MutationObserverInit {
+        let mut init = web_sys::MutationObserverInit::new();
+        init.child_list(self.child_list)
+            .attributes(self.attributes)
+            .character_data(self.character_data)
+            .subtree(self.subtree);
+        if self.old_values {
+            if self.attributes {
+                init.attribute_old_value(true);
+            }
+            if self.character_data {
+                init.character_data_old_value(true);
+            }
+        }
+        if let Some(attribute_filter) = &self.attribute_filter {
+            let attribute_filter = attribute_filter
+                .iter()
+                .map(|name| JsValue::from_str(name))
+                .collect::<js_sys::Array>();
+            init.attribute_filter(&attribute_filter);
+        }
+        init
+    }
+}
+
+// ------ DomMutation ------
+
+#[derive(Debug, Clone)]
+pub enum DomMutation {
+    ChildList {
+        target: web_sys::Node,
+        added_nodes: Vec<web_sys::Node>,
+        removed_nodes: Vec<web_sys::Node>,
+    },
+    Attribute {
+        target: web_sys::Node,
+        name: String,
+        /// `Some` only with `MutationConfig::old_values`.
+        old_value: Option<String>,
+    },
+    CharacterData {
+        target: web_sys::Node,
+        /// `Some` only with `MutationConfig::old_values`.
+        old_value: Option<String>,
+    },
+}
+
+impl DomMutation {
+    fn from_record(record: web_sys::MutationRecord) -> Self {
+        let target = record.target().unwrap_throw();
+        match record.type_().as_str() {
+            "childList" => Self::ChildList {
+                target,
+                added_nodes: nodes(&record.added_nodes()),
+                removed_nodes: nodes(&record.removed_nodes()),
+            },
+            "attributes" => Self::Attribute {
+                target,
+                name: record.attribute_name().unwrap_throw(),
+                old_value: record.old_value(),
+            },
+            _ => Self::CharacterData {
+                target,
+                old_value: record.old_value(),
+            },
+        }
+    }
+}
+
+fn nodes(node_list: &web_sys::NodeList) -> Vec<web_sys::Node> {
+    (0..node_list.length())
+        .filter_map(|index| node_list.item(index))
+        .collect()
+}
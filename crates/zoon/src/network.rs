@@ -0,0 +1,38 @@
+use crate::watched_state::{watched_state_signal, WatchedState};
+use crate::*;
+use std::{cell::RefCell, rc::Weak};
+
+// ------ is_online ------
+
+/// The current value of `navigator.onLine`.
+///
+/// Note: `true` means only that the device is connected to a network,
+/// not that the internet or your server is reachable.
+pub fn is_online() -> bool {
+    window().navigator().on_line()
+}
+
+// ------ online_signal ------
+
+/// Changes on the `online` and `offline` window events.
+///
+/// The event listeners are shared by all signals, they are added
+/// on the first call and removed once all signals have been dropped.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let offline_banner = El::new()
+///     .child_signal(network::online_signal().map_false(|| "You are offline"));
+/// ```
+pub fn online_signal() -> impl Signal<Item = bool> {
+    thread_local! {
+        static ONLINE: RefCell<Weak<WatchedState<bool>>> = RefCell::new(Weak::new());
+    }
+    watched_state_signal(&ONLINE, || {
+        WatchedState::new(
+            is_online,
+            vec![(window().into(), "online"), (window().into(), "offline")],
+        )
+    })
+}
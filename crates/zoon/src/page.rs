@@ -0,0 +1,58 @@
+use crate::watched_state::{watched_state_signal, WatchedState};
+use crate::*;
+use std::{cell::RefCell, rc::Weak};
+
+// ------ PageVisibility ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageVisibility {
+    Visible,
+    /// E.g. the tab is in the background or the browser window is minimized.
+    Hidden,
+}
+
+pub fn visibility() -> PageVisibility {
+    if document().hidden() {
+        PageVisibility::Hidden
+    } else {
+        PageVisibility::Visible
+    }
+}
+
+/// Changes on the `visibilitychange` document event.
+/// Use it to pause animations, timers or polling in background tabs.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let poller = Task::start_droppable(page::visibility_signal().for_each_sync(|visibility| {
+///     println!("page is {:?}", visibility);
+/// }));
+/// ```
+pub fn visibility_signal() -> impl Signal<Item = PageVisibility> {
+    thread_local! {
+        static VISIBILITY: RefCell<Weak<WatchedState<PageVisibility>>> = RefCell::new(Weak::new());
+    }
+    watched_state_signal(&VISIBILITY, || {
+        WatchedState::new(visibility, vec![(document().into(), "visibilitychange")])
+    })
+}
+
+// ------ focus ------
+
+pub fn is_focused() -> bool {
+    document().has_focus().unwrap_or_default()
+}
+
+/// Changes on the `focus` and `blur` window events.
+pub fn is_focused_signal() -> impl Signal<Item = bool> {
+    thread_local! {
+        static FOCUSED: RefCell<Weak<WatchedState<bool>>> = RefCell::new(Weak::new());
+    }
+    watched_state_signal(&FOCUSED, || {
+        WatchedState::new(
+            is_focused,
+            vec![(window().into(), "focus"), (window().into(), "blur")],
+        )
+    })
+}
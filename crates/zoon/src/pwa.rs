@@ -0,0 +1,215 @@
+use crate::*;
+use std::{cell::RefCell, error::Error, fmt, rc::Rc};
+
+// ------ InstallOutcome ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstallOutcome {
+    Accepted,
+    Dismissed,
+    /// The browser hasn't offered the installation (yet), the app is already installed
+    /// or the prompt has been used already.
+    Unavailable,
+}
+
+// ------ on_install_available ------
+
+/// Called when the browser allows to install the app (the `beforeinstallprompt` window event).
+/// Show your install button in the handler and call `prompt_install` when it's pressed.
+///
+/// The handler is called immediately if the installation is already available.
+/// Call this function early (e.g. before `start_app`), the browser may fire the event
+/// soon after the page load.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let installable = Mutable::new(false);
+/// pwa::on_install_available({
+///     let installable = installable.clone();
+///     move || installable.set_neq(true)
+/// });
+/// let install_button = El::new().child_signal(installable.signal().map_true(move || {
+///     let installable = installable.clone();
+///     Button::new().label("Install").on_press(move || {
+///         let installable = installable.clone();
+///         Task::start(async move {
+///             let outcome = pwa::prompt_install().await;
+///             println!("{:?}", outcome);
+///             installable.set_neq(false);
+///         })
+///     })
+/// }));
+/// ```
+pub fn on_install_available(handler: impl FnOnce() + Clone + 'static) {
+    INSTALL_PROMPT.with(|install_prompt| {
+        if install_prompt.deferred_prompt.borrow().is_some() {
+            handler.clone()();
+        }
+        install_prompt
+            .handlers
+            .borrow_mut()
+            .push(Rc::new(move || handler.clone()()));
+    })
+}
+
+// ------ prompt_install ------
+
+/// Shows the browser's install dialog. Each `beforeinstallprompt` event
+/// allows to show the dialog only once.
+pub async fn prompt_install() -> InstallOutcome {
+    let deferred_prompt =
+        INSTALL_PROMPT.with(|install_prompt| install_prompt.deferred_prompt.borrow_mut().take());
+    let deferred_prompt = match deferred_prompt {
+        Some(deferred_prompt) => deferred_prompt,
+        None => return InstallOutcome::Unavailable,
+    };
+    // `BeforeInstallPromptEvent` is non-standard so it's not available in `web_sys`
+    let prompt = Reflect::get(&deferred_prompt, &"prompt".into())
+        .ok()
+        .and_then(|prompt| prompt.dyn_into::<js_sys::Function>().ok());
+    let prompt = match prompt {
+        Some(prompt) => prompt,
+        None => return InstallOutcome::Unavailable,
+    };
+    if let Err(error) = prompt.call0(&deferred_prompt) {
+        crate::zwarn!("failed to show the install prompt: {:?}", error);
+        return InstallOutcome::Unavailable;
+    }
+    let user_choice = Reflect::get(&deferred_prompt, &"userChoice".into())
+        .ok()
+        .and_then(|user_choice| user_choice.dyn_into::<js_sys::Promise>().ok());
+    let user_choice = match user_choice {
+        Some(user_choice) => JsFuture::from(user_choice).await,
+        None => return InstallOutcome::Unavailable,
+    };
+    let outcome = user_choice
+        .and_then(|user_choice| Reflect::get(&user_choice, &"outcome".into()))
+        .ok()
+        .and_then(|outcome| outcome.as_string());
+    match outcome.as_deref() {
+        Some("accepted") => InstallOutcome::Accepted,
+        Some("dismissed") => InstallOutcome::Dismissed,
+        _ => InstallOutcome::Unavailable,
+    }
+}
+
+// ------ InstallPrompt ------
+
+thread_local! {
+    static INSTALL_PROMPT: InstallPrompt = InstallPrompt::new();
+}
+
+struct InstallPrompt {
+    deferred_prompt: Rc<RefCell<Option<web_sys::Event>>>,
+    handlers: Rc<RefCell<Vec<Rc<dyn Fn()>>>>,
+    _before_install_prompt_listener: Closure<dyn Fn(web_sys::Event)>,
+    _app_installed_listener: Closure<dyn Fn()>,
+}
+
+impl InstallPrompt {
+    fn new() -> Self {
+        let deferred_prompt = Rc::new(RefCell::new(None));
+        let handlers = Rc::new(RefCell::new(Vec::<Rc<dyn Fn()>>::new()));
+
+        let before_install_prompt_listener = Closure::wrap(Box::new({
+            let deferred_prompt = Rc::clone(&deferred_prompt);
+            let handlers = Rc::clone(&handlers);
+            move |event: web_sys::Event| {
+                // prevent the browser's mini-infobar, the prompt is shown by `prompt_install`
+                event.prevent_default();
+                *deferred_prompt.borrow_mut() = Some(event);
+                // handlers may register other handlers
+                let handlers = handlers.borrow().clone();
+                for handler in handlers {
+                    handler();
+                }
+            }
+        })
+            as Box<dyn Fn(web_sys::Event)>);
+        window()
+            .add_event_listener_with_callback(
+                "beforeinstallprompt",
+                before_install_prompt_listener.as_ref().unchecked_ref(),
+            )
+            .unwrap_throw();
+
+        let app_installed_listener = Closure::wrap(Box::new({
+            let deferred_prompt = Rc::clone(&deferred_prompt);
+            move || {
+                deferred_prompt.borrow_mut().take();
+            }
+        }) as Box<dyn Fn()>);
+        window()
+            .add_event_listener_with_callback(
+                "appinstalled",
+                app_installed_listener.as_ref().unchecked_ref(),
+            )
+            .unwrap_throw();
+
+        Self {
+            deferred_prompt,
+            handlers,
+            _before_install_prompt_listener: before_install_prompt_listener,
+            _app_installed_listener: app_installed_listener,
+        }
+    }
+}
+
+// ------ register_service_worker ------
+
+/// Registers the service worker script, e.g. to make the app work offline.
+/// The script's location determines the default scope of the service worker.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// Task::start(async {
+///     if let Err(error) = pwa::register_service_worker("/_api/public/service_worker.js").await {
+///         eprintln!("{}", error);
+///     }
+/// });
+/// ```
+pub async fn register_service_worker(url: &str) -> Result<(), ServiceWorkerError> {
+    let navigator = window().navigator();
+    // `navigator.serviceWorker` is `undefined` on insecure (non-HTTPS) origins
+    if not(Reflect::has(&navigator, &"serviceWorker".into()).unwrap_or_default()) {
+        return Err(ServiceWorkerError::NotSupported);
+    }
+    JsFuture::from(navigator.service_worker().register(url))
+        .await
+        .map(|_| ())
+        .map_err(|error| ServiceWorkerError::RegistrationFailed(js_error_message(error)))
+}
+
+// ------ ServiceWorkerError ------
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServiceWorkerError {
+    /// The browser doesn't provide service workers or the page isn't served over HTTPS.
+    NotSupported,
+    RegistrationFailed(String),
+}
+
+impl fmt::Display for ServiceWorkerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ServiceWorkerError::NotSupported => write!(f, "service workers are not supported"),
+            ServiceWorkerError::RegistrationFailed(message) => {
+                write!(f, "service worker registration failed: {}", message)
+            }
+        }
+    }
+}
+
+impl Error for ServiceWorkerError {}
+
+// ------ helpers ------
+
+fn js_error_message(error: JsValue) -> String {
+    error
+        .dyn_ref::<js_sys::Error>()
+        .map(|error| String::from(error.message()))
+        .or_else(|| error.as_string())
+        .unwrap_or_else(|| std::format!("{:?}", error))
+}
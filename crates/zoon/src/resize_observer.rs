@@ -1,10 +1,22 @@
 use crate::*;
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    rc::Rc,
+};
 
 // ------ ResizeObserver ------
 
+/// Calls the handler with the element's border box size when it's resized.
+///
+/// All `ResizeObserver`s share one native observer. Browsers call it once per frame
+/// (after layout, before paint) with all resized elements, the handlers are called synchronously
+/// in the same callback so their style changes are rendered in the same frame.
+///
+/// The first size is delivered once the element has been laid out. When the element is already
+/// observed by another `ResizeObserver`, its last reported size is delivered in a spawned task.
 pub struct ResizeObserver {
-    observer: native::ResizeObserver,
-    _callback: Closure<dyn Fn(Vec<native::ResizeObserverEntry>)>,
+    id: u32,
 }
 
 impl ResizeObserver {
@@ -13,26 +25,168 @@ impl ResizeObserver {
         ws_element: &web_sys::Element,
         on_resize: impl FnOnce(u32, u32) + Clone + 'static,
     ) -> Self {
-        let on_resize = move |width, height| on_resize.clone()(width, height);
+        let on_resize = Rc::new(move |width, height| on_resize.clone()(width, height));
+        let id =
+            SHARED_OBSERVER.with(|shared_observer| shared_observer.observe(ws_element, on_resize));
+        Self { id }
+    }
+}
+
+impl Drop for ResizeObserver {
+    fn drop(&mut self) {
+        let id = self.id;
+        // the shared observer may have been destroyed already when the thread is being shut down
+        let _ = SHARED_OBSERVER.try_with(|shared_observer| shared_observer.unobserve(id));
+    }
+}
+
+// ------ SharedObserver ------
+
+thread_local! {
+    static SHARED_OBSERVER: SharedObserver = SharedObserver::new();
+}
+
+type ResizeHandler = Rc<dyn Fn(u32, u32)>;
+
+struct Registration {
+    element: web_sys::Element,
+    handler: ResizeHandler,
+}
+
+struct SharedObserver {
+    observer: native::ResizeObserver,
+    _callback: Closure<dyn Fn(Vec<native::ResizeObserverEntry>)>,
+    next_id: Cell<u32>,
+    registrations: Rc<RefCell<BTreeMap<u32, Registration>>>,
+    /// Observed element -> `js_sys::Array` of registration ids.
+    element_ids: js_sys::Map,
+    /// Observed element -> `js_sys::Array` `[width, height]` of the last reported size.
+    element_sizes: js_sys::Map,
+}
 
-        let callback = move |entries: Vec<native::ResizeObserverEntry>| {
-            let entry = &entries[0];
-            let (width, height) = entry_size(&entry);
-            on_resize(width, height);
+impl SharedObserver {
+    fn new() -> Self {
+        let registrations = Rc::new(RefCell::new(BTreeMap::<u32, Registration>::new()));
+        let element_ids = js_sys::Map::new();
+        let element_sizes = js_sys::Map::new();
+
+        let callback = {
+            let element_ids = element_ids.clone();
+            let element_sizes = element_sizes.clone();
+            let registrations = Rc::clone(&registrations);
+            move |entries: Vec<native::ResizeObserverEntry>| {
+                // handlers may create or drop observers
+                let handlers = {
+                    let registrations = registrations.borrow();
+                    let mut handlers = Vec::new();
+                    for entry in &entries {
+                        let ids = element_ids.get(&entry.target());
+                        if ids.is_undefined() {
+                            continue;
+                        }
+                        let size = entry_size(entry);
+                        element_sizes.set(
+                            &entry.target(),
+                            &js_sys::Array::of2(&size.0.into(), &size.1.into()),
+                        );
+                        for id in ids.unchecked_into::<js_sys::Array>().iter() {
+                            let id = id.as_f64().unwrap_throw() as u32;
+                            if let Some(registration) = registrations.get(&id) {
+                                handlers.push((Rc::clone(&registration.handler), size));
+                            }
+                        }
+                    }
+                    handlers
+                };
+                for (handler, (width, height)) in handlers {
+                    handler(width, height);
+                }
+            }
         };
         let callback =
             Closure::wrap(Box::new(callback) as Box<dyn Fn(Vec<native::ResizeObserverEntry>)>);
 
-        let observer = native::ResizeObserver::new(callback.as_ref().unchecked_ref());
-        observer.observe(ws_element);
         Self {
-            observer,
+            observer: native::ResizeObserver::new(callback.as_ref().unchecked_ref()),
             _callback: callback,
+            next_id: Cell::new(0),
+            registrations,
+            element_ids,
+            element_sizes,
+        }
+    }
+
+    fn observe(&self, element: &web_sys::Element, handler: ResizeHandler) -> u32 {
+        let id = self.next_id.get();
+        self.next_id.set(id.wrapping_add(1));
+
+        let ids = self.element_ids.get(element);
+        if ids.is_undefined() {
+            self.element_ids
+                .set(element, &js_sys::Array::of1(&id.into()));
+            self.observer.observe(element);
+        } else {
+            ids.unchecked_into::<js_sys::Array>().push(&id.into());
+            // The native observer reports the initial size only when the observation starts.
+            // Without a reported size, the pending initial entry reaches the new handler as well.
+            let size = self.element_sizes.get(element);
+            if not(size.is_undefined()) {
+                self.deliver_last_size(id, size.unchecked_into());
+            }
+        }
+
+        self.registrations.borrow_mut().insert(
+            id,
+            Registration {
+                element: element.clone(),
+                handler,
+            },
+        );
+        id
+    }
+
+    fn deliver_last_size(&self, id: u32, size: js_sys::Array) {
+        let width = size.get(0).as_f64().unwrap_throw() as u32;
+        let height = size.get(1).as_f64().unwrap_throw() as u32;
+        let registrations = Rc::downgrade(&self.registrations);
+        Task::start(async move {
+            let handler = registrations.upgrade().and_then(|registrations| {
+                let registrations = registrations.borrow();
+                let registration = registrations.get(&id)?;
+                Some(Rc::clone(&registration.handler))
+            });
+            if let Some(handler) = handler {
+                handler(width, height);
+            }
+        });
+    }
+
+    fn unobserve(&self, id: u32) {
+        let registration = match self.registrations.borrow_mut().remove(&id) {
+            Some(registration) => registration,
+            None => return,
+        };
+
+        let element = registration.element;
+        let ids = self.element_ids.get(&element);
+        if ids.is_undefined() {
+            return;
+        }
+        let id = JsValue::from(id);
+        let ids = ids
+            .unchecked_into::<js_sys::Array>()
+            .filter(&mut |other_id, _, _| other_id != id);
+        if ids.length() == 0 {
+            self.element_ids.delete(&element);
+            self.element_sizes.delete(&element);
+            self.observer.unobserve(&element);
+        } else {
+            self.element_ids.set(&element, &ids);
         }
     }
 }
 
-impl Drop for ResizeObserver {
+impl Drop for SharedObserver {
     fn drop(&mut self) {
         self.observer.disconnect();
     }
@@ -85,10 +239,16 @@ mod native {
         #[wasm_bindgen(method)]
         pub fn observe(this: &ResizeObserver, target: &Element);
 
+        #[wasm_bindgen(method)]
+        pub fn unobserve(this: &ResizeObserver, target: &Element);
+
         // ------ ResizeObserverEntry ------
 
         pub type ResizeObserverEntry;
 
+        #[wasm_bindgen(method, getter)]
+        pub fn target(this: &ResizeObserverEntry) -> Element;
+
         #[wasm_bindgen(method, getter, js_name = "borderBoxSize")]
         pub fn border_box_size(this: &ResizeObserverEntry) -> JsValue;
 
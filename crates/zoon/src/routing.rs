@@ -1,10 +1,12 @@
 use crate::*;
 
 mod from_route_segments;
+mod query_params;
 mod route_segment;
 mod router;
 
 pub use from_route_segments::FromRouteSegments;
+pub use query_params::{query_params_signal, QueryParams};
 pub use route_segment::RouteSegment;
 pub use router::Router;
 
@@ -20,6 +22,84 @@ pub fn back() {
     history().back().unwrap_throw();
 }
 
+pub fn forward() {
+    history().forward().unwrap_throw();
+}
+
+// ------ scroll restoration ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollRestoration {
+    /// The browser restores the scroll position on history navigation (default).
+    Auto,
+    /// The app restores the scroll position itself,
+    /// e.g. with `save_scroll_position` and `restore_scroll_position`.
+    Manual,
+}
+
+pub fn scroll_restoration(scroll_restoration: ScrollRestoration) {
+    let scroll_restoration = match scroll_restoration {
+        ScrollRestoration::Auto => web_sys::ScrollRestoration::Auto,
+        ScrollRestoration::Manual => web_sys::ScrollRestoration::Manual,
+    };
+    history()
+        .set_scroll_restoration(scroll_restoration)
+        .unwrap_throw();
+}
+
+/// Stores the window's scroll position in the current history entry's state.
+/// Call it before navigating to another route.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// routing::scroll_restoration(routing::ScrollRestoration::Manual);
+///
+/// // before navigating to another route
+/// routing::save_scroll_position();
+///
+/// // after the page of the current route has been rendered
+/// if !routing::restore_scroll_position() {
+///     window().scroll_to_with_x_and_y(0., 0.);
+/// }
+/// ```
+pub fn save_scroll_position() {
+    let window = window();
+    let state = js_sys::Object::new();
+    Reflect::set(
+        &state,
+        &SCROLL_X_KEY.into(),
+        &window.scroll_x().unwrap_throw().into(),
+    )
+    .unwrap_throw();
+    Reflect::set(
+        &state,
+        &SCROLL_Y_KEY.into(),
+        &window.scroll_y().unwrap_throw().into(),
+    )
+    .unwrap_throw();
+    history()
+        .replace_state_with_url(&state, "", None)
+        .unwrap_throw();
+}
+
+/// Scrolls the window to the position stored by `save_scroll_position`
+/// in the current history entry. Returns `false` if there is no stored position.
+pub fn restore_scroll_position() -> bool {
+    let state = history().state().unwrap_throw();
+    let position = |key: &str| Reflect::get(&state, &key.into()).ok()?.as_f64();
+    match (position(SCROLL_X_KEY), position(SCROLL_Y_KEY)) {
+        (Some(x), Some(y)) => {
+            window().scroll_to_with_x_and_y(x, y);
+            true
+        }
+        _ => false,
+    }
+}
+
+const SCROLL_X_KEY: &str = "zoon_scroll_x";
+const SCROLL_Y_KEY: &str = "zoon_scroll_y";
+
 pub fn decode_uri_component(component: impl AsRef<str>) -> Result<String, JsValue> {
     let decoded = js_sys::decode_uri_component(component.as_ref())?;
     Ok(String::from(decoded))
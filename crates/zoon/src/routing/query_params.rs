@@ -0,0 +1,157 @@
+use crate::{
+    routing::{decode_uri_component, encode_uri_component},
+    *,
+};
+use once_cell::race::OnceBox;
+use std::fmt;
+
+// ------ QueryParams ------
+
+/// URL query parameters, e.g. `page=2&tag=rust&tag=web` in `/articles?page=2&tag=rust&tag=web`.
+///
+/// Keys and values are percent-decoded when parsed and percent-encoded by `to_string`.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let mut query_params = QueryParams::current();
+/// let page: u32 = query_params.get("page").and_then(|page| page.parse().ok()).unwrap_or(1);
+/// query_params.set("page", (page + 1).to_string());
+/// // and then `router.set_query_params(&query_params);`
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryParams {
+    params: Vec<(String, String)>,
+}
+
+impl QueryParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Query parameters of the current URL.
+    pub fn current() -> Self {
+        Self::parse(&window().location().search().unwrap_throw())
+    }
+
+    /// Parses a query string with or without the leading `?`.
+    /// Pairs that can't be decoded are skipped.
+    pub fn parse(query: &str) -> Self {
+        let params = query
+            .trim_start_matches('?')
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .filter_map(|pair| {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                Some((decode_query_component(key)?, decode_query_component(value)?))
+            })
+            .collect();
+        Self { params }
+    }
+
+    /// The first value of the `key`.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param_key, _)| param_key == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn get_all(&self, key: &str) -> Vec<&str> {
+        self.params
+            .iter()
+            .filter(|(param_key, _)| param_key == key)
+            .map(|(_, value)| value.as_str())
+            .collect()
+    }
+
+    /// Replaces all values of the `key`.
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        let key = key.into();
+        let value = value.into();
+        let first_index = self
+            .params
+            .iter()
+            .position(|(param_key, _)| *param_key == key);
+        self.params.retain(|(param_key, _)| *param_key != key);
+        match first_index {
+            Some(index) => self.params.insert(index, (key, value)),
+            None => self.params.push((key, value)),
+        }
+        self
+    }
+
+    /// Adds another value of the `key`.
+    pub fn append(&mut self, key: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        self.params.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn remove(&mut self, key: &str) -> &mut Self {
+        self.params.retain(|(param_key, _)| param_key != key);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.params
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+}
+
+/// Without the leading `?`.
+impl fmt::Display for QueryParams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (key, value)) in self.params.iter().enumerate() {
+            if index > 0 {
+                f.write_str("&")?;
+            }
+            write!(
+                f,
+                "{}={}",
+                encode_uri_component(key),
+                encode_uri_component(value)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// ------ query_params_signal ------
+
+/// Changes when the URL's query is changed by a `Router` or by history navigation
+/// (the `popstate` event), it doesn't need a `Router`.
+/// Direct `history().push_state(..)` calls aren't observed.
+pub fn query_params_signal() -> impl Signal<Item = QueryParams> {
+    current_query_params().signal_cloned()
+}
+
+pub(super) fn refresh_query_params() {
+    current_query_params().set_neq(QueryParams::current());
+}
+
+fn current_query_params() -> &'static Mutable<QueryParams> {
+    static QUERY_PARAMS: OnceBox<Mutable<QueryParams>> = OnceBox::new();
+    QUERY_PARAMS.get_or_init(|| {
+        // The listener lives as long as the app, like the `Mutable`.
+        let popstate_listener = Closure::wrap(Box::new(refresh_query_params) as Box<dyn Fn()>);
+        window()
+            .add_event_listener_with_callback(
+                "popstate",
+                popstate_listener.as_ref().unchecked_ref(),
+            )
+            .unwrap_throw();
+        popstate_listener.forget();
+        Box::new(Mutable::new(QueryParams::current()))
+    })
+}
+
+// ------ helpers ------
+
+fn decode_query_component(component: &str) -> Option<String> {
+    decode_uri_component(component.replace('+', " ")).ok()
+}
@@ -1,4 +1,7 @@
-use crate::{routing::decode_uri_component, *};
+use crate::{
+    routing::{decode_uri_component, query_params::refresh_query_params, QueryParams},
+    *,
+};
 use futures_signals::signal::{channel, Sender};
 use std::marker::PhantomData;
 use web_sys::MouseEvent;
@@ -32,6 +35,26 @@ impl<R: FromRouteSegments> Router<R> {
     pub fn replace<'a>(&self, with: impl IntoCowStr<'a>) {
         replace(&self.url_change_sender, with);
     }
+
+    /// Pushes the current path with the new query to the history.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// fn show_active<R: FromRouteSegments>(router: &Router<R>) {
+    ///     let mut query_params = QueryParams::current();
+    ///     query_params.set("filter", "active");
+    ///     router.set_query_params(&query_params);
+    /// }
+    /// ```
+    pub fn set_query_params(&self, query_params: &QueryParams) {
+        go(&self.url_change_sender, url_with_query(query_params));
+    }
+
+    /// Replaces the current history entry with the current path and the new query.
+    pub fn replace_query_params(&self, query_params: &QueryParams) {
+        replace(&self.url_change_sender, url_with_query(query_params));
+    }
 }
 
 impl<R> Drop for Router<R> {
@@ -76,6 +99,7 @@ fn go<'a>(url_change_sender: &UrlChangeSender, to: impl IntoCowStr<'a>) {
     history()
         .push_state_with_url(&JsValue::NULL, "", Some(&to))
         .unwrap_throw();
+    refresh_query_params();
     url_change_sender
         .send(current_url_segments())
         .unwrap_throw();
@@ -89,11 +113,23 @@ fn replace<'a>(url_change_sender: &UrlChangeSender, with: impl IntoCowStr<'a>) {
     history()
         .replace_state_with_url(&JsValue::NULL, "", Some(&with))
         .unwrap_throw();
+    refresh_query_params();
     url_change_sender
         .send(current_url_segments())
         .unwrap_throw();
 }
 
+fn url_with_query(query_params: &QueryParams) -> String {
+    let location = window().location();
+    let mut url = location.pathname().unwrap_throw();
+    if !query_params.is_empty() {
+        url.push('?');
+        url.push_str(&query_params.to_string());
+    }
+    url.push_str(&location.hash().unwrap_throw());
+    url
+}
+
 fn current_url_segments() -> Option<Vec<String>> {
     let path = window().location().pathname().unwrap_throw();
     let mut segments = Vec::new();
@@ -115,6 +151,7 @@ fn current_url_segments() -> Option<Vec<String>> {
 
 fn setup_popstate_listener(url_change_sender: UrlChangeSender) -> SendWrapper<Closure<dyn Fn()>> {
     let closure = Closure::wrap(Box::new(move || {
+        refresh_query_params();
         url_change_sender
             .send(current_url_segments())
             .unwrap_throw();
@@ -0,0 +1,167 @@
+use crate::*;
+
+// ------ RovingFocus ------
+
+/// Keyboard navigation for composite widgets like menus, listboxes or toolbars
+/// ([roving tabindex](https://www.w3.org/WAI/ARIA/apg/practices/keyboard-interface/#kbd_roving_tabindex)).
+///
+/// Only the active item has `tabindex="0"`, so the whole group is a single Tab stop.
+/// Arrow keys move the focus to the previous / next item, `Home` and `End`
+/// to the first / last item. A clicked item becomes active.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let options = ["Copy", "Paste", "Delete"];
+/// let roving_focus = RovingFocus::new(options.len());
+/// let menu = Column::new()
+///     .update_raw_el(|raw_el| raw_el.attr("role", "menu"))
+///     .items(options.into_iter().enumerate().map(|(index, option)| {
+///         roving_focus.item(
+///             index,
+///             Button::new()
+///                 .update_raw_el(|raw_el| raw_el.attr("role", "menuitem"))
+///                 .label(option)
+///                 .on_press(move || println!("{}", option)),
+///         )
+///     }));
+/// let active_option = Text::with_signal(
+///     roving_focus.active_index_signal().map(move |index| options[index]),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct RovingFocus {
+    active_index: Mutable<usize>,
+    item_count: Mutable<usize>,
+    orientation: Orientation,
+    wrap: bool,
+    /// `true` after a keyboard navigation, so the active item isn't focused on page load.
+    navigated: Mutable<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Orientation {
+    Vertical,
+    Horizontal,
+    Both,
+}
+
+impl RovingFocus {
+    /// Vertical navigation (`ArrowUp` and `ArrowDown`) without wrapping, the first item is active.
+    pub fn new(item_count: usize) -> Self {
+        Self {
+            active_index: Mutable::new(0),
+            item_count: Mutable::new(item_count),
+            orientation: Orientation::Vertical,
+            wrap: false,
+            navigated: Mutable::new(false),
+        }
+    }
+
+    /// Navigate by `ArrowLeft` and `ArrowRight`, e.g. in a toolbar or a tab list.
+    pub fn horizontal(mut self) -> Self {
+        self.orientation = Orientation::Horizontal;
+        self
+    }
+
+    /// Navigate by all arrow keys.
+    pub fn both_directions(mut self) -> Self {
+        self.orientation = Orientation::Both;
+        self
+    }
+
+    /// The last item is followed by the first one and vice versa.
+    pub fn wrap(mut self) -> Self {
+        self.wrap = true;
+        self
+    }
+
+    /// Update the number of items, e.g. when they are loaded or filtered.
+    /// The active index is moved to the last item when it's out of range.
+    pub fn set_item_count(&self, item_count: usize) {
+        self.item_count.set_neq(item_count);
+        let last_index = item_count.saturating_sub(1);
+        if self.active_index.get() > last_index {
+            self.active_index.set(last_index);
+        }
+    }
+
+    pub fn active_index(&self) -> usize {
+        self.active_index.get()
+    }
+
+    pub fn active_index_signal(&self) -> impl Signal<Item = usize> {
+        self.active_index.signal()
+    }
+
+    /// Activates and focuses the item.
+    pub fn set_active_index(&self, index: usize) {
+        self.navigated.set_neq(true);
+        self.active_index.set_neq(index);
+    }
+
+    /// Makes the element the item with the given index.
+    pub fn item<E>(&self, index: usize, element: E) -> E
+    where
+        E: Focusable + KeyboardEventAware,
+        <E::RawEl as RawEl>::DomElement: AsRef<web_sys::HtmlElement>,
+    {
+        let this = self.clone();
+        let focus = map_ref! {
+            let active_index = self.active_index.signal(),
+            let navigated = self.navigated.signal() =>
+            *navigated && *active_index == index
+        };
+        let tab_index = self
+            .active_index
+            .signal()
+            .map(move |active_index| if active_index == index { 0 } else { -1 });
+        let active_index = self.active_index.clone();
+        element
+            .tab_index_signal(tab_index)
+            .focus_signal(focus.dedupe())
+            .on_focus(move || active_index.set_neq(index))
+            .on_key_down_event(move |event| {
+                if let Some(new_index) = this.target_index(index, event.key()) {
+                    if let RawKeyboardEvent::KeyDown(raw_event) = &event.raw_event {
+                        // prevent page scrolling
+                        raw_event.prevent_default();
+                    }
+                    this.set_active_index(new_index);
+                }
+            })
+    }
+
+    fn target_index(&self, index: usize, key: &Key) -> Option<usize> {
+        let item_count = self.item_count.get();
+        if item_count == 0 {
+            return None;
+        }
+        let last_index = item_count - 1;
+        let vertical = self.orientation != Orientation::Horizontal;
+        let horizontal = self.orientation != Orientation::Vertical;
+        let previous = || match index.checked_sub(1) {
+            Some(previous_index) => previous_index,
+            None if self.wrap => last_index,
+            None => index,
+        };
+        let next = || match index + 1 {
+            next_index if next_index <= last_index => next_index,
+            _ if self.wrap => 0,
+            _ => index,
+        };
+        let key = match key {
+            Key::Other(key) => key.as_str(),
+            _ => return None,
+        };
+        match key {
+            "ArrowUp" if vertical => Some(previous()),
+            "ArrowLeft" if horizontal => Some(previous()),
+            "ArrowDown" if vertical => Some(next()),
+            "ArrowRight" if horizontal => Some(next()),
+            "Home" => Some(0),
+            "End" => Some(last_index),
+            _ => None,
+        }
+    }
+}
@@ -4,10 +4,13 @@ use std::{
     borrow::Cow,
     collections::{BTreeMap, BTreeSet},
     convert::TryFrom,
-    iter, mem,
-    sync::Arc,
+    error::Error,
+    fmt, iter, mem,
+    sync::{Arc, Mutex},
+};
+use web_sys::{
+    CssGroupingRule, CssStyleDeclaration, CssStyleRule, CssStyleSheet, HtmlStyleElement,
 };
-use web_sys::{CssStyleDeclaration, CssStyleRule, CssStyleSheet, HtmlStyleElement};
 
 pub mod named_color;
 
@@ -17,30 +20,63 @@ pub use align::Align;
 mod background;
 pub use background::Background;
 
+mod blend;
+pub use blend::{Blend, BlendMode};
+
 mod borders;
 pub use borders::{Border, Borders};
 
 mod clip;
 pub use clip::Clip;
 
+mod clip_path;
+pub use clip_path::ClipPath;
+
+mod columns;
+pub use columns::Columns;
+
+mod content_align;
+pub use content_align::ContentAlign;
+
 mod cursor;
 pub use cursor::{Cursor, CursorIcon};
 
+mod fixed;
+pub use fixed::Fixed;
+
 mod font;
 pub use font::{Font, FontFamily, FontLine, FontWeight};
 
+mod forced_color_adjust;
+pub use forced_color_adjust::ForcedColorAdjust;
+
 mod height;
 pub use height::Height;
 
 mod layer_index;
 pub use layer_index::LayerIndex;
 
+mod mask;
+pub use mask::Mask;
+
+mod opacity;
+pub use opacity::Opacity;
+
+mod order;
+pub use order::Order;
+
 mod padding;
 pub use padding::Padding;
 
+mod resize;
+pub use resize::Resize;
+
 mod rounded_corners;
 pub use rounded_corners::{IntoOptionRadius, Radius, RoundedCorners};
 
+mod scroll_snap;
+pub use scroll_snap::{ScrollSnap, SnapAlign, SnapAxis, SnapStrictness};
+
 mod scrollbars;
 pub use scrollbars::Scrollbars;
 
@@ -103,6 +139,19 @@ impl<'a> StaticCSSProps<'a> {
     }
 }
 
+impl<'a> StaticCSSProps<'a> {
+    /// Css declarations in a deterministic order, e.g. `color:red;margin:0!important;`.
+    fn to_declarations(&self) -> String {
+        self.0
+            .iter()
+            .map(|(name, CssPropValue { value, important })| {
+                let priority = if *important { "!important" } else { "" };
+                [*name, ":", &**value, priority, ";"].concat()
+            })
+            .collect()
+    }
+}
+
 impl<'a> IntoIterator for StaticCSSProps<'a> {
     type Item = (&'a str, CssPropValue<'a>);
     type IntoIter = std::collections::btree_map::IntoIter<&'a str, CssPropValue<'a>>;
@@ -122,6 +171,34 @@ impl<'a> Extend<(&'a str, CssPropValue<'a>)> for StaticCSSProps<'a> {
 
 pub type DynamicCSSProps = BTreeMap<Cow<'static, str>, BoxedCssSignal>;
 
+/// Precedence: a dynamic prop overrides the static prop with the same name.
+/// When its signal yields `None`, the static value is restored instead of removing the prop.
+///
+/// Note: The restored value loses the `!important` flag.
+pub(crate) fn with_static_fallbacks(
+    dynamic_css_props: DynamicCSSProps,
+    static_css_props: &StaticCSSProps,
+) -> DynamicCSSProps {
+    dynamic_css_props
+        .into_iter()
+        .map(|(name, value)| match static_css_props.0.get(&*name) {
+            Some(CssPropValue {
+                value: fallback, ..
+            }) => {
+                let fallback = fallback.to_string();
+                let value = value.map(move |value| {
+                    let value = value
+                        .into_option_cow_str()
+                        .unwrap_or_else(|| Cow::Owned(fallback.clone()));
+                    Box::new(value) as Box<dyn IntoOptionCowStr<'static>>
+                });
+                (name, Box::new(value) as BoxedCssSignal)
+            }
+            None => (name, value),
+        })
+        .collect()
+}
+
 // ------ BoxedCssSignal ------
 
 pub type BoxedCssSignal = Box<dyn Signal<Item = Box<dyn IntoOptionCowStr<'static>>> + Unpin>;
@@ -135,7 +212,7 @@ pub fn box_css_signal(
 
 // ------ StaticCSSClasses ------
 
-pub type StaticCSSClasses<'a> = BTreeSet<&'a str>;
+pub type StaticCSSClasses<'a> = BTreeSet<Cow<'a, str>>;
 
 // ------ DynamicCSSClasses ------
 
@@ -165,6 +242,59 @@ pub trait Style<'a>: Default {
     fn merge_with_group(self, group: StyleGroup<'a>) -> StyleGroup<'a>;
 }
 
+// ------ BoxedStyle ------
+
+/// A type-erased [Style] for lists of styles built at runtime.
+/// A `Vec<BoxedStyle>` is a [Style] as well.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let highlighted = true;
+/// let mut styles = vec![BoxedStyle::new(Width::new(100))];
+/// if highlighted {
+///     styles.push(BoxedStyle::new(Background::new().color(hsluv!(0, 100, 50))));
+/// }
+/// let el = El::new().s(styles);
+/// ```
+#[derive(Default)]
+pub struct BoxedStyle<'a>(Option<Box<dyn DynStyle<'a> + 'a>>);
+
+impl<'a> BoxedStyle<'a> {
+    pub fn new(style: impl Style<'a> + 'a) -> Self {
+        Self(Some(Box::new(style)))
+    }
+}
+
+impl<'a> Style<'a> for BoxedStyle<'a> {
+    fn merge_with_group(self, group: StyleGroup<'a>) -> StyleGroup<'a> {
+        match self.0 {
+            Some(style) => style.merge_boxed_with_group(group),
+            None => group,
+        }
+    }
+}
+
+impl<'a> Style<'a> for Vec<BoxedStyle<'a>> {
+    fn merge_with_group(self, group: StyleGroup<'a>) -> StyleGroup<'a> {
+        self.into_iter()
+            .fold(group, |group, style| style.merge_with_group(group))
+    }
+}
+
+// "Helper trait" to call `merge_with_group` on `Box<dyn Style>`
+// (the `Style` trait isn't object-safe because of `Default` and `self` receivers).
+#[doc(hidden)]
+pub trait DynStyle<'a> {
+    fn merge_boxed_with_group(self: Box<Self>, group: StyleGroup<'a>) -> StyleGroup<'a>;
+}
+
+impl<'a, T: Style<'a>> DynStyle<'a> for T {
+    fn merge_boxed_with_group(self: Box<Self>, group: StyleGroup<'a>) -> StyleGroup<'a> {
+        (*self).merge_with_group(group)
+    }
+}
+
 // ------ StyleGroup ------
 
 /// Css styles that can be added on a raw html element or globally with a
@@ -173,6 +303,10 @@ pub trait Style<'a>: Default {
 pub struct StyleGroup<'a> {
     /// The `css selector` where the styles apply.
     pub selector: Cow<'a, str>,
+    /// See [StyleGroup::layer].
+    pub(crate) layer: Option<Cow<'a, str>>,
+    /// See [StyleGroup::shared].
+    pub(crate) shared: bool,
     pub static_css_props: StaticCSSProps<'a>,
     pub dynamic_css_props: DynamicCSSProps,
     // --- not applicable to global styles (only directly to elements) ---
@@ -218,6 +352,97 @@ impl<'a> StyleGroup<'a> {
         }
     }
 
+    /// Targets the direct children of the element the group is applied to
+    /// (the selector ` > *`). Combine it with `nth_child`, `first_child` or `last_child`.
+    /// # Example
+    /// Zebra stripes.
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let rows = Column::new()
+    ///     .update_raw_el(|raw_el| {
+    ///         raw_el.style_group(
+    ///             StyleGroup::children()
+    ///                 .nth_child(NthChild::Even)
+    ///                 .style("background", "whitesmoke"),
+    ///         )
+    ///     })
+    ///     .items((1..=10).map(|index| El::new().child(index)));
+    /// ```
+    pub fn children() -> Self {
+        Self::new(" > *")
+    }
+
+    /// Appends the `:nth-child` pseudo-class to the selector.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// global_styles()
+    ///     .style_group(StyleGroup::new("tr").nth_child(NthChild::Odd).style("background", "whitesmoke"))
+    ///     .style_group(StyleGroup::new("li").nth_child(NthChild::Formula(3, 1)).style("clear", "left"));
+    /// ```
+    pub fn nth_child(self, nth_child: NthChild) -> Self {
+        self.pseudo_class(&["nth-child(", &nth_child.formula(), ")"].concat())
+    }
+
+    /// Appends the `:first-child` pseudo-class to the selector.
+    pub fn first_child(self) -> Self {
+        self.pseudo_class("first-child")
+    }
+
+    /// Appends the `:last-child` pseudo-class to the selector.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let list = Column::new()
+    ///     .update_raw_el(|raw_el| {
+    ///         raw_el.style_group(
+    ///             StyleGroup::children().last_child().style("border-bottom", "none"),
+    ///         )
+    ///     })
+    ///     .items(["A", "B", "C"].into_iter().map(|item| {
+    ///         El::new().s(Borders::new().bottom(Border::new())).child(item)
+    ///     }));
+    /// ```
+    pub fn last_child(self) -> Self {
+        self.pseudo_class("last-child")
+    }
+
+    fn pseudo_class(mut self, pseudo_class: &str) -> Self {
+        self.selector = [&*self.selector, ":", pseudo_class].concat().into();
+        self
+    }
+
+    /// Puts the rule into the css cascade layer, see [GlobalStyles::layer].
+    /// Ignored when the group is applied directly to an element (with an empty selector).
+    pub fn layer(mut self, layer: impl IntoCowStr<'a>) -> Self {
+        self.layer = Some(layer.into_cow_str());
+        self
+    }
+
+    /// Elements with identical shared groups use one rule with a shared class
+    /// instead of a rule per element, e.g. items of long lists.
+    /// Ignored when the group contains dynamic styles
+    /// or when it's applied directly to an element (with an empty selector).
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let items = (0..1000).map(|index| {
+    ///     El::new()
+    ///         .update_raw_el(|raw_el| {
+    ///             raw_el.style_group(StyleGroup::new(":hover").style("color", "blue").shared())
+    ///         })
+    ///         .child(index)
+    /// });
+    /// ```
+    pub fn shared(mut self) -> Self {
+        self.shared = true;
+        self
+    }
+
     /// Add a css a property to a specific selector with a `key` and `value`.
     /// # Example
     /// ```no_run
@@ -251,6 +476,7 @@ impl<'a> StyleGroup<'a> {
     }
 
     /// Update the group style depending of the signal's state.
+    /// It overrides a static style with the same name and falls back to it when the signal yields `None`.
     /// ```no_run
     /// use zoon::*;
     ///
@@ -275,8 +501,8 @@ impl<'a> StyleGroup<'a> {
         self
     }
 
-    pub fn class(mut self, class: &'a str) -> Self {
-        self.static_css_classes.insert(class);
+    pub fn class(mut self, class: impl IntoCowStr<'a>) -> Self {
+        self.static_css_classes.insert(class.into_cow_str());
         self
     }
 
@@ -299,21 +525,168 @@ impl<'a> StyleGroup<'a> {
         }));
         self
     }
+
+    /// Layers `other` on top of `self`. The selector and the cascade layer of `self` are kept.
+    /// - A prop or class from `other` replaces the one with the same name in `self`.
+    /// - A static prop from `other` removes the dynamic prop with the same name from `self`
+    ///   so the later value really wins.
+    /// - A dynamic prop from `other` keeps the static prop from `self` as its fallback.
+    /// - Resize handlers from both groups are called.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// fn card<'a>() -> StyleGroup<'a> {
+    ///     StyleGroup::default().style("padding", "10px").style("background", "white")
+    /// }
+    ///
+    /// fn elevated<'a>() -> StyleGroup<'a> {
+    ///     StyleGroup::default().style("box-shadow", "0 2px 6px gray")
+    /// }
+    ///
+    /// let el = El::new().update_raw_el(|raw_el| raw_el.style_group(card().merge(elevated())));
+    /// ```
+    pub fn merge(mut self, other: StyleGroup<'a>) -> Self {
+        let StyleGroup {
+            selector: _,
+            layer: _,
+            shared: _,
+            static_css_props,
+            dynamic_css_props,
+            static_css_classes,
+            dynamic_css_classes,
+            resize_handlers,
+        } = other;
+
+        for name in static_css_props.0.keys() {
+            self.dynamic_css_props.remove(*name);
+        }
+        self.static_css_props.extend(static_css_props);
+        self.dynamic_css_props.extend(dynamic_css_props);
+
+        for class in &static_css_classes {
+            self.dynamic_css_classes.remove(&**class);
+        }
+        for class in dynamic_css_classes.keys() {
+            self.static_css_classes.remove(&**class);
+        }
+        self.static_css_classes.extend(static_css_classes);
+        self.dynamic_css_classes.extend(dynamic_css_classes);
+
+        self.resize_handlers.extend(resize_handlers);
+        self
+    }
+}
+
+impl<'a> Extend<StyleGroup<'a>> for StyleGroup<'a> {
+    fn extend<T: IntoIterator<Item = StyleGroup<'a>>>(&mut self, groups: T) {
+        for group in groups {
+            *self = mem::take(self).merge(group);
+        }
+    }
+}
+
+// ------ NthChild ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NthChild {
+    Even,
+    Odd,
+    /// `Formula(a, b)` selects the elements at the positions `a*n + b` for `n` = 0, 1, 2, ...,
+    /// e.g. `Formula(3, 1)` selects the 1st, 4th, 7th, ... element.
+    Formula(i32, i32),
+}
+
+impl NthChild {
+    fn formula(self) -> Cow<'static, str> {
+        match self {
+            Self::Even => "even".into(),
+            Self::Odd => "odd".into(),
+            Self::Formula(a, b) => {
+                let sign = if b < 0 { "" } else { "+" };
+                [&a.to_string(), "n", sign, &b.to_string()].concat().into()
+            }
+        }
+    }
 }
 
 // ------ StyleGroupHandle ------
 
+/// Removes the rule from the global stylesheet on drop.
 pub struct StyleGroupHandle {
-    rule_id: u32,
+    // `None` when the rule hasn't been inserted because of an invalid selector
+    rule_id: Option<u32>,
+    selector: String,
     _task_handles: Vec<TaskHandle>,
 }
 
+impl StyleGroupHandle {
+    /// The id of the rule in the global stylesheet,
+    /// `None` when the rule hasn't been inserted because of an invalid selector.
+    pub fn rule_id(&self) -> Option<u32> {
+        self.rule_id
+    }
+
+    /// The selector of the inserted rule, e.g. `._12:hover` for a group set on an element.
+    pub fn selector(&self) -> &str {
+        &self.selector
+    }
+
+    /// The class the selector starts with, e.g. `_12` for the selector `._12:hover`.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let handle = global_styles()
+    ///     .style_group_droppable(StyleGroup::new(".card:hover").style("color", "green"));
+    /// assert_eq!(handle.class(), Some("card"));
+    /// ```
+    pub fn class(&self) -> Option<&str> {
+        let selector = self.selector.strip_prefix('.')?;
+        let class_end = selector
+            .find(|character: char| {
+                not(character.is_alphanumeric() || matches!(character, '-' | '_'))
+            })
+            .unwrap_or(selector.len());
+        Some(&selector[..class_end]).filter(|class| not(class.is_empty()))
+    }
+}
+
 impl Drop for StyleGroupHandle {
     fn drop(&mut self) {
-        global_styles().remove_rule(self.rule_id);
+        if let Some(rule_id) = self.rule_id {
+            global_styles().remove_rule(rule_id);
+        }
     }
 }
 
+// ------ SharedStyleGroupHandle ------
+
+/// A reference to a rule shared by all elements with identical static styles.
+pub struct SharedStyleGroupHandle {
+    key: String,
+    class: String,
+}
+
+impl SharedStyleGroupHandle {
+    /// The class assigned to elements using the shared rule.
+    pub fn class(&self) -> &str {
+        &self.class
+    }
+}
+
+impl Drop for SharedStyleGroupHandle {
+    fn drop(&mut self) {
+        global_styles().release_shared_style_group(&self.key);
+    }
+}
+
+struct SharedStyleGroup {
+    class: String,
+    count: usize,
+    _handle: StyleGroupHandle,
+}
+
 // ------ global_styles ------
 
 /// Set styles that are globally used in your application.
@@ -352,98 +725,353 @@ pub fn global_styles() -> &'static GlobalStyles {
 
 pub struct GlobalStyles {
     sheet: SendWrapper<CssStyleSheet>,
+    layer_order_element: SendWrapper<HtmlStyleElement>,
+    layers: Mutex<Vec<String>>,
     rule_ids: MonotonicIds,
+    shared_style_groups: Mutex<BTreeMap<String, SharedStyleGroup>>,
+    shared_class_ids: IndexGenerator,
 }
 
 impl GlobalStyles {
     fn new() -> Self {
-        let style_element: HtmlStyleElement = document()
-            .create_element("style")
-            .expect_throw("style: create_element failed")
-            .unchecked_into();
-        document()
-            .head()
-            .expect_throw("style: head failed")
-            .append_child(&style_element)
-            .expect_throw("style: append_child failed");
-        let sheet = style_element
+        let create_style_element = || -> HtmlStyleElement {
+            let style_element = document()
+                .create_element("style")
+                .expect_throw("style: create_element failed");
+            document()
+                .head()
+                .expect_throw("style: head failed")
+                .append_child(&style_element)
+                .expect_throw("style: append_child failed");
+            style_element.unchecked_into()
+        };
+        // the first `@layer` statement in the document determines the layer order
+        let layer_order_element = create_style_element();
+        let sheet = create_style_element()
             .sheet()
             .expect_throw("style: sheet failed")
             .unchecked_into();
         Self {
             sheet: SendWrapper::new(sheet),
+            layer_order_element: SendWrapper::new(layer_order_element),
+            layers: Mutex::default(),
             rule_ids: MonotonicIds::default(),
+            shared_style_groups: Mutex::default(),
+            shared_class_ids: IndexGenerator::default(),
+        }
+    }
+
+    /// Declares the css [cascade layer](https://developer.mozilla.org/en-US/docs/Web/CSS/@layer).
+    /// Rules in a later declared layer override rules in earlier layers regardless of
+    /// the selector specificity and the order the rules have been inserted in.
+    /// Rules without a layer, including styles set directly on elements, override all layered rules.
+    ///
+    /// Layers used by a [StyleGroup] but not declared are appended to the declared ones.
+    /// Browsers without `@layer` support reject layered groups as invalid.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// global_styles()
+    ///     .layer("base")
+    ///     .layer("components")
+    ///     .layer("utilities");
+    ///
+    /// global_styles()
+    ///     .style_group(StyleGroup::new(".hidden").layer("utilities").style("display", "none"))
+    ///     // doesn't override `.hidden` although it's inserted later and it's more specific
+    ///     .style_group(StyleGroup::new("div.card").layer("components").style("display", "flex"))
+    ///     .style_group(StyleGroup::new("div").layer("base").style("display", "block"));
+    /// ```
+    pub fn layer(&self, name: &str) -> &Self {
+        let mut layers = self.layers.lock().unwrap_throw();
+        if layers.iter().any(|layer| layer == name) {
+            return self;
         }
+        layers.push(name.to_owned());
+        self.layer_order_element
+            .set_text_content(Some(&["@layer ", &layers.join(", "), ";"].concat()));
+        self
     }
 
+    /// Invalid selectors and properties are logged and skipped.
+    /// They panic in debug builds with the feature `strict_css`.
     pub fn style_group(&self, group: StyleGroup) -> &Self {
-        let (_, task_handles) = self.style_group_inner(group, false);
+        match self.style_group_inner(group, false, false) {
+            Ok((_, task_handles)) => mem::forget(task_handles),
+            Err(error) => report_invalid_css(error),
+        }
+        self
+    }
+
+    /// Like `style_group`, but the rule isn't inserted at all when the selector
+    /// or a static property is invalid.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let result = global_styles().try_style_group(StyleGroup::new("..invalid"));
+    /// assert!(matches!(result, Err(InvalidCss::Selector(_))));
+    /// ```
+    pub fn try_style_group(&self, group: StyleGroup) -> Result<&Self, InvalidCss> {
+        let (_, task_handles) = self.style_group_inner(group, false, true)?;
         mem::forget(task_handles);
+        Ok(self)
+    }
+
+    /// Inserts all groups in one sheet mutation - their rules are wrapped in one `@media all` block.
+    ///
+    /// When a selector is invalid, the groups are inserted one by one instead
+    /// so the invalid ones are reported and skipped like by `style_group`.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// global_styles().style_groups([
+    ///     StyleGroup::new(".button").style("padding", "10px"),
+    ///     StyleGroup::new(".button:hover").style("background", "purple"),
+    /// ]);
+    /// ```
+    pub fn style_groups<'a>(&self, groups: impl IntoIterator<Item = StyleGroup<'a>>) -> &Self {
+        let groups = groups.into_iter().collect::<Vec<_>>();
+        if groups.is_empty() {
+            return self;
+        }
+        let declarations = match self.insert_rule_block(&groups) {
+            Some(declarations) => declarations,
+            None => {
+                for group in groups {
+                    self.style_group(group);
+                }
+                return self;
+            }
+        };
+        for (group, declaration) in groups.into_iter().zip(declarations) {
+            match set_group_styles(declaration, group, false, false) {
+                Ok(task_handles) => mem::forget(task_handles),
+                Err(error) => report_invalid_css(error),
+            }
+        }
         self
     }
 
     #[must_use]
     pub fn style_group_droppable(&self, group: StyleGroup) -> StyleGroupHandle {
-        let (rule_id, _task_handles) = self.style_group_inner(group, true);
-        StyleGroupHandle {
-            rule_id,
+        let selector = group.selector.clone().into_owned();
+        match self.style_group_inner(group, true, false) {
+            Ok((rule_id, _task_handles)) => StyleGroupHandle {
+                rule_id: Some(rule_id),
+                selector,
+                _task_handles,
+            },
+            Err(error) => {
+                report_invalid_css(error);
+                StyleGroupHandle {
+                    rule_id: None,
+                    selector,
+                    _task_handles: Vec::new(),
+                }
+            }
+        }
+    }
+
+    pub fn try_style_group_droppable(
+        &self,
+        group: StyleGroup,
+    ) -> Result<StyleGroupHandle, InvalidCss> {
+        let selector = group.selector.clone().into_owned();
+        let (rule_id, _task_handles) = self.style_group_inner(group, true, true)?;
+        Ok(StyleGroupHandle {
+            rule_id: Some(rule_id),
+            selector,
             _task_handles,
+        })
+    }
+
+    /// Elements with identical static styles share one rule instead of generating
+    /// a rule per element. The group selector is prefixed with the returned shared class.
+    ///
+    /// Panics when the group contains dynamic styles - they have to be unique per element.
+    /// Used by `RawEl::style_group` for groups marked by `StyleGroup::shared`.
+    #[must_use]
+    pub fn style_group_shared(&self, mut group: StyleGroup) -> SharedStyleGroupHandle {
+        assert!(
+            group.dynamic_css_props.is_empty(),
+            "shared style groups can't contain dynamic styles"
+        );
+        let key = [
+            group.layer.as_deref().unwrap_or_default(),
+            "|",
+            &*group.selector,
+            "{",
+            &group.static_css_props.to_declarations(),
+            "}",
+        ]
+        .concat();
+
+        let mut shared_style_groups = self.shared_style_groups.lock().unwrap_throw();
+        if let Some(shared_group) = shared_style_groups.get_mut(&key) {
+            shared_group.count += 1;
+            return SharedStyleGroupHandle {
+                key,
+                class: shared_group.class.clone(),
+            };
         }
+        let class = ["_s", &self.shared_class_ids.next_index().to_string()].concat();
+        group.selector = [".", &class, &group.selector].concat().into();
+        let shared_group = SharedStyleGroup {
+            class: class.clone(),
+            count: 1,
+            _handle: self.style_group_droppable(group),
+        };
+        shared_style_groups.insert(key.clone(), shared_group);
+        SharedStyleGroupHandle { key, class }
     }
 
     // --
 
-    fn style_group_inner(&self, group: StyleGroup, droppable: bool) -> (u32, Vec<TaskHandle>) {
+    fn release_shared_style_group(&self, key: &str) {
+        let mut shared_style_groups = self.shared_style_groups.lock().unwrap_throw();
+        let shared_group = shared_style_groups
+            .get_mut(key)
+            .expect_throw("style: shared style group not found");
+        shared_group.count -= 1;
+        if shared_group.count > 0 {
+            return;
+        }
+        let shared_group = shared_style_groups.remove(key).unwrap_throw();
+        drop(shared_style_groups);
+        self.shared_class_ids
+            .remove_index(shared_group.class[2..].parse().unwrap_throw());
+    }
+
+    /// `strict` - fail on the first invalid static property instead of skipping it.
+    fn style_group_inner(
+        &self,
+        group: StyleGroup,
+        droppable: bool,
+        strict: bool,
+    ) -> Result<(u32, Vec<TaskHandle>), InvalidCss> {
         let (rule_id_and_index, ids_lock) = self.rule_ids.add_new_id();
-        let empty_rule = [&group.selector, "{}"].concat();
+        let empty_rule = match &group.layer {
+            Some(layer) => {
+                self.layer(layer);
+                ["@layer ", &**layer, "{", &group.selector, "{}}"].concat()
+            }
+            None => [&group.selector, "{}"].concat(),
+        };
 
-        self.sheet
+        if self
+            .sheet
             .insert_rule_with_index(&empty_rule, rule_id_and_index)
-            .unwrap_or_else(|_| {
-                panic!("invalid CSS selector: `{}`", &group.selector);
-            });
+            .is_err()
+        {
+            drop(ids_lock);
+            let _ = self.rule_ids.remove_id(rule_id_and_index);
+            return Err(InvalidCss::Selector(group.selector.into_owned()));
+        }
 
-        let declaration = self
+        let rule = self
             .sheet
             .css_rules()
             .expect_throw("failed to get global CSS rules")
             .item(rule_id_and_index)
-            .expect_throw("failed to get selected global CSS rule")
-            .unchecked_into::<CssStyleRule>()
-            .style();
+            .expect_throw("failed to get selected global CSS rule");
+
+        let rule = if group.layer.is_some() {
+            // `CSSLayerBlockRule` isn't in `web_sys`, but it's a `CSSGroupingRule`
+            let style_rule = rule.unchecked_into::<CssGroupingRule>().css_rules().item(0);
+            match style_rule {
+                Some(style_rule) => style_rule,
+                None => {
+                    // the browser drops an invalid selector from the layer block
+                    self.sheet
+                        .delete_rule(rule_id_and_index)
+                        .expect_throw("style: delete_rule failed");
+                    drop(ids_lock);
+                    let _ = self.rule_ids.remove_id(rule_id_and_index);
+                    return Err(InvalidCss::Selector(group.selector.into_owned()));
+                }
+            }
+        } else {
+            rule
+        };
+        let declaration = rule.unchecked_into::<CssStyleRule>().style();
 
         drop(ids_lock);
 
-        for (name, css_prop_value) in group.static_css_props {
-            set_css_property(
-                &declaration,
-                name,
-                &css_prop_value.value,
-                css_prop_value.important,
-            );
+        match set_group_styles(declaration, group, droppable, strict) {
+            Ok(task_handles) => Ok((rule_id_and_index, task_handles)),
+            Err(error) => {
+                self.remove_rule(rule_id_and_index);
+                Err(error)
+            }
         }
+    }
 
-        let declaration = Arc::new(SendWrapper::new(declaration));
-        let mut task_handles = Vec::new();
-        for (name, value_signal) in group.dynamic_css_props {
-            let declaration = Arc::clone(&declaration);
-            let task = value_signal.for_each_sync(move |value| {
-                if let Some(value) = value.into_option_cow_str() {
-                    // @TODO allow to set `important ` also in dynamic styles
-                    set_css_property(&declaration, &name, &value, false);
-                } else {
-                    declaration
-                        .remove_property(&name)
-                        .expect_throw("style: remove_property failed");
+    /// Inserts empty rules for all groups wrapped in one `@media all` block
+    /// and returns their declarations in the groups' order.
+    /// Returns `None` and inserts nothing when a selector is invalid.
+    fn insert_rule_block(&self, groups: &[StyleGroup]) -> Option<Vec<CssStyleDeclaration>> {
+        let mut block = String::from("@media all{");
+        for group in groups {
+            match &group.layer {
+                Some(layer) => {
+                    self.layer(layer);
+                    block.extend(["@layer ", &**layer, "{", &*group.selector, "{}}"]);
                 }
-            });
-            if droppable {
-                task_handles.push(Task::start_droppable(task));
-            } else {
-                Task::start(task);
+                None => block.extend([&*group.selector, "{}"]),
             }
         }
-        (rule_id_and_index, task_handles)
+        block.push('}');
+
+        let (rule_id_and_index, ids_lock) = self.rule_ids.add_new_id();
+        if self
+            .sheet
+            .insert_rule_with_index(&block, rule_id_and_index)
+            .is_err()
+        {
+            drop(ids_lock);
+            let _ = self.rule_ids.remove_id(rule_id_and_index);
+            return None;
+        }
+        let rules = self
+            .sheet
+            .css_rules()
+            .expect_throw("failed to get global CSS rules")
+            .item(rule_id_and_index)
+            .expect_throw("failed to get selected global CSS rule")
+            .unchecked_into::<CssGroupingRule>()
+            .css_rules();
+
+        // the browser drops invalid selectors from the block
+        let declarations = (rules.length() as usize == groups.len())
+            .then(|| {
+                groups
+                    .iter()
+                    .enumerate()
+                    .map(|(index, group)| {
+                        let rule = rules.item(index as u32)?;
+                        let rule = if group.layer.is_some() {
+                            rule.unchecked_into::<CssGroupingRule>()
+                                .css_rules()
+                                .item(0)?
+                        } else {
+                            rule
+                        };
+                        Some(rule.unchecked_into::<CssStyleRule>().style())
+                    })
+                    .collect::<Option<Vec<_>>>()
+            })
+            .flatten();
+        if declarations.is_none() {
+            self.sheet
+                .delete_rule(rule_id_and_index)
+                .expect_throw("style: delete_rule failed");
+            drop(ids_lock);
+            let _ = self.rule_ids.remove_id(rule_id_and_index);
+        }
+        declarations
     }
 
     fn remove_rule(&self, id: u32) {
@@ -454,9 +1082,58 @@ impl GlobalStyles {
     }
 }
 
-fn set_css_property(declaration: &CssStyleDeclaration, name: &str, value: &str, important: bool) {
-    // @TODO refactor?
+/// `strict` - fail on the first invalid static property instead of skipping it.
+fn set_group_styles(
+    declaration: CssStyleDeclaration,
+    group: StyleGroup,
+    droppable: bool,
+    strict: bool,
+) -> Result<Vec<TaskHandle>, InvalidCss> {
+    let dynamic_css_props = with_static_fallbacks(group.dynamic_css_props, &group.static_css_props);
+
+    for (name, css_prop_value) in group.static_css_props {
+        let result = set_css_property(
+            &declaration,
+            name,
+            &css_prop_value.value,
+            css_prop_value.important,
+        );
+        match result {
+            Err(error) if strict => return Err(error),
+            Err(error) => report_invalid_css(error),
+            Ok(()) => (),
+        }
+    }
+
+    let declaration = Arc::new(SendWrapper::new(declaration));
+    let mut task_handles = Vec::new();
+    for (name, value_signal) in dynamic_css_props {
+        let declaration = Arc::clone(&declaration);
+        let task = value_signal.for_each_sync(move |value| {
+            if let Some(value) = value.into_option_cow_str() {
+                // @TODO allow to set `important ` also in dynamic styles
+                if let Err(error) = set_css_property(&declaration, &name, &value, false) {
+                    report_invalid_css(error);
+                }
+            } else {
+                remove_css_property(&declaration, &name);
+            }
+        });
+        if droppable {
+            task_handles.push(Task::start_droppable(task));
+        } else {
+            Task::start(task);
+        }
+    }
+    Ok(task_handles)
+}
 
+fn set_css_property(
+    declaration: &CssStyleDeclaration,
+    name: &str,
+    value: &str,
+    important: bool,
+) -> Result<(), InvalidCss> {
     let priority = if important { "important" } else { "" };
 
     match declaration.set_property_with_priority(name, value, priority) {
@@ -465,32 +1142,131 @@ fn set_css_property(declaration: &CssStyleDeclaration, name: &str, value: &str,
             // e.g. `CSSStyleDeclaration.setProperty: Can't set properties on
             // CSSFontFaceRule declarations` on Firefox
             crate::eprintln!("{:#?}", error);
-            return;
+            return Ok(());
         }
     }
 
-    if not(declaration
-        .get_property_value(name)
-        .expect_throw("style: get_property_value failed")
-        .is_empty())
-    {
-        return;
+    if is_css_property_set(declaration, name) {
+        return Ok(());
+    }
+
+    // try the prefixes that worked last time for the same property
+    let cached_prefixes = vendor_prefix_cache()
+        .lock()
+        .unwrap_throw()
+        .get(name)
+        .copied();
+    if let Some(prefixes) = cached_prefixes {
+        if try_set_prefixed_css_property(declaration, name, value, priority, prefixes) {
+            return Ok(());
+        }
     }
+
     for name_prefix in iter::once("").chain(VENDOR_PREFIXES) {
-        let prefixed_name = [name_prefix, name].concat();
         for value_prefix in iter::once("").chain(VENDOR_PREFIXES) {
-            let prefixed_value = [value_prefix, value].concat();
-            declaration
-                .set_property_with_priority(&prefixed_name, &prefixed_value, priority)
-                .expect_throw("style: set_property_with_priority failed");
-            if not(declaration
-                .get_property_value(&prefixed_name)
-                .expect_throw("style: get_property_value failed")
-                .is_empty())
-            {
-                return;
+            let prefixes = (name_prefix, value_prefix);
+            if try_set_prefixed_css_property(declaration, name, value, priority, prefixes) {
+                vendor_prefix_cache()
+                    .lock()
+                    .unwrap_throw()
+                    .insert(name.to_owned(), prefixes);
+                return Ok(());
+            }
+        }
+    }
+    Err(InvalidCss::Property {
+        name: name.to_owned(),
+        value: value.to_owned(),
+    })
+}
+
+// ------ InvalidCss ------
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidCss {
+    Selector(String),
+    Property { name: String, value: String },
+}
+
+impl fmt::Display for InvalidCss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidCss::Selector(selector) => write!(f, "invalid CSS selector: `{}`", selector),
+            InvalidCss::Property { name, value } => {
+                write!(f, "invalid CSS property: `{}: {};`", name, value)
             }
         }
     }
-    panic!("invalid CSS property: `{}: {};`", name, value);
+}
+
+impl Error for InvalidCss {}
+
+fn report_invalid_css(error: InvalidCss) {
+    if cfg!(all(feature = "strict_css", debug_assertions)) {
+        panic!("{}", error);
+    }
+    crate::zerror!("{}", error);
+}
+
+/// Successful `(name_prefix, value_prefix)` combinations by property name.
+fn vendor_prefix_cache() -> &'static Mutex<BTreeMap<String, (&'static str, &'static str)>> {
+    static VENDOR_PREFIX_CACHE: OnceBox<Mutex<BTreeMap<String, (&'static str, &'static str)>>> =
+        OnceBox::new();
+    VENDOR_PREFIX_CACHE.get_or_init(|| Box::new(Mutex::default()))
+}
+
+fn try_set_prefixed_css_property(
+    declaration: &CssStyleDeclaration,
+    name: &str,
+    value: &str,
+    priority: &str,
+    (name_prefix, value_prefix): (&str, &str),
+) -> bool {
+    let prefixed_name = [name_prefix, name].concat();
+    let prefixed_value = [value_prefix, value].concat();
+    declaration
+        .set_property_with_priority(&prefixed_name, &prefixed_value, priority)
+        .expect_throw("style: set_property_with_priority failed");
+    is_css_property_set(declaration, &prefixed_name)
+}
+
+/// Removes also the vendor-prefixed variant set by `set_css_property`.
+fn remove_css_property(declaration: &CssStyleDeclaration, name: &str) {
+    declaration
+        .remove_property(name)
+        .expect_throw("style: remove_property failed");
+    let cached_prefixes = vendor_prefix_cache()
+        .lock()
+        .unwrap_throw()
+        .get(name)
+        .copied();
+    if let Some((name_prefix, _)) = cached_prefixes {
+        if not(name_prefix.is_empty()) {
+            declaration
+                .remove_property(&[name_prefix, name].concat())
+                .expect_throw("style: remove_property failed");
+        }
+    }
+}
+
+fn is_css_property_set(declaration: &CssStyleDeclaration, name: &str) -> bool {
+    not(declaration
+        .get_property_value(name)
+        .expect_throw("style: get_property_value failed")
+        .is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nth_child_formula() {
+        assert_eq!(NthChild::Even.formula(), "even");
+        assert_eq!(NthChild::Odd.formula(), "odd");
+        assert_eq!(NthChild::Formula(3, 1).formula(), "3n+1");
+        assert_eq!(NthChild::Formula(2, 0).formula(), "2n+0");
+        assert_eq!(NthChild::Formula(4, -1).formula(), "4n-1");
+        assert_eq!(NthChild::Formula(-1, 3).formula(), "-1n+3");
+    }
 }
@@ -5,6 +5,8 @@ use strum::{EnumIter, IntoEnumIterator, IntoStaticStr};
 /// Styling to align elements inside their container.
 /// It is possible to combine different methods to get the styling you need.
 ///
+/// Use `ContentAlign` to distribute or align the children of the styled container instead.
+///
 /// Here the element is positioned on the bottom of the right side.
 /// # Example
 /// ```no_run
@@ -201,12 +203,32 @@ impl Align {
         self.alignments.remove(&Alignment::AlignLeft);
         self
     }
+
+    /// The SVG `preserveAspectRatio` alignment, e.g. `xMinYMax` for `Align::new().left().bottom()`.
+    /// Unset axes are centered and dynamic alignments are ignored.
+    pub(crate) fn to_svg_align(&self) -> String {
+        let x = if self.alignments.contains(&Alignment::AlignLeft) {
+            "xMin"
+        } else if self.alignments.contains(&Alignment::AlignRight) {
+            "xMax"
+        } else {
+            "xMid"
+        };
+        let y = if self.alignments.contains(&Alignment::AlignTop) {
+            "YMin"
+        } else if self.alignments.contains(&Alignment::AlignBottom) {
+            "YMax"
+        } else {
+            "YMid"
+        };
+        [x, y].concat()
+    }
 }
 
 impl<'a> Style<'a> for Align {
     fn merge_with_group(self, mut group: StyleGroup<'a>) -> StyleGroup<'a> {
         for alignment in self.alignments {
-            group = group.class(alignment.into());
+            group = group.class(<&str>::from(alignment));
         }
         for (alignment, enabled) in self.dynamic_alignments {
             group = group.class_signal(<&str>::from(alignment), enabled);
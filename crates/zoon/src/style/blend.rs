@@ -0,0 +1,111 @@
+use crate::*;
+use std::borrow::Cow;
+use strum::IntoStaticStr;
+
+/// Styling to blend an element with the content behind it (`mix-blend-mode`)
+/// or its background layers with each other (`background-blend-mode`).
+/// # Example
+/// ```no_run
+/// use zoon::{named_color::*, *};
+///
+/// let duotone_photo = El::new()
+///     .s(Background::new().color(BLUE_5).url("/assets/images/photo.jpg"))
+///     .s(Blend::new().background_mode(BlendMode::Multiply));
+/// ```
+#[derive(Default)]
+pub struct Blend<'a> {
+    /// Static css properties used by zoon.
+    static_css_props: StaticCSSProps<'a>,
+    dynamic_css_props: DynamicCSSProps,
+}
+
+impl<'a> Blend<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Blends the element with the content behind it.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let overlay_title = El::new()
+    ///     .s(Blend::new().mode(BlendMode::Difference))
+    ///     .child("Title");
+    /// ```
+    pub fn mode(mut self, mode: BlendMode) -> Self {
+        self.static_css_props
+            .insert("mix-blend-mode", mode.into_cow_str());
+        self
+    }
+
+    pub fn mode_signal(
+        mut self,
+        mode: impl Signal<Item = impl Into<Option<BlendMode>>> + Unpin + 'static,
+    ) -> Self {
+        let mode = mode.map(|mode| mode.into().map(|mode| mode.into_cow_str()));
+        self.dynamic_css_props
+            .insert("mix-blend-mode".into(), box_css_signal(mode));
+        self
+    }
+
+    /// Blends the element's background layers (images, gradients and the color)
+    /// with each other.
+    pub fn background_mode(mut self, mode: BlendMode) -> Self {
+        self.static_css_props
+            .insert("background-blend-mode", mode.into_cow_str());
+        self
+    }
+
+    pub fn background_mode_signal(
+        mut self,
+        mode: impl Signal<Item = impl Into<Option<BlendMode>>> + Unpin + 'static,
+    ) -> Self {
+        let mode = mode.map(|mode| mode.into().map(|mode| mode.into_cow_str()));
+        self.dynamic_css_props
+            .insert("background-blend-mode".into(), box_css_signal(mode));
+        self
+    }
+}
+
+impl<'a> Style<'a> for Blend<'a> {
+    fn merge_with_group(self, mut group: StyleGroup<'a>) -> StyleGroup<'a> {
+        let Self {
+            static_css_props,
+            dynamic_css_props,
+        } = self;
+        group.static_css_props.extend(static_css_props);
+        group.dynamic_css_props.extend(dynamic_css_props);
+        group
+    }
+}
+
+// ------ BlendMode ------
+
+/// More information at <https://developer.mozilla.org/en-US/docs/Web/CSS/blend-mode>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
+pub enum BlendMode {
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+    Hue,
+    Saturation,
+    Color,
+    Luminosity,
+}
+
+impl<'a> IntoCowStr<'a> for BlendMode {
+    fn into_cow_str(self) -> Cow<'a, str> {
+        <&str>::from(self).into()
+    }
+}
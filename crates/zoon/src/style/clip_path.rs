@@ -0,0 +1,137 @@
+use crate::*;
+
+/// Clip the element to a shape, everything outside of the shape is hidden.
+/// The `-webkit-` prefix is used automatically when the browser requires it.
+/// More information at <https://developer.mozilla.org/en-US/docs/Web/CSS/clip-path>.
+/// # Example
+/// ```no_run
+/// use zoon::{named_color::*, *};
+///
+/// let avatar = El::new()
+///     .s(Width::new(64))
+///     .s(Height::new(64))
+///     .s(Background::new().color(BLUE_5))
+///     .s(ClipPath::circle(50, (50, 50)));
+/// ```
+#[derive(Default)]
+pub struct ClipPath {
+    shape: Option<String>,
+    self_signal: Option<Box<dyn Signal<Item = Option<Self>> + Unpin>>,
+}
+
+impl ClipPath {
+    /// Change the shape depending on signal's state, e.g. to animate a reveal
+    /// together with `Transitions`.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let (revealed, revealed_signal) = Mutable::new_and_signal(false);
+    /// let image = Image::new()
+    ///     .url("/assets/images/photo.jpg")
+    ///     .description("Photo")
+    ///     .s(ClipPath::with_signal(revealed_signal.map_bool(
+    ///         || ClipPath::circle(75, (50, 50)),
+    ///         || ClipPath::circle(0, (50, 50)),
+    ///     )))
+    ///     .s(Transitions::new([Transition::property("clip-path").duration(500)]))
+    ///     .on_click(move || revealed.set_neq(true));
+    /// ```
+    pub fn with_signal(
+        clip_path: impl Signal<Item = impl Into<Option<Self>>> + Unpin + 'static,
+    ) -> Self {
+        let mut this = Self::default();
+        let clip_path = clip_path.map(|clip_path| clip_path.into());
+        this.self_signal = Some(Box::new(clip_path));
+        this
+    }
+
+    /// A rectangle defined by the distances (in pixels) from the element's edges.
+    pub fn inset(
+        top: impl Into<f64>,
+        right: impl Into<f64>,
+        bottom: impl Into<f64>,
+        left: impl Into<f64>,
+    ) -> Self {
+        Self::shape(crate::format!(
+            "inset({}px {}px {}px {}px)",
+            top.into(),
+            right.into(),
+            bottom.into(),
+            left.into()
+        ))
+    }
+
+    /// A circle with the given radius and center.
+    /// All values are percentages of the element's size.
+    pub fn circle(radius: impl Into<f64>, (x, y): (impl Into<f64>, impl Into<f64>)) -> Self {
+        Self::shape(crate::format!(
+            "circle({}% at {}% {}%)",
+            radius.into(),
+            x.into(),
+            y.into()
+        ))
+    }
+
+    /// An ellipse with the given horizontal and vertical radii and center.
+    /// All values are percentages of the element's size.
+    pub fn ellipse(
+        radius_x: impl Into<f64>,
+        radius_y: impl Into<f64>,
+        (x, y): (impl Into<f64>, impl Into<f64>),
+    ) -> Self {
+        Self::shape(crate::format!(
+            "ellipse({}% {}% at {}% {}%)",
+            radius_x.into(),
+            radius_y.into(),
+            x.into(),
+            y.into()
+        ))
+    }
+
+    /// A polygon defined by its vertices.
+    /// Coordinates are percentages of the element's size.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let triangle = ClipPath::polygon([(50, 0), (100, 100), (0, 100)]);
+    /// ```
+    pub fn polygon<X: Into<f64>, Y: Into<f64>>(points: impl IntoIterator<Item = (X, Y)>) -> Self {
+        let points = points
+            .into_iter()
+            .map(|(x, y)| crate::format!("{}% {}%", x.into(), y.into()))
+            .collect::<Vec<_>>();
+        Self::shape(crate::format!("polygon({})", points.join(", ")))
+    }
+
+    /// A shape defined by SVG path data, e.g. `"M 0 0 L 100 0 L 50 100 Z"`.
+    /// Coordinates are in pixels.
+    pub fn path(svg_path_data: impl AsRef<str>) -> Self {
+        Self::shape(crate::format!("path('{}')", svg_path_data.as_ref()))
+    }
+
+    fn shape(shape: String) -> Self {
+        Self {
+            shape: Some(shape),
+            self_signal: None,
+        }
+    }
+}
+
+impl<'a> Style<'a> for ClipPath {
+    fn merge_with_group(self, group: StyleGroup<'a>) -> StyleGroup<'a> {
+        let Self { shape, self_signal } = self;
+
+        if let Some(self_signal) = self_signal {
+            group.style_signal(
+                "clip-path",
+                self_signal.map(|clip_path| clip_path.and_then(|clip_path| clip_path.shape)),
+            )
+        } else if let Some(shape) = shape {
+            group.style("clip-path", shape)
+        } else {
+            group
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::*;
+
+/// CSS multi-column layout - the content flows into balanced columns like in a newspaper.
+/// Simpler than a grid for long lists of short items like tags or glossary entries.
+/// More information at <https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_Columns>.
+/// # Example
+/// ```no_run
+/// use zoon::{named_color::*, *};
+///
+/// let glossary = Column::new()
+///     .s(Columns::new().width(200).gap(24).rule(1, GRAY_3))
+///     .items(["Apple", "Banana", "Cherry"].map(|term| {
+///         El::new().s(Columns::avoid_break_inside()).child(term)
+///     }));
+/// ```
+#[derive(Default)]
+pub struct Columns<'a> {
+    /// Static css properties used by zoon.
+    static_css_props: StaticCSSProps<'a>,
+}
+
+impl<'a> Columns<'a> {
+    /// Note: The element becomes a block container (`display: block`),
+    /// because multi-column layout doesn't apply to flex containers like `Column`.
+    pub fn new() -> Self {
+        let mut this = Self::default();
+        this.static_css_props.insert("display", "block");
+        this
+    }
+
+    /// The maximum number of columns.
+    pub fn count(mut self, count: u32) -> Self {
+        self.static_css_props
+            .insert("column-count", count.into_cow_str());
+        self
+    }
+
+    /// The ideal column width. The number of columns is derived from the available width;
+    /// it's limited by `count` when both are set.
+    pub fn width(mut self, width: u32) -> Self {
+        self.static_css_props.insert("column-width", px(width));
+        self
+    }
+
+    /// The space between columns.
+    pub fn gap(mut self, gap: u32) -> Self {
+        self.static_css_props.insert("column-gap", px(gap));
+        self
+    }
+
+    /// A solid line between columns, drawn in the middle of the gap.
+    pub fn rule(mut self, width: u32, color: impl Into<Option<HSLuv>>) -> Self {
+        self.static_css_props.insert("column-rule-width", px(width));
+        self.static_css_props.insert("column-rule-style", "solid");
+        if let Some(color) = color.into() {
+            self.static_css_props
+                .insert("column-rule-color", color.into_cow_str());
+        }
+        self
+    }
+
+    /// For items - the item won't be split across two columns.
+    pub fn avoid_break_inside() -> Self {
+        let mut this = Self::default();
+        this.static_css_props.insert("break-inside", "avoid");
+        this
+    }
+}
+
+impl<'a> Style<'a> for Columns<'a> {
+    fn merge_with_group(self, mut group: StyleGroup<'a>) -> StyleGroup<'a> {
+        group.static_css_props.extend(self.static_css_props);
+        group
+    }
+}
@@ -0,0 +1,140 @@
+use crate::*;
+use std::collections::{BTreeMap, BTreeSet};
+use strum::{EnumIter, IntoEnumIterator, IntoStaticStr};
+
+/// Styling to distribute or align the children of the styled container,
+/// e.g. a `Row` or a `Column`. Use `Align` to align the styled element itself inside its parent.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let toolbar = Row::new()
+///     .s(Width::fill())
+///     .s(ContentAlign::new().space_between())
+///     .item("Menu")
+///     .item("Title")
+///     .item("Profile");
+/// ```
+#[derive(Default)]
+pub struct ContentAlign {
+    alignments: BTreeSet<ContentAlignment>,
+    dynamic_alignments: BTreeMap<ContentAlignment, Box<dyn Signal<Item = bool> + Unpin>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumIter, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+enum ContentAlignment {
+    ContentSpaceBetween,
+    ContentSpaceAround,
+    ContentSpaceEvenly,
+    ContentBaseline,
+}
+
+impl ContentAlign {
+    /// Set the alignment depending on Signal's state.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let (is_wide, wide_signal) = Mutable::new_and_signal(true);
+    ///
+    /// let toolbar = Row::new()
+    ///     .s(ContentAlign::with_signal(
+    ///         wide_signal.map_true(|| ContentAlign::new().space_between()),
+    ///     ))
+    ///     .item("Menu")
+    ///     .item("Profile");
+    /// ```
+    pub fn with_signal(
+        align: impl Signal<Item = impl Into<Option<Self>>> + Unpin + 'static,
+    ) -> Self {
+        let mut this = Self::default();
+        let align = Broadcaster::new(align.map(|align| align.into()));
+
+        for alignment in ContentAlignment::iter() {
+            this.dynamic_alignments.insert(
+                alignment,
+                Box::new(
+                    align
+                        .signal_ref(move |align| {
+                            align
+                                .as_ref()
+                                .map(|align| align.alignments.contains(&alignment))
+                                .unwrap_or_default()
+                        })
+                        .dedupe(),
+                ),
+            );
+        }
+        this
+    }
+
+    /// The children are distributed along the main axis,
+    /// the first and the last ones are at the container's edges.
+    pub fn space_between(self) -> Self {
+        self.distribution(ContentAlignment::ContentSpaceBetween)
+    }
+
+    /// The children are distributed along the main axis
+    /// with half-size spaces at the container's edges.
+    pub fn space_around(self) -> Self {
+        self.distribution(ContentAlignment::ContentSpaceAround)
+    }
+
+    /// The children are distributed along the main axis
+    /// with equal spaces between them and at the container's edges.
+    pub fn space_evenly(self) -> Self {
+        self.distribution(ContentAlignment::ContentSpaceEvenly)
+    }
+
+    /// The children are aligned by their text baselines,
+    /// e.g. an icon and a label with different font sizes in a `Row`.
+    pub fn baseline(mut self) -> Self {
+        self.alignments.insert(ContentAlignment::ContentBaseline);
+        self
+    }
+
+    fn distribution(mut self, distribution: ContentAlignment) -> Self {
+        for alignment in [
+            ContentAlignment::ContentSpaceBetween,
+            ContentAlignment::ContentSpaceAround,
+            ContentAlignment::ContentSpaceEvenly,
+        ] {
+            self.alignments.remove(&alignment);
+        }
+        self.alignments.insert(distribution);
+        self
+    }
+}
+
+impl<'a> Style<'a> for ContentAlign {
+    fn merge_with_group(self, mut group: StyleGroup<'a>) -> StyleGroup<'a> {
+        run_once!(|| {
+            global_styles()
+                .style_group(
+                    StyleGroup::new(".content_space_between")
+                        .style("justify-content", "space-between"),
+                )
+                .style_group(
+                    StyleGroup::new(".content_space_around")
+                        .style("justify-content", "space-around"),
+                )
+                .style_group(
+                    StyleGroup::new(".content_space_evenly")
+                        .style("justify-content", "space-evenly"),
+                )
+                // `Row`, `Button` and `Link` set `align-items: center` as an inline style,
+                // a class rule overrides it only with `!important`.
+                .style_group(
+                    StyleGroup::new(".content_baseline").style_important("align-items", "baseline"),
+                );
+        });
+        for alignment in self.alignments {
+            group = group.class(<&str>::from(alignment));
+        }
+        for (alignment, enabled) in self.dynamic_alignments {
+            group = group.class_signal(<&str>::from(alignment), enabled);
+        }
+        group
+    }
+}
@@ -53,6 +53,20 @@ impl<'a> Cursor<'a> {
             .insert("cursor".into(), box_css_signal(cursor_icon));
         this
     }
+
+    /// `CursorIcon::Grab` switched to `CursorIcon::Grabbing` while `dragging` is `true`.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    /// let (dragging, dragging_signal) = Mutable::new_and_signal(false);
+    /// let handle = El::new()
+    ///     .s(Cursor::grab_signal(dragging_signal))
+    ///     .on_pointer_down(clone!((dragging) move || dragging.set_neq(true)))
+    ///     .on_pointer_up(move || dragging.set_neq(false));
+    /// ```
+    pub fn grab_signal(dragging: impl Signal<Item = bool> + Unpin + 'static) -> Self {
+        Self::with_signal(dragging.map_bool(|| CursorIcon::Grabbing, || CursorIcon::Grab))
+    }
 }
 
 impl<'a> Style<'a> for Cursor<'a> {
@@ -0,0 +1,91 @@
+use crate::*;
+
+/// Fixes the element to the viewport so it doesn't move while scrolling,
+/// e.g. a header, a bottom navigation bar or a floating action button.
+/// It translates to `position: fixed` with the given offsets in css.
+///
+/// The `*_with_safe_area` offsets add `env(safe-area-inset-*)` so the element isn't hidden
+/// by a notch or the home indicator on mobile devices. The insets are non-zero only
+/// when the page contains `<meta name="viewport" content="..., viewport-fit=cover">`.
+/// # Example
+/// ```no_run
+/// use zoon::{named_color::*, *};
+///
+/// let bottom_bar = Row::new()
+///     .s(Fixed::new().left(0).right(0).bottom_with_safe_area(0))
+///     .s(Background::new().color(GRAY_0))
+///     .item("Home");
+///
+/// let floating_button = Button::new()
+///     .s(Fixed::new().right_with_safe_area(16).bottom_with_safe_area(16))
+///     .label("+");
+/// ```
+pub struct Fixed<'a> {
+    /// Static css properties used by zoon.
+    static_css_props: StaticCSSProps<'a>,
+}
+
+impl<'a> Default for Fixed<'a> {
+    fn default() -> Self {
+        let mut static_css_props = StaticCSSProps::default();
+        static_css_props.insert("position", "fixed");
+        Self { static_css_props }
+    }
+}
+
+impl<'a> Fixed<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Distance in pixels from the top edge of the viewport.
+    pub fn top(self, offset: i32) -> Self {
+        self.offset("top", px(offset))
+    }
+
+    pub fn right(self, offset: i32) -> Self {
+        self.offset("right", px(offset))
+    }
+
+    pub fn bottom(self, offset: i32) -> Self {
+        self.offset("bottom", px(offset))
+    }
+
+    pub fn left(self, offset: i32) -> Self {
+        self.offset("left", px(offset))
+    }
+
+    /// Distance in pixels from the top edge of the safe area.
+    pub fn top_with_safe_area(self, offset: i32) -> Self {
+        self.offset("top", with_safe_area_inset("top", offset))
+    }
+
+    pub fn right_with_safe_area(self, offset: i32) -> Self {
+        self.offset("right", with_safe_area_inset("right", offset))
+    }
+
+    pub fn bottom_with_safe_area(self, offset: i32) -> Self {
+        self.offset("bottom", with_safe_area_inset("bottom", offset))
+    }
+
+    pub fn left_with_safe_area(self, offset: i32) -> Self {
+        self.offset("left", with_safe_area_inset("left", offset))
+    }
+
+    /// A custom css offset, e.g. `.offset("top", "50%")`.
+    pub fn offset(mut self, edge: &'a str, offset: impl IntoCowStr<'a>) -> Self {
+        self.static_css_props.insert(edge, offset.into_cow_str());
+        self
+    }
+}
+
+impl<'a> Style<'a> for Fixed<'a> {
+    fn merge_with_group(self, mut group: StyleGroup<'a>) -> StyleGroup<'a> {
+        group.static_css_props.extend(self.static_css_props);
+        group
+    }
+}
+
+fn with_safe_area_inset(edge: &str, offset: i32) -> String {
+    crate::format!("calc({}px + env(safe-area-inset-{}, 0px))", offset, edge)
+}
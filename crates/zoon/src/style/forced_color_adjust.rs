@@ -0,0 +1,67 @@
+use crate::*;
+
+/// Controls whether the browser replaces the element's colors with the system palette
+/// in forced colors mode (e.g. Windows High Contrast). It translates to `forced-color-adjust` in css.
+///
+/// It's ignored in browsers without forced colors mode (e.g. Safari),
+/// unsupported properties would fail otherwise.
+///
+/// Opt out only where the colors carry meaning the system palette would strip,
+/// e.g. color swatches or status indicators, and check `media::forced_colors`
+/// to provide a fallback like a border or an icon.
+/// # Example
+/// ```no_run
+/// use zoon::{named_color::*, *};
+///
+/// let swatch = El::new()
+///     .s(Width::new(20))
+///     .s(Height::new(20))
+///     .s(Background::new().color(GREEN_5))
+///     .s(ForcedColorAdjust::none());
+/// ```
+#[derive(Default)]
+pub struct ForcedColorAdjust<'a> {
+    /// Static css properties used by zoon.
+    static_css_props: StaticCSSProps<'a>,
+}
+
+impl<'a> ForcedColorAdjust<'a> {
+    /// The colors are replaced with the system palette (the browser default).
+    pub fn auto() -> Self {
+        Self::with_value("auto")
+    }
+
+    /// The element keeps its own colors.
+    pub fn none() -> Self {
+        Self::with_value("none")
+    }
+
+    fn with_value(value: &'static str) -> Self {
+        let mut this = Self::default();
+        if css_supports("forced-color-adjust", value) {
+            this.static_css_props.insert("forced-color-adjust", value);
+        }
+        this
+    }
+}
+
+impl<'a> Style<'a> for ForcedColorAdjust<'a> {
+    fn merge_with_group(self, mut group: StyleGroup<'a>) -> StyleGroup<'a> {
+        group.static_css_props.extend(self.static_css_props);
+        group
+    }
+}
+
+/// `CSS.supports(name, value)`, `false` in browsers without the `CSS` API.
+fn css_supports(name: &str, value: &str) -> bool {
+    let css = match Reflect::get(&window(), &"CSS".into()) {
+        Ok(css) if css.is_object() => css,
+        _ => return false,
+    };
+    Reflect::get(&css, &"supports".into())
+        .ok()
+        .and_then(|supports| supports.dyn_into::<js_sys::Function>().ok())
+        .and_then(|supports| supports.call2(&css, &name.into(), &value.into()).ok())
+        .and_then(|supported| supported.as_bool())
+        .unwrap_or_default()
+}
@@ -0,0 +1,120 @@
+use crate::*;
+
+/// Hide parts of the element according to the alpha channel of an image or a gradient.
+/// Browsers that support only `-webkit-mask-*` properties (e.g. Safari) get them automatically.
+/// More information at <https://developer.mozilla.org/en-US/docs/Web/CSS/mask>.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// // the bottom of the list fades out
+/// let list = Column::new()
+///     .s(Height::new(300))
+///     .s(Scrollbars::y_and_clip_x())
+///     .s(Mask::new().linear_gradient(180, [(1., 80.), (0., 100.)]))
+///     .items((0..50).map(|index| Text::new(index)));
+/// ```
+#[derive(Default)]
+pub struct Mask<'a> {
+    static_css_props: StaticCSSProps<'a>,
+    dynamic_css_props: DynamicCSSProps,
+}
+
+impl<'a> Mask<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use an image as the mask.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let element = El::new().s(Mask::new().url("/assets/images/star.svg").no_repeat());
+    /// ```
+    pub fn url(mut self, url: impl IntoCowStr<'a>) -> Self {
+        let url = ["url(", &url.into_cow_str(), ")"].concat();
+        self.static_css_props.insert("mask-image", url);
+        self
+    }
+
+    pub fn url_signal(
+        mut self,
+        url: impl Signal<Item = impl IntoOptionCowStr<'static> + 'static> + Unpin + 'static,
+    ) -> Self {
+        let url = url.map(|url| {
+            url.into_option_cow_str()
+                .map(|url| ["url(", &url, ")"].concat())
+        });
+        self.dynamic_css_props
+            .insert("mask-image".into(), box_css_signal(url));
+        self
+    }
+
+    /// Use a linear gradient as the mask.
+    /// - `degrees`: The gradient direction, `180` is from top to bottom.
+    /// - `stops`: `(opacity, position)` pairs, opacity is in the range `0.0..=1.0`
+    /// and position is a percentage of the gradient length.
+    pub fn linear_gradient(
+        mut self,
+        degrees: impl Into<f64>,
+        stops: impl IntoIterator<Item = (f64, f64)>,
+    ) -> Self {
+        let stops = stops
+            .into_iter()
+            .map(|(opacity, position)| crate::format!("rgba(0, 0, 0, {}) {}%", opacity, position))
+            .collect::<Vec<_>>();
+        let gradient = crate::format!(
+            "linear-gradient({}deg, {})",
+            degrees.into(),
+            stops.join(", ")
+        );
+        self.static_css_props.insert("mask-image", gradient);
+        self
+    }
+
+    /// The mask image is scaled to cover the entire element.
+    pub fn size_cover(mut self) -> Self {
+        self.static_css_props.insert("mask-size", "cover");
+        self
+    }
+
+    /// The mask image is scaled to fit into the element.
+    pub fn size_contain(mut self) -> Self {
+        self.static_css_props.insert("mask-size", "contain");
+        self
+    }
+
+    /// The mask image size in pixels.
+    pub fn size(mut self, width: impl Into<f64>, height: impl Into<f64>) -> Self {
+        let size = crate::format!("{}px {}px", width.into(), height.into());
+        self.static_css_props.insert("mask-size", size);
+        self
+    }
+
+    /// The mask image is rendered only once instead of being tiled.
+    pub fn no_repeat(mut self) -> Self {
+        self.static_css_props.insert("mask-repeat", "no-repeat");
+        self
+    }
+
+    /// The mask image position as percentages of the element's size,
+    /// `(50, 50)` centers the image.
+    pub fn position(mut self, x: impl Into<f64>, y: impl Into<f64>) -> Self {
+        let position = crate::format!("{}% {}%", x.into(), y.into());
+        self.static_css_props.insert("mask-position", position);
+        self
+    }
+}
+
+impl<'a> Style<'a> for Mask<'a> {
+    fn merge_with_group(self, mut group: StyleGroup<'a>) -> StyleGroup<'a> {
+        let Self {
+            static_css_props,
+            dynamic_css_props,
+        } = self;
+        group.static_css_props.extend(static_css_props);
+        group.dynamic_css_props.extend(dynamic_css_props);
+        group
+    }
+}
@@ -95,3 +95,81 @@ color!(PINK_6 => 353.4, 89.1, 49.3);
 color!(PINK_7 => 356.6, 92.8, 41.8);
 color!(PINK_8 => 356.5, 89.9, 34.6);
 color!(PINK_9 => 355.2, 84.9, 29.1);
+
+// ------ shade ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hue {
+    Gray,
+    Red,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+    Pink,
+}
+
+impl Hue {
+    fn shades(self) -> [HSLuv; 10] {
+        match self {
+            Hue::Gray => [
+                GRAY_0, GRAY_1, GRAY_2, GRAY_3, GRAY_4, GRAY_5, GRAY_6, GRAY_7, GRAY_8, GRAY_9,
+            ],
+            Hue::Red => [
+                RED_0, RED_1, RED_2, RED_3, RED_4, RED_5, RED_6, RED_7, RED_8, RED_9,
+            ],
+            Hue::Yellow => [
+                YELLOW_0, YELLOW_1, YELLOW_2, YELLOW_3, YELLOW_4, YELLOW_5, YELLOW_6, YELLOW_7,
+                YELLOW_8, YELLOW_9,
+            ],
+            Hue::Green => [
+                GREEN_0, GREEN_1, GREEN_2, GREEN_3, GREEN_4, GREEN_5, GREEN_6, GREEN_7, GREEN_8,
+                GREEN_9,
+            ],
+            Hue::Blue => [
+                BLUE_0, BLUE_1, BLUE_2, BLUE_3, BLUE_4, BLUE_5, BLUE_6, BLUE_7, BLUE_8, BLUE_9,
+            ],
+            Hue::Purple => [
+                PURPLE_0, PURPLE_1, PURPLE_2, PURPLE_3, PURPLE_4, PURPLE_5, PURPLE_6, PURPLE_7,
+                PURPLE_8, PURPLE_9,
+            ],
+            Hue::Pink => [
+                PINK_0, PINK_1, PINK_2, PINK_3, PINK_4, PINK_5, PINK_6, PINK_7, PINK_8, PINK_9,
+            ],
+        }
+    }
+}
+
+/// A color between the palette steps, interpolated in the HSLuv color space.
+/// The `step` is clamped to `0..=9`, whole steps return the palette colors.
+/// # Example
+/// ```no_run
+/// use zoon::{named_color::*, *};
+///
+/// // between `BLUE_5` and `BLUE_6`
+/// let button = Button::new().s(Background::new().color(shade(Hue::Blue, 5.5)));
+/// ```
+pub fn shade(hue: Hue, step: f64) -> HSLuv {
+    let shades = hue.shades();
+    let step = if step.is_nan() {
+        0.
+    } else {
+        step.clamp(0., 9.)
+    };
+    let lower_index = step.floor() as usize;
+    let ratio = step - step.floor();
+    let lower = shades[lower_index];
+    if ratio == 0. {
+        return lower;
+    }
+    let upper = shades[lower_index + 1];
+    let mix = |from: f64, to: f64| from + (to - from) * ratio;
+    // along the shorter arc of the hue circle
+    let hue_delta = (upper.h() - lower.h() + 540.) % 360. - 180.;
+    HSLuv::hsla(
+        (lower.h() + hue_delta * ratio).rem_euclid(360.),
+        mix(lower.s(), upper.s()),
+        mix(lower.l(), upper.l()),
+        mix(lower.a(), upper.a()),
+    )
+}
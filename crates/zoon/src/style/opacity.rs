@@ -0,0 +1,73 @@
+use crate::*;
+
+/// Styling for the element's transparency. It translates to `opacity` in css.
+/// Values are clamped to the range `0.0` (fully transparent) - `1.0` (fully opaque).
+#[derive(Default)]
+pub struct Opacity<'a> {
+    /// Static css properties used by zoon.
+    static_css_props: StaticCSSProps<'a>,
+    dynamic_css_props: DynamicCSSProps,
+}
+
+impl<'a> Opacity<'a> {
+    /// Set the element's opacity.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let hint = Paragraph::new().s(Opacity::new(0.6)).content("Optional");
+    /// ```
+    pub fn new(opacity: f64) -> Self {
+        let mut this = Self::default();
+        this.static_css_props
+            .insert("opacity", clamp_opacity(opacity).to_string());
+        this
+    }
+
+    /// Set the element's opacity depending on signal's state.
+    /// # Example
+    /// Fade the element in and out.
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let opacity = Mutable::new(1.);
+    /// let fading = El::new()
+    ///     .s(Opacity::with_signal(opacity.signal()))
+    ///     .s(Transitions::new([Transition::property("opacity").duration(500)]))
+    ///     .child("Click me to fade")
+    ///     .on_click(move || opacity.update(|opacity| 1. - opacity));
+    /// ```
+    pub fn with_signal(
+        opacity: impl Signal<Item = impl Into<Option<f64>>> + Unpin + 'static,
+    ) -> Self {
+        let mut this = Self::default();
+        let opacity = opacity.map(|opacity| {
+            opacity
+                .into()
+                .map(|opacity| clamp_opacity(opacity).to_string())
+        });
+        this.dynamic_css_props
+            .insert("opacity".into(), box_css_signal(opacity));
+        this
+    }
+}
+
+impl<'a> Style<'a> for Opacity<'a> {
+    fn merge_with_group(self, mut group: StyleGroup<'a>) -> StyleGroup<'a> {
+        let Self {
+            static_css_props,
+            dynamic_css_props,
+        } = self;
+        group.static_css_props.extend(static_css_props);
+        group.dynamic_css_props.extend(dynamic_css_props);
+        group
+    }
+}
+
+/// `NaN` is considered fully opaque, the css default.
+fn clamp_opacity(opacity: f64) -> f64 {
+    if opacity.is_nan() {
+        return 1.;
+    }
+    opacity.clamp(0., 1.)
+}
@@ -0,0 +1,69 @@
+use crate::*;
+
+/// Styling to visually reorder an item inside its `Row`, `Column` or another flex container
+/// without moving its DOM node. It translates to `order` in css.
+///
+/// Items are sorted by their order (`0` by default), items with the same order
+/// keep their source order. Positional `Align` methods still apply to the reordered items.
+/// Keyboard navigation and screen readers follow the source order.
+#[derive(Default)]
+pub struct Order<'a> {
+    /// Static css properties used by zoon.
+    static_css_props: StaticCSSProps<'a>,
+    dynamic_css_props: DynamicCSSProps,
+}
+
+impl<'a> Order<'a> {
+    /// Set the order of an item.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let layout = Row::new()
+    ///     .item(El::new().s(Order::new(1)).child("Sidebar"))
+    ///     .item(El::new().child("Content"));
+    /// ```
+    pub fn new(order: i32) -> Self {
+        let mut this = Self::default();
+        this.static_css_props.insert("order", order.into_cow_str());
+        this
+    }
+
+    /// Set the order of an item depending on signal's state.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// let (is_mobile, is_mobile_signal) = Mutable::new_and_signal(false);
+    ///
+    /// let layout = Row::new()
+    ///     .multiline()
+    ///     .item(
+    ///         El::new()
+    ///             .s(Order::with_signal(is_mobile_signal.map_true(|| 1)))
+    ///             .child("Sidebar"),
+    ///     )
+    ///     .item(El::new().child("Content"));
+    /// ```
+    pub fn with_signal(
+        order: impl Signal<Item = impl Into<Option<i32>>> + Unpin + 'static,
+    ) -> Self {
+        let mut this = Self::default();
+        let order = order.map(|order| order.into());
+        this.dynamic_css_props
+            .insert("order".into(), box_css_signal(order));
+        this
+    }
+}
+
+impl<'a> Style<'a> for Order<'a> {
+    fn merge_with_group(self, mut group: StyleGroup<'a>) -> StyleGroup<'a> {
+        let Self {
+            static_css_props,
+            dynamic_css_props,
+        } = self;
+        group.static_css_props.extend(static_css_props);
+        group.dynamic_css_props.extend(dynamic_css_props);
+        group
+    }
+}
@@ -0,0 +1,63 @@
+use crate::*;
+
+/// Let the user resize the element by dragging its bottom-right corner.
+/// It translates to `resize` in css together with `overflow: auto`,
+/// because `resize` has no effect on elements with visible overflow.
+///
+/// Observe the new size with `on_resize`, e.g. to persist it.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let notes = El::new()
+///     .s(Width::new(300))
+///     .s(Height::new(200))
+///     .s(Resize::both())
+///     .update_raw_el(|raw_el| {
+///         raw_el.on_resize(|width, height| println!("notes resized to {}x{}", width, height))
+///     })
+///     .child("Notes");
+/// ```
+#[derive(Default)]
+pub struct Resize<'a> {
+    /// Static css properties used by zoon.
+    static_css_props: StaticCSSProps<'a>,
+}
+
+impl<'a> Resize<'a> {
+    /// The element can't be resized by the user.
+    pub fn none() -> Self {
+        let mut this = Self::default();
+        this.static_css_props.insert("resize", "none");
+        this
+    }
+
+    /// The element can be resized horizontally and vertically.
+    pub fn both() -> Self {
+        Self::resizable("both")
+    }
+
+    /// The element can be resized only horizontally.
+    pub fn horizontal() -> Self {
+        Self::resizable("horizontal")
+    }
+
+    /// The element can be resized only vertically.
+    pub fn vertical() -> Self {
+        Self::resizable("vertical")
+    }
+
+    fn resizable(resize: &'static str) -> Self {
+        let mut this = Self::default();
+        this.static_css_props.insert("resize", resize);
+        this.static_css_props.insert("overflow", "auto");
+        this
+    }
+}
+
+impl<'a> Style<'a> for Resize<'a> {
+    fn merge_with_group(self, mut group: StyleGroup<'a>) -> StyleGroup<'a> {
+        group.static_css_props.extend(self.static_css_props);
+        group
+    }
+}
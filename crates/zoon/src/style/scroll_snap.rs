@@ -0,0 +1,117 @@
+use crate::*;
+use strum::IntoStaticStr;
+
+/// Native scroll snapping - the scroll container stops at its items when the user
+/// finishes scrolling. Set `snap_type` on the container and `snap_align` on the items.
+/// More information at <https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_Scroll_Snap>.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let carousel = Row::new()
+///     .s(Width::new(300))
+///     .s(Scrollbars::x_and_clip_y())
+///     .s(ScrollSnap::new().snap_type(SnapAxis::X, SnapStrictness::Mandatory))
+///     .items((1..=5).map(|index| {
+///         El::new()
+///             .s(Width::new(300))
+///             .s(ScrollSnap::new().snap_align(SnapAlign::Start))
+///             .child(index)
+///     }));
+/// ```
+#[derive(Default)]
+pub struct ScrollSnap<'a> {
+    /// Static css properties used by zoon.
+    static_css_props: StaticCSSProps<'a>,
+}
+
+impl<'a> ScrollSnap<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables snapping in the scroll container.
+    /// It translates to `scroll-snap-type` in css.
+    pub fn snap_type(mut self, axis: SnapAxis, strictness: SnapStrictness) -> Self {
+        let axis: &str = axis.into();
+        let strictness: &str = strictness.into();
+        self.static_css_props
+            .insert("scroll-snap-type", [axis, " ", strictness].concat());
+        self
+    }
+
+    /// The item's snap position in the scroll container.
+    /// It translates to `scroll-snap-align` in css.
+    pub fn snap_align(mut self, align: SnapAlign) -> Self {
+        let align: &str = align.into();
+        self.static_css_props.insert("scroll-snap-align", align);
+        self
+    }
+
+    /// The scroll container doesn't skip the item even on a fast swipe.
+    pub fn snap_stop_always(mut self) -> Self {
+        self.static_css_props.insert("scroll-snap-stop", "always");
+        self
+    }
+
+    /// Offsets the snap positions in the scroll container,
+    /// e.g. to keep the items below a fixed header.
+    pub fn padding(mut self, padding: u32) -> Self {
+        self.static_css_props.insert("scroll-padding", px(padding));
+        self
+    }
+
+    pub fn padding_x(mut self, padding: u32) -> Self {
+        self.static_css_props
+            .insert("scroll-padding-left", px(padding));
+        self.static_css_props
+            .insert("scroll-padding-right", px(padding));
+        self
+    }
+
+    pub fn padding_y(mut self, padding: u32) -> Self {
+        self.static_css_props
+            .insert("scroll-padding-top", px(padding));
+        self.static_css_props
+            .insert("scroll-padding-bottom", px(padding));
+        self
+    }
+}
+
+impl<'a> Style<'a> for ScrollSnap<'a> {
+    fn merge_with_group(self, mut group: StyleGroup<'a>) -> StyleGroup<'a> {
+        group.static_css_props.extend(self.static_css_props);
+        group
+    }
+}
+
+// ------ SnapAxis ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum SnapAxis {
+    X,
+    Y,
+    Both,
+}
+
+// ------ SnapStrictness ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum SnapStrictness {
+    /// Always rest on a snap position.
+    Mandatory,
+    /// Snap only when the scroll ends near a snap position.
+    Proximity,
+}
+
+// ------ SnapAlign ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum SnapAlign {
+    Start,
+    Center,
+    End,
+}
@@ -70,6 +70,28 @@ impl<'a> Style<'a> for Transitions<'a> {
 
 // ------ Transition ------
 
+/// Transitioned css properties that trigger layout (e.g. `width` or `margin-left`) are recalculated
+/// for the whole page in every frame, so the animation may stutter.
+/// Prefer GPU-friendly properties, which are only composited:
+/// - `transform` - e.g. `translate` instead of `margin` or `left`, `scale` instead of `width`
+/// - `opacity`
+/// - `filter`
+///
+/// Transitions of layout-triggering properties are reported in debug builds.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let (hovered, hovered_signal) = Mutable::new_and_signal(false);
+/// let card = El::new()
+///     .s(Transform::with_signal(hovered_signal.map_bool(
+///         || Transform::new().move_up(4),
+///         || Transform::new(),
+///     )))
+///     .s(Transitions::new([Transition::transform().duration(150)]))
+///     .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
+///     .child("Hover me");
+/// ```
 pub struct Transition {
     property: Cow<'static, str>,
     duration: u32,
@@ -86,8 +108,11 @@ impl Default for Transition {
 
 impl Transition {
     pub fn property(property: impl IntoCowStr<'static>) -> Self {
+        let property = property.into_cow_str();
+        #[cfg(debug_assertions)]
+        warn_layout_property(&property);
         Self {
-            property: property.into_cow_str(),
+            property,
             ..Self::default()
         }
     }
@@ -104,10 +129,21 @@ impl Transition {
         Self::property("height")
     }
 
+    /// GPU-friendly, see `Transition`.
     pub fn transform() -> Self {
         Self::property("transform")
     }
 
+    /// GPU-friendly, see `Transition`.
+    pub fn opacity() -> Self {
+        Self::property("opacity")
+    }
+
+    /// GPU-friendly, see `Transition`.
+    pub fn filter() -> Self {
+        Self::property("filter")
+    }
+
     pub fn color() -> Self {
         Self::property("color")
     }
@@ -122,6 +158,56 @@ impl Transition {
     }
 }
 
+/// Warns only once per property, `Transition`s are often created in render functions.
+#[cfg(debug_assertions)]
+fn warn_layout_property(property: &str) {
+    use std::{cell::RefCell, collections::BTreeSet};
+
+    thread_local! {
+        static WARNED_PROPERTIES: RefCell<BTreeSet<&'static str>> = RefCell::new(BTreeSet::new());
+    }
+    let layout_property = match LAYOUT_PROPERTIES.iter().find(|name| **name == property) {
+        Some(layout_property) => *layout_property,
+        None => return,
+    };
+    if WARNED_PROPERTIES.with(|warned| warned.borrow_mut().insert(layout_property)) {
+        crate::zwarn!(
+            "transitioned css property `{}` triggers layout, consider `transform` instead",
+            property
+        );
+    }
+}
+
+/// Properties reported by `Transition::property` in debug builds.
+#[cfg(debug_assertions)]
+const LAYOUT_PROPERTIES: &[&str] = &[
+    "width",
+    "height",
+    "min-width",
+    "min-height",
+    "max-width",
+    "max-height",
+    "margin",
+    "margin-top",
+    "margin-right",
+    "margin-bottom",
+    "margin-left",
+    "padding",
+    "padding-top",
+    "padding-right",
+    "padding-bottom",
+    "padding-left",
+    "top",
+    "right",
+    "bottom",
+    "left",
+    "border-width",
+    "font-size",
+    "line-height",
+    "flex-basis",
+    "gap",
+];
+
 impl<'a> IntoCowStr<'a> for Transition {
     fn into_cow_str(self) -> Cow<'a, str> {
         crate::format!("{} {}ms", self.property, self.duration).into()
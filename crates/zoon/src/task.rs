@@ -1,5 +1,11 @@
 use crate::*;
 use futures_util::future::{abortable, AbortHandle};
+use std::{
+    error::Error,
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
 use wasm_bindgen_futures::spawn_local;
 
 // ------ Task ------
@@ -7,10 +13,12 @@ use wasm_bindgen_futures::spawn_local;
 pub struct Task;
 
 impl Task {
+    /// Runs the future to completion in the background. It can't be aborted.
     pub fn start(future: impl Future<Output = ()> + 'static) {
         spawn_local(future)
     }
 
+    /// The future is aborted when the returned `TaskHandle` is dropped.
     pub fn start_droppable(future: impl Future<Output = ()> + 'static) -> TaskHandle {
         let (future_handler, future_handle) = abortable(future);
         spawn_local(async {
@@ -18,6 +26,30 @@ impl Task {
         });
         TaskHandle(future_handle)
     }
+
+    /// Like `start_droppable`, but the returned handle can be awaited to get the future's output.
+    /// # Example
+    /// ```no_run
+    /// use zoon::*;
+    ///
+    /// Task::start(async {
+    ///     let answer = Task::spawn(async { 42 });
+    ///     assert_eq!(answer.await, Ok(42));
+    /// });
+    /// ```
+    pub fn spawn<T: 'static>(future: impl Future<Output = T> + 'static) -> TaskJoinHandle<T> {
+        let (sender, receiver) = oneshot::channel();
+        let (future_handler, future_handle) = abortable(future);
+        spawn_local(async {
+            if let Ok(output) = future_handler.await {
+                let _ = sender.send(output);
+            }
+        });
+        TaskJoinHandle {
+            receiver,
+            abort_handle: future_handle,
+        }
+    }
 }
 
 // ------ TaskHandle ------
@@ -30,3 +62,111 @@ impl Drop for TaskHandle {
         self.0.abort();
     }
 }
+
+// ------ TaskHandleGroup ------
+
+/// Owns multiple `TaskHandle`s and aborts all their tasks when dropped,
+/// in the order the handles have been added.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let count = Mutable::new(0);
+///
+/// let mut tasks = TaskHandleGroup::new();
+/// tasks.start_droppable(count.signal().for_each_sync(|count| println!("count: {}", count)));
+/// tasks.push(Task::start_droppable(async { Timer::sleep(1000).await }));
+///
+/// let counter = El::new()
+///     .child_signal(count.signal())
+///     .after_remove(move |_| drop(tasks));
+/// ```
+#[must_use]
+#[derive(Default)]
+pub struct TaskHandleGroup(Vec<TaskHandle>);
+
+impl TaskHandleGroup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, handle: TaskHandle) {
+        self.0.push(handle);
+    }
+
+    /// `Task::start_droppable` with the handle added to the group.
+    pub fn start_droppable(&mut self, future: impl Future<Output = ()> + 'static) {
+        self.push(Task::start_droppable(future));
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Aborts all tasks now, the group can be reused.
+    pub fn clear(&mut self) {
+        // `Vec::clear` drops the items in order
+        self.0.clear();
+    }
+}
+
+impl Extend<TaskHandle> for TaskHandleGroup {
+    fn extend<I: IntoIterator<Item = TaskHandle>>(&mut self, handles: I) {
+        self.0.extend(handles);
+    }
+}
+
+impl FromIterator<TaskHandle> for TaskHandleGroup {
+    fn from_iter<I: IntoIterator<Item = TaskHandle>>(handles: I) -> Self {
+        Self(handles.into_iter().collect())
+    }
+}
+
+// ------ TaskJoinHandle ------
+
+/// Resolves to the task's output or to `Cancelled` when the task has been aborted.
+/// The task is aborted when the handle is dropped.
+#[must_use]
+pub struct TaskJoinHandle<T> {
+    receiver: oneshot::Receiver<T>,
+    abort_handle: AbortHandle,
+}
+
+impl<T> TaskJoinHandle<T> {
+    pub fn abort(&self) {
+        self.abort_handle.abort();
+    }
+}
+
+impl<T> Future for TaskJoinHandle<T> {
+    type Output = Result<T, Cancelled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.receiver)
+            .poll(cx)
+            .map(|output| output.map_err(|_| Cancelled))
+    }
+}
+
+impl<T> Drop for TaskJoinHandle<T> {
+    fn drop(&mut self) {
+        self.abort_handle.abort();
+    }
+}
+
+// ------ Cancelled ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "task has been cancelled")
+    }
+}
+
+impl Error for Cancelled {}
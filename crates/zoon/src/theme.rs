@@ -0,0 +1,55 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static SPACE_UNIT: AtomicU32 = AtomicU32::new(4);
+static SIZE_UNIT: AtomicU32 = AtomicU32::new(8);
+
+// ------ space ------
+
+/// Spacing scale step in pixels - `n` times the space unit (4px by default),
+/// e.g. `space(4)` is `16` with the default unit.
+///
+/// Use it instead of magic numbers in paddings, gaps and margins
+/// so the whole app keeps a consistent rhythm.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+///
+/// let card = Column::new()
+///     .s(Padding::all(theme::space(4)))
+///     .s(Spacing::new(theme::space(2)))
+///     .s(Width::new(theme::size(40)))
+///     .item("Title")
+///     .item("Content");
+/// ```
+pub fn space(n: u32) -> u32 {
+    n * SPACE_UNIT.load(Ordering::Relaxed)
+}
+
+/// Changes the pixels of one `space` step. Call it before `start_app` -
+/// already created elements keep their sizes.
+pub fn set_space_unit(px: u32) {
+    SPACE_UNIT.store(px, Ordering::Relaxed);
+}
+
+pub fn space_unit() -> u32 {
+    SPACE_UNIT.load(Ordering::Relaxed)
+}
+
+// ------ size ------
+
+/// Size scale step in pixels - `n` times the size unit (8px by default),
+/// e.g. `size(40)` is `320` with the default unit.
+/// Intended for widths, heights and icon sizes.
+pub fn size(n: u32) -> u32 {
+    n * SIZE_UNIT.load(Ordering::Relaxed)
+}
+
+/// Changes the pixels of one `size` step. Call it before `start_app` -
+/// already created elements keep their sizes.
+pub fn set_size_unit(px: u32) {
+    SIZE_UNIT.store(px, Ordering::Relaxed);
+}
+
+pub fn size_unit() -> u32 {
+    SIZE_UNIT.load(Ordering::Relaxed)
+}
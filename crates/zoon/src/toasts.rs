@@ -0,0 +1,194 @@
+use crate::*;
+use once_cell::race::OnceBox;
+use std::{
+    borrow::Cow,
+    convert::TryFrom,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+// ------ toasts ------
+
+/// Notifications rendered into a fixed container at the bottom right corner of the page.
+/// The container is appended to `<body>` when the first toast is shown.
+/// # Example
+/// ```no_run
+/// use zoon::*;
+/// use std::time::Duration;
+///
+/// let toast_id = toasts().show(
+///     Toast::new("Saved")
+///         .kind(ToastKind::Success)
+///         .duration(Duration::from_secs(3)),
+/// );
+/// toasts().dismiss(toast_id);
+/// ```
+pub fn toasts() -> &'static Toasts {
+    static TOASTS: OnceBox<Toasts> = OnceBox::new();
+    TOASTS.get_or_init(|| Box::new(Toasts::new()))
+}
+
+// ------ Toasts ------
+
+pub struct Toasts {
+    toasts: MutableVec<Arc<ShownToast>>,
+    next_id: AtomicU32,
+    mounted: AtomicBool,
+}
+
+impl Toasts {
+    fn new() -> Self {
+        Self {
+            toasts: MutableVec::new(),
+            next_id: AtomicU32::new(0),
+            mounted: AtomicBool::new(false),
+        }
+    }
+
+    /// Shows the toast below the already visible ones.
+    /// The returned id can be used to dismiss the toast manually.
+    pub fn show(&self, toast: Toast) -> ToastId {
+        if not(self.mounted.swap(true, Ordering::SeqCst)) {
+            dominator::append_dom(&dom::body(), container(self).into_raw_element().into_dom());
+        }
+        let id = ToastId(self.next_id.fetch_add(1, Ordering::SeqCst));
+        if let Some(duration) = toast.duration {
+            let ms = u32::try_from(duration.as_millis()).unwrap_or(u32::MAX);
+            Task::start(async move {
+                Timer::sleep(ms).await;
+                toasts().dismiss(id);
+            });
+        }
+        self.toasts
+            .lock_mut()
+            .push_cloned(Arc::new(ShownToast { id, toast }));
+        id
+    }
+
+    /// Does nothing if the toast has been already dismissed.
+    pub fn dismiss(&self, id: ToastId) {
+        self.toasts
+            .lock_mut()
+            .retain(|shown_toast| shown_toast.id != id);
+    }
+
+    pub fn dismiss_all(&self) {
+        self.toasts.lock_mut().clear();
+    }
+}
+
+// ------ ToastId ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ToastId(u32);
+
+// ------ ToastKind ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastKind {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Default for ToastKind {
+    fn default() -> Self {
+        Self::Info
+    }
+}
+
+impl ToastKind {
+    fn background_color(&self) -> &'static str {
+        match self {
+            Self::Info => "#2563eb",
+            Self::Success => "#16a34a",
+            Self::Warning => "#d97706",
+            Self::Error => "#dc2626",
+        }
+    }
+
+    fn role(&self) -> &'static str {
+        match self {
+            Self::Info | Self::Success => "status",
+            Self::Warning | Self::Error => "alert",
+        }
+    }
+}
+
+// ------ Toast ------
+
+pub struct Toast {
+    message: Cow<'static, str>,
+    kind: ToastKind,
+    duration: Option<Duration>,
+}
+
+impl Toast {
+    /// Default kind: `ToastKind::Info`
+    ///
+    /// Default duration: 5 seconds
+    pub fn new(message: impl IntoCowStr<'static>) -> Self {
+        Self {
+            message: message.into_cow_str(),
+            kind: ToastKind::default(),
+            duration: Some(Duration::from_secs(5)),
+        }
+    }
+
+    pub fn kind(mut self, kind: ToastKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// The toast stays visible until it's dismissed by the user or by `Toasts::dismiss`.
+    pub fn persistent(mut self) -> Self {
+        self.duration = None;
+        self
+    }
+}
+
+// ------ helpers ------
+
+struct ShownToast {
+    id: ToastId,
+    toast: Toast,
+}
+
+fn container(toasts: &Toasts) -> RawHtmlEl<web_sys::HtmlElement> {
+    RawHtmlEl::<web_sys::HtmlElement>::new("div")
+        .class("toasts")
+        .style("position", "fixed")
+        .style("right", "16px")
+        .style("bottom", "16px")
+        .style("z-index", "2147483647")
+        .style("display", "flex")
+        .style("flex-direction", "column")
+        .style("gap", "8px")
+        .children_signal_vec(toasts.toasts.signal_vec_cloned().map(toast_view))
+}
+
+fn toast_view(shown_toast: Arc<ShownToast>) -> RawHtmlEl<web_sys::HtmlElement> {
+    let ShownToast { id, toast } = &*shown_toast;
+    let id = *id;
+    RawHtmlEl::<web_sys::HtmlElement>::new("div")
+        .class("toast")
+        .attr("role", toast.kind.role())
+        .attr("title", "Dismiss")
+        .style("padding", "12px 16px")
+        .style("border-radius", "6px")
+        .style("box-shadow", "0 2px 8px rgba(0, 0, 0, 0.25)")
+        .style("color", "white")
+        .style("background-color", toast.kind.background_color())
+        .style("cursor", "pointer")
+        .event_handler(move |_: events::Click| toasts().dismiss(id))
+        .child(&*toast.message)
+}
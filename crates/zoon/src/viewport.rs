@@ -1,3 +1,17 @@
+use crate::watched_state::{watched_state_signal, WatchedState};
+use crate::*;
+use std::{
+    cell::RefCell,
+    rc::Weak,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+/// Window resizes are reported this number of ms after the last `resize` event.
+const RESIZE_DEBOUNCE: u32 = 100;
+
+static TABLET_MIN_WIDTH: AtomicU32 = AtomicU32::new(768);
+static DESKTOP_MIN_WIDTH: AtomicU32 = AtomicU32::new(1024);
+
 // ------ Viewport ------
 
 #[derive(Debug, Clone, Copy)]
@@ -43,3 +57,87 @@ impl Scene {
         self.height
     }
 }
+
+// ------ width ------
+
+/// The window's inner width in pixels.
+pub fn width() -> u32 {
+    window()
+        .inner_width()
+        .ok()
+        .and_then(|width| width.as_f64())
+        .unwrap_or_default() as u32
+}
+
+/// Changes on the window `resize` event, debounced so a resize by dragging
+/// emits only once the user stops.
+///
+/// The event listener is shared by all signals, it's added on the first call
+/// and removed once all signals have been dropped.
+pub fn width_signal() -> impl Signal<Item = u32> {
+    thread_local! {
+        static WIDTH: RefCell<Weak<WatchedState<u32>>> = RefCell::new(Weak::new());
+    }
+    watched_state_signal(&WIDTH, || {
+        WatchedState::with_debounce(
+            width,
+            vec![(window().into(), "resize")],
+            Some(RESIZE_DEBOUNCE),
+        )
+    })
+}
+
+// ------ Breakpoint ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Breakpoint {
+    /// Narrower than 768px by default.
+    Mobile,
+    /// 768px - 1023px by default.
+    Tablet,
+    /// At least 1024px wide by default.
+    Desktop,
+}
+
+impl Breakpoint {
+    pub fn from_width(width: u32) -> Self {
+        if width >= DESKTOP_MIN_WIDTH.load(Ordering::Relaxed) {
+            Self::Desktop
+        } else if width >= TABLET_MIN_WIDTH.load(Ordering::Relaxed) {
+            Self::Tablet
+        } else {
+            Self::Mobile
+        }
+    }
+}
+
+/// Changes the minimum widths of `Breakpoint::Tablet` and `Breakpoint::Desktop`.
+/// Call it before the breakpoint signals are created.
+pub fn set_breakpoints(tablet_min_width: u32, desktop_min_width: u32) {
+    TABLET_MIN_WIDTH.store(tablet_min_width, Ordering::Relaxed);
+    DESKTOP_MIN_WIDTH.store(desktop_min_width, Ordering::Relaxed);
+}
+
+pub fn breakpoint() -> Breakpoint {
+    Breakpoint::from_width(width())
+}
+
+/// Emits only when the window width crosses a breakpoint.
+/// Use it when the layout changes structurally, otherwise prefer css media queries.
+/// # Example
+/// ```no_run
+/// use zoon::{viewport::Breakpoint, *};
+///
+/// let navigation = El::new().child_signal(viewport::breakpoint_signal().map(|breakpoint| {
+///     match breakpoint {
+///         Breakpoint::Mobile => El::new().child("☰").into_raw_element(),
+///         Breakpoint::Tablet | Breakpoint::Desktop => Row::new()
+///             .s(Spacing::new(20))
+///             .items(["Home", "Blog", "About"])
+///             .into_raw_element(),
+///     }
+/// }));
+/// ```
+pub fn breakpoint_signal() -> impl Signal<Item = Breakpoint> {
+    width_signal().map(Breakpoint::from_width).dedupe()
+}
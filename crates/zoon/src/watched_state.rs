@@ -0,0 +1,99 @@
+use crate::*;
+use std::{
+    cell::RefCell,
+    pin::Pin,
+    rc::{Rc, Weak},
+    task::{Context, Poll},
+};
+
+// ------ WatchedState ------
+
+/// A value refreshed on the given events. The event listeners are shared by all signals,
+/// they are added on the first call and removed once all signals have been dropped.
+pub(crate) struct WatchedState<T> {
+    value: Mutable<T>,
+    listeners: Vec<(web_sys::EventTarget, &'static str, Closure<dyn Fn()>)>,
+}
+
+impl<T: Copy + PartialEq + 'static> WatchedState<T> {
+    pub(crate) fn new(
+        getter: fn() -> T,
+        events: Vec<(web_sys::EventTarget, &'static str)>,
+    ) -> Self {
+        Self::with_debounce(getter, events, None)
+    }
+
+    /// `debounce` - the value is refreshed the given number of ms after the last event.
+    pub(crate) fn with_debounce(
+        getter: fn() -> T,
+        events: Vec<(web_sys::EventTarget, &'static str)>,
+        debounce: Option<u32>,
+    ) -> Self {
+        let value = Mutable::new(getter());
+        let listeners = events
+            .into_iter()
+            .map(|(target, event)| {
+                let value = value.clone();
+                let listener: Box<dyn Fn()> = match debounce {
+                    None => Box::new(move || value.set_neq(getter())),
+                    Some(delay) => {
+                        let timer = RefCell::new(None);
+                        Box::new(move || {
+                            let value = value.clone();
+                            // the replaced timer is cancelled
+                            *timer.borrow_mut() =
+                                Some(Timer::once(delay, move || value.set_neq(getter())));
+                        })
+                    }
+                };
+                let listener = Closure::wrap(listener);
+                target
+                    .add_event_listener_with_callback(event, listener.as_ref().unchecked_ref())
+                    .unwrap_throw();
+                (target, event, listener)
+            })
+            .collect();
+        Self { value, listeners }
+    }
+}
+
+impl<T> Drop for WatchedState<T> {
+    fn drop(&mut self) {
+        for (target, event, listener) in &self.listeners {
+            target
+                .remove_event_listener_with_callback(event, listener.as_ref().unchecked_ref())
+                .unwrap_throw();
+        }
+    }
+}
+
+pub(crate) fn watched_state_signal<T: Copy + 'static>(
+    shared_state: &'static std::thread::LocalKey<RefCell<Weak<WatchedState<T>>>>,
+    new_state: impl FnOnce() -> WatchedState<T>,
+) -> WatchedStateSignal<T> {
+    let state = shared_state.with(|shared_state| {
+        if let Some(state) = shared_state.borrow().upgrade() {
+            return state;
+        }
+        let state = Rc::new(new_state());
+        *shared_state.borrow_mut() = Rc::downgrade(&state);
+        state
+    });
+    WatchedStateSignal {
+        signal: state.value.signal(),
+        _state: state,
+    }
+}
+
+pub(crate) struct WatchedStateSignal<T> {
+    signal: MutableSignal<T>,
+    _state: Rc<WatchedState<T>>,
+}
+
+impl<T: Copy> Signal for WatchedStateSignal<T> {
+    type Item = T;
+
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.signal).poll_change(cx)
+    }
+}
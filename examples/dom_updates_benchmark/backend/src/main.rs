@@ -0,0 +1,29 @@
+use moon::*;
+
+async fn frontend() -> Frontend {
+    Frontend::new().title("DOM updates benchmark").append_to_head(
+        "
+        <style>
+            html {
+                background-color: black;
+                color: lightgray;
+            }
+
+            .button {
+                background-color: darkgreen;
+                padding: 5px;
+            }
+            
+            .button:hover {
+                background-color: green;
+            }
+        </style>",
+    )
+}
+
+async fn up_msg_handler(_: UpMsgRequest<()>) {}
+
+#[moon::main]
+async fn main() -> std::io::Result<()> {
+    start(frontend, up_msg_handler, |_| {}).await
+}
@@ -0,0 +1,141 @@
+use std::cell::RefCell;
+use zoon::{format, *};
+
+const BOX_COUNT: usize = 30;
+const ROUNDS: u32 = 200;
+
+thread_local! {
+    static BOX_ELEMENTS: RefCell<Vec<web_sys::HtmlElement>> = RefCell::new(Vec::new());
+}
+
+#[static_ref]
+fn values() -> &'static Vec<Mutable<u32>> {
+    (0..BOX_COUNT).map(|_| Mutable::new(0)).collect()
+}
+
+#[static_ref]
+fn result() -> &'static Mutable<String> {
+    Mutable::new("Press a button to start the benchmark.".to_owned())
+}
+
+#[static_ref]
+fn running() -> &'static Mutable<bool> {
+    Mutable::new(false)
+}
+
+// ------ benchmarks ------
+
+/// Every box has two bound style properties, i.e. all 60 properties change in the same tick.
+/// The measured time includes applying the updates and one layout.
+async fn bound_updates(round: u32) -> f64 {
+    let start = js_sys::Date::now();
+    for value in values() {
+        value.set(round);
+    }
+    let (end_sender, end_receiver) = oneshot::channel();
+    // `batch_read` runs after the tasks applying the bound updates.
+    dom::batch_read(move || {
+        force_layout();
+        end_sender.send(js_sys::Date::now()).unwrap_throw();
+    });
+    end_receiver.await.unwrap_throw() - start
+}
+
+/// The same writes, each followed by a layout read, i.e. up to 60 layouts per round.
+async fn interleaved_reads(round: u32) -> f64 {
+    let start = js_sys::Date::now();
+    BOX_ELEMENTS.with(|elements| {
+        for element in elements.borrow().iter() {
+            let style = element.style();
+            style.set_property("width", &width(round)).unwrap_throw();
+            element.offset_height();
+            style
+                .set_property("background-color", &color(round))
+                .unwrap_throw();
+            element.offset_height();
+        }
+    });
+    js_sys::Date::now() - start
+}
+
+fn run(name: &'static str, benchmark: fn(u32) -> future::LocalBoxFuture<'static, f64>) {
+    if running().replace(true) {
+        return;
+    }
+    result().set(format!("{}: running...", name));
+    Task::start(async move {
+        let mut total_ms = 0.;
+        for round in 1..=ROUNDS {
+            total_ms += benchmark(round).await;
+            // let the browser render the round
+            Timer::sleep(0).await;
+        }
+        result().set(format!(
+            "{}: {:.2} ms per round ({} rounds)",
+            name,
+            total_ms / f64::from(ROUNDS),
+            ROUNDS
+        ));
+        running().set(false);
+    });
+}
+
+fn force_layout() {
+    document().body().unwrap_throw().offset_height();
+}
+
+fn width(value: u32) -> String {
+    format!("{}px", 20 + value % 20)
+}
+
+fn color(value: u32) -> String {
+    format!("hsl({}, 70%, 50%)", value * 7 % 360)
+}
+
+// ------ view ------
+
+fn root() -> impl Element {
+    Column::new()
+        .s(Padding::all(20))
+        .s(Gap::both(20))
+        .item(
+            Row::new()
+                .s(Gap::both(10))
+                .item(run_button("Bound updates", |round| {
+                    bound_updates(round).boxed_local()
+                }))
+                .item(run_button("Interleaved reads", |round| {
+                    interleaved_reads(round).boxed_local()
+                })),
+        )
+        .item(Text::with_signal(result().signal_cloned()))
+        .item(
+            Row::new()
+                .multiline()
+                .s(Gap::both(5))
+                .items(values().iter().map(benchmark_box)),
+        )
+}
+
+fn run_button(
+    label: &'static str,
+    benchmark: fn(u32) -> future::LocalBoxFuture<'static, f64>,
+) -> impl Element {
+    Button::new()
+        .s(Padding::all(5))
+        .label(label)
+        .on_press(move || run(label, benchmark))
+}
+
+fn benchmark_box(value: &Mutable<u32>) -> impl Element {
+    RawHtmlEl::<web_sys::HtmlElement>::new("div")
+        .style("height", "20px")
+        .style_signal("width", value.signal().map(width))
+        .style_signal("background-color", value.signal().map(color))
+        .after_insert(|element| BOX_ELEMENTS.with(|elements| elements.borrow_mut().push(element)))
+}
+
+#[wasm_bindgen(start)]
+pub fn start() {
+    start_app("app", root);
+}
@@ -72,25 +72,13 @@ fn rectangle_radius() -> impl Element {
 }
 
 fn slider() -> impl Element {
-    // @TODO create Zoon element Slider; inspiration:
-    // https://www.w3schools.com/howto/howto_js_rangeslider.asp
-    // https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input/range
-    // https://package.elm-lang.org/packages/mdgriffith/elm-ui/latest/Element-Input#slider
-    // https://korban.net/elm/elm-ui-patterns/slider
-    RawHtmlEl::new("input")
-        .attr("type", "range")
-        .attr("min", "0")
-        .attr("max", "75")
-        .attr_signal("value", radius().signal())
-        .event_handler(|event: events::Input| {
-            #[allow(deprecated)]
-            let value = event
-                .value()
-                .expect("slider value")
-                .parse()
-                .expect("u32 value");
-            set_radius(value)
-        })
+    Slider::new()
+        .label_hidden("radius")
+        .min(0.)
+        .max(75.)
+        .step(1.)
+        .value_signal(radius().signal().map(f64::from))
+        .on_change(|value| set_radius(value as u32))
 }
 
 fn max_panel() -> impl Element {